@@ -0,0 +1,168 @@
+//! Times parsing, part 1 and part 2 for every day
+//!
+//! `cargo bench -p bench` runs everything, `cargo bench -p bench -- day7/` just the one day.
+//! `-- --save-baseline before` keeps the numbers around and `-- --baseline before` compares against them.
+
+use std::hint::black_box;
+use std::io::Write;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, BatchSize, Bencher, BenchmarkGroup, Criterion};
+use gag::Gag;
+
+// the days print their answers (and sometimes whole grids) as they go,
+// that's just noise in a bench so stdout is gagged while a day is running
+fn quiet<T>(f: impl FnOnce() -> T) -> T {
+    // only one gag can be open at a time, if that fails it's just noisy
+    let gag = Gag::stdout().ok();
+    let v = f();
+    std::io::stdout().flush().unwrap();
+    drop(gag);
+    v
+}
+
+// criterion prints its results after the routine is done, so those still show up
+struct QuietGroup<'a>(BenchmarkGroup<'a, WallTime>);
+
+impl QuietGroup<'_> {
+    fn sample_size(&mut self, n: usize) -> &mut Self {
+        self.0.sample_size(n);
+        self
+    }
+
+    fn bench_function<F: FnMut(&mut Bencher)>(&mut self, id: &str, mut f: F) -> &mut Self {
+        self.0.bench_function(id, |b| quiet(|| f(b)));
+        self
+    }
+
+    fn finish(self) {
+        self.0.finish();
+    }
+}
+
+// every day is a library crate, this brings its parse and parts into a module to bench them
+macro_rules! day {
+    ($day:ident, |$group:ident, $input:ident| $body:block) => {
+        // not every day needs batching
+        #[allow(unused_imports)]
+        mod $day {
+            use super::{black_box, quiet, BatchSize, QuietGroup};
+            use ::$day::*;
+
+            pub fn bench(c: &mut criterion::Criterion) {
+                let $input = include_str!(concat!("../../", stringify!($day), "/input.txt"));
+
+                let mut $group = QuietGroup(c.benchmark_group(stringify!($day)));
+                $group.sample_size(10);
+                $body
+                $group.finish();
+            }
+        }
+    };
+}
+
+day!(day1, |group, input| {
+    // main reads this one from stdin, same input though
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let expenses = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&expenses))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&expenses))));
+});
+
+day!(day2, |group, input| {
+    // main reads this one from stdin, same input though
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let validators = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&validators))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&validators))));
+});
+
+day!(day3, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let lines = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&lines))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&lines))));
+});
+
+day!(day4, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let passports = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&passports))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&passports))));
+});
+
+day!(day5, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let seatids = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&seatids))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&seatids))));
+});
+
+day!(day6, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let groups = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&groups))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&groups))));
+});
+
+day!(day7, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let bags = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(black_box(&bags), "shiny gold"))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| part2(black_box(&bags), "shiny gold"))
+    });
+});
+
+day!(day8, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let lines = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&lines))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&lines))));
+});
+
+day!(day9, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(black_box(&values), PREAMBLE_LEN))
+    });
+
+    let invalid = quiet(|| part1(&values, PREAMBLE_LEN));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values), invalid)));
+});
+
+criterion_group!(
+    benches,
+    day1::bench,
+    day2::bench,
+    day3::bench,
+    day4::bench,
+    day5::bench,
+    day6::bench,
+    day7::bench,
+    day8::bench,
+    day9::bench,
+);
+
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    aoc_bench::print_summary(aoc_bench::criterion_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/.."
+    )));
+}
@@ -0,0 +1,79 @@
+const TARGET_SUM: i64 = 2020;
+
+pub fn part1(expenses: impl AsRef<[i64]>) -> i64 {
+    let expenses = expenses.as_ref();
+
+    for x in 0..expenses.len() {
+        for y in x..expenses.len() {
+            let a = expenses.get(x).unwrap();
+            let b = expenses.get(y).unwrap();
+
+            if a + b == TARGET_SUM {
+                println!(
+                    "{} + {} = {} and {} * {} = {}",
+                    a,
+                    b,
+                    TARGET_SUM,
+                    a,
+                    b,
+                    a * b
+                );
+                return a * b;
+            }
+        }
+    }
+
+    panic!("Part 1 found no values found that sum to {}!", TARGET_SUM);
+}
+
+pub fn part2(expenses: impl AsRef<[i64]>) -> i64 {
+    let expenses = expenses.as_ref();
+
+    for x in 0..expenses.len() {
+        for y in x..expenses.len() {
+            for z in y..expenses.len() {
+                let a = expenses.get(x).unwrap();
+                let b = expenses.get(y).unwrap();
+                let c = expenses.get(z).unwrap();
+
+                if a + b + c == TARGET_SUM {
+                    println!(
+                        "{} + {} + {} = {} and {} * {} * {} = {}",
+                        a,
+                        b,
+                        c,
+                        TARGET_SUM,
+                        a,
+                        b,
+                        c,
+                        a * b * c
+                    );
+                    return a * b * c;
+                }
+            }
+        }
+    }
+
+    panic!("Part 2 found no values found that sum to {}!", TARGET_SUM);
+}
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input
+        .lines()
+        .map(|x| x.parse::<i64>()) // not sure how to simplify this
+        .map(Result::unwrap)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let expenses = parse(include_str!("../examples/example.txt"));
+
+        assert_eq!(part1(&expenses), 514579);
+        assert_eq!(part2(&expenses), 241861950);
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct PasswordValidator {
+    pub character: char,
+    pub min: usize,
+    pub max: usize,
+    pub password: String,
+}
+
+impl PasswordValidator {
+    pub fn is_valid_part_one(&self) -> bool {
+        let count = self.password.matches(self.character).count();
+        count >= self.min && count <= self.max
+    }
+
+    pub fn is_valid_part_two(&self) -> bool {
+        if self.password.len() < self.max - 1 {
+            return false;
+        }
+
+        let first = self.password.chars().nth(self.min - 1).unwrap() == self.character;
+        let second = self.password.chars().nth(self.max - 1).unwrap() == self.character;
+        (first || second) && first != second
+    }
+}
+
+pub fn part1(validators: impl AsRef<[PasswordValidator]>) -> usize {
+    let validators = validators.as_ref();
+
+    let valid = validators.iter().filter(|v| v.is_valid_part_one()).count();
+
+    println!(
+        "Found {} part one valid passwords out of {}",
+        valid,
+        validators.len()
+    );
+
+    valid
+}
+
+pub fn part2(validators: impl AsRef<[PasswordValidator]>) -> usize {
+    let validators = validators.as_ref();
+
+    let valid = validators.iter().filter(|v| v.is_valid_part_two()).count();
+
+    println!(
+        "Found {} part two valid passwords out of {}",
+        valid,
+        validators.len()
+    );
+
+    valid
+}
+
+pub fn parse(input: &str) -> Result<Vec<PasswordValidator>, ParseError> {
+    let re = Regex::new(r"^(?P<min>\d+)-(?P<max>\d+) (?P<character>.): (?P<password>.*)$").unwrap();
+
+    input
+        .lines()
+        .map(|line| {
+            let caps = re
+                .captures(line)
+                .ok_or_else(|| ParseError::new(input, line, "expected min-max c: password"))?;
+
+            Ok(PasswordValidator {
+                character: caps["character"].chars().next().unwrap(),
+                min: parse_at(input, caps.name("min").unwrap().as_str())?,
+                max: parse_at(input, caps.name("max").unwrap().as_str())?,
+                password: caps["password"].to_owned(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let validators = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&validators), 2);
+        assert_eq!(part2(&validators), 1);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("1-3 a: abcde\n1-x b: cdefg").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected min-max c: password");
+
+        let err = parse("1-3 a: abcde\n99999999999999999999-3 b: cdefg").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "99999999999999999999");
+    }
+}
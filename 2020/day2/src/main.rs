@@ -1,75 +1,13 @@
-use std::io::{self, BufRead};
+use std::io::{self, Read};
 
-use regex::Regex;
-
-#[derive(Debug)]
-struct PasswordValidator {
-    pub character: char,
-    pub min: usize,
-    pub max: usize,
-    pub password: String,
-}
-
-impl PasswordValidator {
-    pub fn is_valid_part_one(&self) -> bool {
-        let count = self.password.matches(self.character).count();
-        count >= self.min && count <= self.max
-    }
-
-    pub fn is_valid_part_two(&self) -> bool {
-        if self.password.len() < self.max - 1 {
-            return false;
-        }
-
-        let first = self.password.chars().nth(self.min - 1).unwrap() == self.character;
-        let second = self.password.chars().nth(self.max - 1).unwrap() == self.character;
-        (first || second) && first != second
-    }
-}
-
-fn part1(validators: impl AsRef<[PasswordValidator]>) {
-    let validators = validators.as_ref();
-
-    let valid = validators.iter().filter(|v| v.is_valid_part_one()).count();
-
-    println!(
-        "Found {} part one valid passwords out of {}",
-        valid,
-        validators.len()
-    );
-}
-
-fn part2(validators: impl AsRef<[PasswordValidator]>) {
-    let validators = validators.as_ref();
-
-    let valid = validators.iter().filter(|v| v.is_valid_part_two()).count();
-
-    println!(
-        "Found {} part two valid passwords out of {}",
-        valid,
-        validators.len()
-    );
-}
+use day2::{parse, part1, part2};
 
 fn main() {
-    let re = Regex::new(r"(?P<min>\d+)-(?P<max>\d+) (?P<character>.): (?P<password>.*)").unwrap();
-
     println!("Enter password entries one line at a time:");
-    let validators: Vec<PasswordValidator> = io::stdin()
-        .lock()
-        .lines()
-        .map(Result::unwrap)
-        .map(|line| {
-            let caps = re.captures(&line).unwrap();
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).unwrap();
 
-            PasswordValidator {
-                character: caps["character"].to_owned().chars().next().unwrap(),
-                min: caps["min"].parse().unwrap(),
-                max: caps["max"].parse().unwrap(),
-                password: caps["password"].to_owned(),
-            }
-        })
-        .collect();
+    let validators = aoc_parse::or_exit!(parse(&input));
 
     part1(&validators);
     part2(&validators);
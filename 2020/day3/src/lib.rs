@@ -0,0 +1,77 @@
+const TREE: char = '#';
+
+#[derive(Debug, Copy, Clone)]
+struct Slope {
+    rows: usize,
+    cols: usize,
+}
+
+impl Slope {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols }
+    }
+}
+
+fn check_slope<'a>(lines: impl AsRef<[&'a str]>, slope: Slope) -> usize {
+    let lines = lines.as_ref();
+
+    let mut row = 0;
+    let mut col = 0;
+
+    let mut trees = 0;
+    loop {
+        row += slope.rows;
+        if row >= lines.len() {
+            break;
+        }
+
+        let line = lines[row];
+
+        col = (col + slope.cols) % line.len();
+
+        if line.chars().nth(col).unwrap() == TREE {
+            trees += 1;
+        }
+    }
+
+    println!("Ran into {} trees", trees);
+    trees
+}
+
+pub fn part1<'a>(lines: impl AsRef<[&'a str]>) -> usize {
+    check_slope(lines, Slope::new(1, 3))
+}
+
+pub fn part2<'a>(lines: impl AsRef<[&'a str]>) -> usize {
+    let slopes = [
+        Slope::new(1, 1),
+        Slope::new(1, 3),
+        Slope::new(1, 5),
+        Slope::new(1, 7),
+        Slope::new(2, 1),
+    ];
+
+    let total = slopes
+        .iter()
+        .fold(1, |acc, s| acc * check_slope(lines.as_ref(), *s));
+
+    println!("Total: {}", total);
+    total
+}
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().filter(|x| !x.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let lines = parse(include_str!("../examples/example.txt"));
+
+        assert_eq!(part1(&lines), 7);
+        assert_eq!(part2(&lines), 336);
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_parse::ParseError;
+
+fn narrow(input: char, min: i64, max: i64) -> Option<(i64, i64)> {
+    let mid = (max - min) / 2;
+
+    match input {
+        'F' | 'L' => Some((min, min + mid)),
+        'B' | 'R' => Some((min + mid + 1, max)),
+        _ => None,
+    }
+}
+
+fn seatid(boarding_pass: &str) -> Result<i64, ParseError> {
+    let mut chars = boarding_pass.char_indices();
+    let mut next = |min, max| {
+        let (idx, c) = chars
+            .next()
+            .ok_or_else(|| ParseError::truncated(boarding_pass, "expected 10 of F, B, L or R"))?;
+        narrow(c, min, max).ok_or_else(|| {
+            let c = &boarding_pass[idx..idx + c.len_utf8()];
+            ParseError::new(boarding_pass, c, "expected F, B, L or R")
+        })
+    };
+
+    // narrow the rows
+    let mut rowmin = 0;
+    let mut rowmax = 127;
+    for _ in 0..7 {
+        let (s, t) = next(rowmin, rowmax)?;
+        rowmin = s;
+        rowmax = t;
+    }
+    assert_eq!(rowmin, rowmax, "row");
+    let row = rowmin;
+
+    // narrow the seats
+    let mut seatmin = 0;
+    let mut seatmax = 7;
+    for _ in 0..3 {
+        let (s, t) = next(seatmin, seatmax)?;
+        seatmin = s;
+        seatmax = t;
+    }
+    assert_eq!(seatmin, seatmax, "seat");
+    let seat = seatmin;
+
+    if let Some((idx, _)) = chars.next() {
+        return Err(ParseError::new(
+            boarding_pass,
+            &boarding_pass[idx..],
+            "unexpected text at the end",
+        ));
+    }
+
+    Ok(row * 8 + seat)
+}
+
+// assumes seatids is sorted
+fn find_missing(seatids: impl AsRef<[i64]>) -> i64 {
+    let seatids = seatids.as_ref();
+
+    println!("checking {} seatids", seatids.len());
+
+    let mut last_seat_id = seatids.first().unwrap() - 1;
+    for seatid in seatids {
+        let expected = last_seat_id + 1;
+        if *seatid != expected {
+            return expected;
+        }
+        last_seat_id = *seatid;
+    }
+
+    panic!("No seat id!");
+}
+
+// assumes seatids is sorted
+pub fn part1(seatids: impl AsRef<[i64]>) -> i64 {
+    let highest = *seatids.as_ref().iter().last().unwrap();
+    println!("highest seat id: {}", highest);
+    highest
+}
+
+pub fn part2(seatids: impl AsRef<[i64]>) -> i64 {
+    let seatid = find_missing(seatids);
+    println!("your seat id: {}", seatid);
+    seatid
+}
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    let mut seatids = input
+        .lines()
+        .filter(|x| !x.is_empty())
+        .map(|x| seatid(x).map_err(|err| err.within(input, x)))
+        .collect::<Result<Vec<i64>, _>>()?;
+    seatids.sort_unstable();
+    Ok(seatids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = include_str!("../examples/example.txt");
+
+        let ids = input
+            .lines()
+            .map(|x| seatid(x).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![357, 567, 119, 820]);
+
+        assert_eq!(part1(parse(input).unwrap()), 820);
+    }
+
+    #[test]
+    fn missing_seat() {
+        // part 2 doesn't have an example, so just leave a gap
+        assert_eq!(part2([3, 4, 5, 7, 8]), 6);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("FBFBBFFRLR\nBFFFBXFRRR\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.text, "X");
+
+        let err = parse("FBFBBFFRLR\nBFFFBBF\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(err.message, "expected 10 of F, B, L or R");
+
+        let err = parse("FBFBBFFRLRL\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 11));
+    }
+}
@@ -0,0 +1,66 @@
+fn score(answers: impl AsRef<str>) -> u32 {
+    let mut v: u32 = 0;
+    for ch in answers.as_ref().chars() {
+        if ch.is_whitespace() {
+            continue;
+        }
+
+        let d = ch as u32 - 'a' as u32;
+        v |= 1 << d;
+    }
+    v
+}
+
+fn count_anyone(answers: impl AsRef<str>) -> u32 {
+    score(answers).count_ones()
+}
+
+fn count_everyone(group_answers: impl AsRef<str>) -> u32 {
+    let answers: Vec<&str> = group_answers
+        .as_ref()
+        .lines()
+        .filter(|x| !x.is_empty())
+        .collect();
+
+    let mut v: u32 = (0 - 1) as u32;
+    for answer in answers {
+        let individual = score(answer);
+        v &= individual;
+    }
+    v.count_ones()
+}
+
+pub fn part1<'a>(groups: impl AsRef<[&'a str]>) -> u32 {
+    let count = groups
+        .as_ref()
+        .iter()
+        .fold(0, |acc, line| acc + count_anyone(line));
+    println!("anyone sum: {}", count);
+    count
+}
+
+pub fn part2<'a>(groups: impl AsRef<[&'a str]>) -> u32 {
+    let count = groups
+        .as_ref()
+        .iter()
+        .fold(0, |acc, line| acc + count_everyone(line));
+    println!("everyone sum: {}", count);
+    count
+}
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.split("\n\n").filter(|x| !x.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let groups = parse(include_str!("../examples/example.txt"));
+
+        assert_eq!(part1(&groups), 11);
+        assert_eq!(part2(&groups), 6);
+    }
+}
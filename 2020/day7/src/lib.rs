@@ -0,0 +1,109 @@
+// there's probably a way to do this with a single regex /shrug
+
+use aoc_graph::Graph;
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+// edges go from the containing bag to the bags it contains,
+// weighted by how many of them it holds
+type Bags<'a> = Graph<'a, usize>;
+
+fn contains_total(bags: &Bags, bag: usize, cache: &mut Vec<Option<usize>>) -> usize {
+    if let Some(contains) = cache[bag] {
+        return contains;
+    }
+
+    let mut contains = 0;
+    for &(inner, count) in bags.edges(bag) {
+        contains += count + (count * contains_total(bags, inner, cache));
+    }
+
+    cache[bag] = Some(contains);
+    contains
+}
+
+pub fn part1(bags: &Bags, color: impl AsRef<str>) -> usize {
+    let bag = bags.index_of(color.as_ref()).unwrap();
+
+    // anything that can reach this bag going backwards can contain it
+    // (minus the bag itself)
+    let containers = bags.reversed().reachable(bag).len() - 1;
+
+    println!(
+        "'{}' bag contained by {} other bags",
+        color.as_ref(),
+        containers
+    );
+    containers
+}
+
+pub fn part2(bags: &Bags, color: impl AsRef<str>) -> usize {
+    let bag = bags.index_of(color.as_ref()).unwrap();
+
+    let contains = contains_total(bags, bag, &mut vec![None; bags.len()]);
+
+    println!("'{}' bag contains {} other bags", color.as_ref(), contains);
+    contains
+}
+
+pub fn parse(input: &str) -> Result<Bags<'_>, ParseError> {
+    let re = Regex::new(r"^(?P<container>.+) bags contain (?P<bags>.*)\.$").unwrap();
+    let bagsre = Regex::new(r"^(?P<count>\d+) (?P<bag>.+) bags?$").unwrap();
+
+    let mut bags = Bags::directed();
+    for line in input.lines().filter(|x| !x.is_empty()) {
+        let caps = re
+            .captures(line)
+            .ok_or_else(|| ParseError::new(input, line, "expected <color> bags contain ..."))?;
+
+        let container = caps.name("container").unwrap().as_str();
+        bags.add_node(container);
+
+        let bagdefs = caps.name("bags").unwrap().as_str();
+        if bagdefs == "no other bags" {
+            continue;
+        }
+
+        for bagdef in bagdefs.split(',') {
+            let bagdef = bagdef.trim();
+            let caps = bagsre
+                .captures(bagdef)
+                .ok_or_else(|| ParseError::new(input, bagdef, "expected <count> <color> bags"))?;
+            let bag = caps.name("bag").unwrap().as_str();
+            let count = parse_at(input, caps.name("count").unwrap().as_str())?;
+            bags.add_edge(container, bag, count);
+        }
+    }
+
+    Ok(bags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let bags = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&bags, "shiny gold"), 4);
+        assert_eq!(part2(&bags, "shiny gold"), 32);
+    }
+
+    #[test]
+    fn example_nested() {
+        let bags = parse(include_str!("../examples/example2.txt")).unwrap();
+
+        assert_eq!(part2(&bags, "shiny gold"), 126);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("faded blue bags contain no other bags.\nshiny gold bags contain 1 faded blue bag, two dark red bags.").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 43));
+        assert_eq!(err.text, "two dark red bags");
+
+        let err = parse("faded blue bags hold no other bags.").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
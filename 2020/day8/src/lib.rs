@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use derivative::Derivative;
+
+#[derive(Debug, Derivative, Clone, Copy, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum InstructionType {
+    #[derivative(Default)]
+    NoOp,
+    Accumulate,
+    Jump,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Instruction {
+    pub r#type: InstructionType,
+    pub value: i64,
+
+    pub execute_count: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let (op, value) = v
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(v, v, "expected an instruction like acc +1"))?;
+
+        let r#type = match op {
+            "nop" => InstructionType::NoOp,
+            "acc" => InstructionType::Accumulate,
+            "jmp" => InstructionType::Jump,
+            _ => return Err(ParseError::new(v, op, "expected nop, acc or jmp")),
+        };
+
+        Ok(Self {
+            r#type,
+            value: parse_at(v, value)?,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Cpu {
+    pub accumulator: i64,
+
+    pub counter: i64,
+
+    pub program: Vec<Instruction>,
+}
+
+impl Cpu {
+    pub fn load(&mut self, program: impl AsRef<[Instruction]>) {
+        self.program.extend_from_slice(program.as_ref());
+    }
+
+    pub fn run(&mut self) -> bool {
+        loop {
+            match self.program.get_mut(self.counter as usize) {
+                None => return true,
+                Some(instruction) => {
+                    if instruction.execute_count > 0 {
+                        return false;
+                    }
+                    instruction.execute_count += 1;
+
+                    match instruction.r#type {
+                        InstructionType::NoOp => self.counter += 1,
+                        InstructionType::Accumulate => {
+                            self.accumulator += instruction.value;
+                            self.counter += 1;
+                        }
+                        InstructionType::Jump => self.counter += instruction.value,
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn part1(program: impl AsRef<[Instruction]>) -> i64 {
+    let mut cpu = Cpu::default();
+    cpu.load(program);
+
+    if !cpu.run() {
+        println!("infinite loop detected");
+    }
+
+    println!("accumulator: {}", cpu.accumulator);
+    cpu.accumulator
+}
+
+fn part2_change(
+    mut cpu: Cpu,
+    change: usize,
+    from: InstructionType,
+    to: InstructionType,
+) -> Option<i64> {
+    let mut idx = 0;
+    let mut count = 0;
+    loop {
+        match cpu.program.get_mut(idx) {
+            Some(instruction) => {
+                idx += 1;
+
+                if instruction.r#type != from {
+                    continue;
+                }
+
+                if count == change {
+                    instruction.r#type = to;
+                    break;
+                }
+                count += 1;
+            }
+            None => return None,
+        }
+    }
+
+    if cpu.run() {
+        return Some(cpu.accumulator);
+    }
+
+    Some(-1)
+}
+
+pub fn part2(program: impl AsRef<[Instruction]>) -> i64 {
+    let mut cpu = Cpu::default();
+    cpu.load(program);
+
+    let mut change = 0;
+    while let Some(acc) = part2_change(
+        cpu.clone(),
+        change,
+        InstructionType::NoOp,
+        InstructionType::Jump,
+    ) {
+        if acc >= 0 {
+            println!("success accumulator: {}", acc);
+            return acc;
+        }
+        change += 1;
+    }
+
+    let mut change = 0;
+    while let Some(acc) = part2_change(
+        cpu.clone(),
+        change,
+        InstructionType::Jump,
+        InstructionType::NoOp,
+    ) {
+        if acc >= 0 {
+            println!("success accumulator: {}", acc);
+            return acc;
+        }
+        change += 1;
+    }
+
+    panic!("total failure!");
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|err: ParseError| err.within(input, x)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let program = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&program), 5);
+        assert_eq!(part2(&program), 8);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("nop +0\nacc +1\njmp +x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.text, "+x");
+
+        let err = parse("nop +0\nmul +1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected nop, acc or jmp");
+
+        let err = parse("nop +0\nacc\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
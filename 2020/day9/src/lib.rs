@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use aoc_parse::{parse_at, ParseError};
+
+pub const PREAMBLE_LEN: usize = 25;
+
+fn is_valid(preamble: impl AsRef<[usize]>, value: usize) -> bool {
+    let preamble = preamble.as_ref();
+
+    let mut sums = HashSet::new();
+    for x in 0..preamble.len() {
+        let xv = preamble.get(x).unwrap();
+        for y in x + 1..preamble.len() {
+            let yv = preamble.get(y).unwrap();
+            sums.insert(xv + yv);
+        }
+    }
+
+    sums.contains(&value)
+}
+
+pub fn part1(values: impl AsRef<[usize]>, preamble_len: usize) -> usize {
+    let values = values.as_ref();
+
+    let mut invalid = None;
+    for x in preamble_len..values.len() {
+        let slice_start = x - preamble_len;
+        let value = values.get(x).unwrap();
+        if !is_valid(&values[slice_start..slice_start + preamble_len], *value) {
+            invalid = Some(value);
+            break;
+        }
+    }
+
+    if invalid.is_none() {
+        panic!("failed to find the invalid number");
+    }
+
+    let invalid = *(invalid.unwrap());
+    println!("{} is not valid", invalid);
+    invalid
+}
+
+pub fn part2(values: impl AsRef<[usize]>, invalid: usize) -> usize {
+    let values = values.as_ref();
+
+    for x in 0..values.len() {
+        let xv = values.get(x).unwrap();
+
+        let mut sum = *xv;
+        for y in x + 1..values.len() {
+            let yv = values.get(y).unwrap();
+
+            sum += yv;
+            match sum.cmp(&invalid) {
+                std::cmp::Ordering::Equal => {
+                    let v = &values[x..y + 1];
+                    let min = v.iter().min().unwrap();
+                    let max = v.iter().max().unwrap();
+
+                    println!("weakness: {} + {} = {}", min, max, min + max);
+                    return min + max;
+                }
+                std::cmp::Ordering::Greater => break,
+                _ => continue,
+            }
+        }
+    }
+
+    panic!("didn't find the weakness!");
+}
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let lines: Vec<&str> = input.lines().filter(|x| !x.is_empty()).collect();
+
+    lines.iter().map(|x| parse_at(input, x)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        // the example only uses a 5 number preamble
+        let invalid = part1(&values, 5);
+        assert_eq!(invalid, 127);
+        assert_eq!(part2(&values, invalid), 62);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("35\n20\n-15\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "-15");
+    }
+}
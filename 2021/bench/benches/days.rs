@@ -0,0 +1,336 @@
+//! Times parsing, part 1 and part 2 for every day
+//!
+//! `cargo bench -p bench` runs everything, `cargo bench -p bench -- day7/` just the one day.
+//! `-- --save-baseline before` keeps the numbers around and `-- --baseline before` compares against them.
+
+use std::hint::black_box;
+use std::io::Write;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, BatchSize, Bencher, BenchmarkGroup, Criterion};
+use gag::Gag;
+
+// the days print their answers (and sometimes whole grids) as they go,
+// that's just noise in a bench so stdout is gagged while a day is running
+fn quiet<T>(f: impl FnOnce() -> T) -> T {
+    // only one gag can be open at a time, if that fails it's just noisy
+    let gag = Gag::stdout().ok();
+    let v = f();
+    std::io::stdout().flush().unwrap();
+    drop(gag);
+    v
+}
+
+// criterion prints its results after the routine is done, so those still show up
+struct QuietGroup<'a>(BenchmarkGroup<'a, WallTime>);
+
+impl QuietGroup<'_> {
+    fn sample_size(&mut self, n: usize) -> &mut Self {
+        self.0.sample_size(n);
+        self
+    }
+
+    fn bench_function<F: FnMut(&mut Bencher)>(&mut self, id: &str, mut f: F) -> &mut Self {
+        self.0.bench_function(id, |b| quiet(|| f(b)));
+        self
+    }
+
+    fn finish(self) {
+        self.0.finish();
+    }
+}
+
+// every day is a library crate, this brings its parse and parts into a module to bench them
+macro_rules! day {
+    ($day:ident, |$group:ident, $input:ident| $body:block) => {
+        // not every day needs batching
+        #[allow(unused_imports)]
+        mod $day {
+            use super::{black_box, quiet, BatchSize, QuietGroup};
+            use ::$day::*;
+
+            pub fn bench(c: &mut criterion::Criterion) {
+                let $input = include_str!(concat!("../../", stringify!($day), "/input.txt"));
+
+                let mut $group = QuietGroup(c.benchmark_group(stringify!($day)));
+                $group.sample_size(10);
+                $body
+                $group.finish();
+            }
+        }
+    };
+}
+
+day!(day1, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day2, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let commands = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&commands))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&commands))));
+});
+
+day!(day3, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day4, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (drawings, boards) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || boards.clone(),
+            |boards| part1(&drawings, boards),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || boards.clone(),
+            |boards| part2(&drawings, boards),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day5, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (grid, paths) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || grid.clone(),
+            |grid| part1(grid, &paths),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || grid.clone(),
+            |grid| part2(grid, &paths),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day6, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let initialages = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| simulate(black_box(&initialages), 80))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| simulate(black_box(&initialages), 256))
+    });
+});
+
+day!(day7, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let positions = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| positions.clone(), part1, BatchSize::SmallInput)
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| positions.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day8, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let entries = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&entries))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&entries))));
+});
+
+day!(day9, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let heightmap = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&heightmap))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&heightmap))));
+});
+
+day!(day10, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let lines = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&lines))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&lines))));
+});
+
+day!(day11, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grid = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| grid.clone(), part1, BatchSize::SmallInput)
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| grid.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day12, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let graph = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&graph))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&graph))));
+});
+
+day!(day13, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (grid, folds) = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&grid), &folds)));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&grid), &folds)));
+});
+
+day!(day14, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (template, rules) = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| run(black_box(template), &rules, 10)));
+    group.bench_function("part2", |b| b.iter(|| run(black_box(template), &rules, 40)));
+});
+
+day!(day15, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let nodes = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| nodes.clone(), part1, BatchSize::SmallInput)
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| nodes.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day16, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let transmission = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&transmission))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&transmission))));
+});
+
+day!(day17, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (xmin, xmax, ymin, ymax) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(black_box(xmin), xmax, ymin, ymax))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| part2(black_box(xmin), xmax, ymin, ymax))
+    });
+});
+
+day!(day18, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let numbers = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| numbers.clone().into(), part1, BatchSize::SmallInput)
+    });
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&numbers))));
+});
+
+day!(day19, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let scanners = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&scanners))));
+});
+
+day!(day20, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (image, enhancement) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| run(black_box(&image), &enhancement, 2))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| run(black_box(&image), &enhancement, 50))
+    });
+});
+
+day!(day21, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (player1, player2) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(black_box(player1), player2, 10, 1000))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| part2(black_box(player1), player2, 10, 21))
+    });
+});
+
+day!(day22, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let instructions = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&instructions))));
+
+    // part 2 is still commented out in main
+});
+
+day!(day24, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    // part 1 brute forces the model numbers and doesn't finish
+});
+
+criterion_group!(
+    benches,
+    day1::bench,
+    day2::bench,
+    day3::bench,
+    day4::bench,
+    day5::bench,
+    day6::bench,
+    day7::bench,
+    day8::bench,
+    day9::bench,
+    day10::bench,
+    day11::bench,
+    day12::bench,
+    day13::bench,
+    day14::bench,
+    day15::bench,
+    day16::bench,
+    day17::bench,
+    day18::bench,
+    day19::bench,
+    day20::bench,
+    day21::bench,
+    day22::bench,
+    day24::bench,
+);
+
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    aoc_bench::print_summary(aoc_bench::criterion_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/.."
+    )));
+}
@@ -0,0 +1,62 @@
+use aoc_parse::{parse_at, ParseError};
+
+pub fn part1(values: impl AsRef<[usize]>) -> usize {
+    let increased: usize = values
+        .as_ref()
+        .windows(2)
+        .map(|x| usize::from(x[1] > x[0]))
+        .sum();
+
+    println!("Depth measurement increased {} times", increased);
+    increased
+}
+
+pub fn part2(values: impl AsRef<[usize]>) -> usize {
+    // the problem here is asking for sliding window sums (A + B + C) > (B + C + D)
+    // and I had been tracking that previous sum value for the comparision
+    // but a note from https://github.com/zertosh/ that really helped make this simpler:
+    // in A + B + C > B + C + D the B and C cancel out from both sides leaving you with just A > D
+
+    let increased: usize = values
+        .as_ref()
+        .windows(4)
+        .map(|x| usize::from(x[3] > x[0]))
+        .sum();
+
+    println!("Depth-sum measurement increased {} times", increased);
+    increased
+}
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            Some(parse_at(input, x))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 7);
+        assert_eq!(part2(&values), 5);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("199\n200\n2O8\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "2O8");
+    }
+}
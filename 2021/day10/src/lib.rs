@@ -0,0 +1,129 @@
+use aoc_parse::ParseError;
+
+fn count_valid_chars(line: impl AsRef<str>) -> (usize, Vec<char>) {
+    let mut stack = Vec::new();
+    let valid_count = line
+        .as_ref()
+        .chars()
+        .take_while(|ch| match ch {
+            '(' | '[' | '{' | '<' => {
+                stack.push(*ch);
+                true
+            }
+            // closing with nothing open is corrupted too
+            ')' => stack.pop() == Some('('),
+            ']' => stack.pop() == Some('['),
+            '}' => stack.pop() == Some('{'),
+            '>' => stack.pop() == Some('<'),
+            // parse doesn't let anything else through
+            _ => false,
+        })
+        .count();
+    (valid_count, stack)
+}
+
+pub fn part1<'a>(input: impl AsRef<[&'a str]>) -> usize {
+    let total: usize = input
+        .as_ref()
+        .iter()
+        .filter_map(|&line| {
+            let (valid_count, _) = count_valid_chars(line);
+            if valid_count == line.len() {
+                None
+            } else {
+                Some(match line.chars().nth(valid_count) {
+                    Some(')') => 3,
+                    Some(']') => 57,
+                    Some('}') => 1197,
+                    Some('>') => 25137,
+                    _ => 0,
+                })
+            }
+        })
+        .sum();
+
+    println!("Final score: {}", total);
+    total
+}
+
+pub fn part2<'a>(input: impl AsRef<[&'a str]>) -> usize {
+    let mut scores: Vec<usize> = input
+        .as_ref()
+        .iter()
+        .filter_map(|&line| {
+            let (valid_count, stack) = count_valid_chars(line);
+            if valid_count != line.len() {
+                None
+            } else {
+                Some(stack)
+            }
+        })
+        .map(|stack| {
+            let mut score = 0;
+            for ch in stack.iter().rev() {
+                score *= 5;
+                score += match ch {
+                    '(' => 1,
+                    '[' => 2,
+                    '{' => 3,
+                    '<' => 4,
+                    _ => unreachable!(),
+                };
+            }
+            score
+        })
+        .collect();
+    assert!(!scores.len().is_multiple_of(2));
+
+    scores.sort_unstable();
+
+    let final_score = scores[scores.len() / 2];
+    println!("Final score: {}", final_score);
+    final_score
+}
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            if let Some((idx, ch)) = x.char_indices().find(|(_, ch)| !"()[]{}<>".contains(*ch)) {
+                let ch = &x[idx..idx + ch.len_utf8()];
+                return Some(Err(ParseError::new(input, ch, "expected a bracket")));
+            }
+
+            Some(Ok(x))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let lines = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&lines), 26397);
+        assert_eq!(part2(&lines), 288957);
+    }
+
+    #[test]
+    fn unopened() {
+        // closing something that was never opened is corrupted
+        let lines = parse(")\n").unwrap();
+        assert_eq!(part1(&lines), 3);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<a[]>>(\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 18));
+        assert_eq!(err.text, "a");
+    }
+}
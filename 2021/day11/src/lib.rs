@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use aoc_parse::combinators::{grid, parse_all};
+use aoc_parse::ParseError;
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    grid: Vec<Vec<usize>>,
+}
+
+impl Grid {
+    fn size(&self) -> usize {
+        self.grid.len() * self.grid[0].len()
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut usize> {
+        self.grid.get_mut(row)?.get_mut(col)
+    }
+
+    fn step(&mut self) -> usize {
+        let mut flashed = HashSet::new();
+
+        // step 1: increase energy level
+        for row in &mut self.grid {
+            for col in row {
+                *col += 1;
+            }
+        }
+
+        // step 2: flash
+        loop {
+            // find everything that can flash this pass of this step
+            let flash: Vec<(usize, usize)> = self
+                .grid
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter().enumerate().filter_map(move |(x, &v)| {
+                        let idx = (x, y);
+                        if v > 9 {
+                            Some(idx)
+                        } else {
+                            None
+                        }
+                    })
+                })
+                .filter(|&idx| {
+                    // can only flash once per step
+                    if flashed.contains(&idx) {
+                        return false;
+                    }
+
+                    flashed.insert(idx);
+                    true
+                })
+                .collect();
+
+            if flash.is_empty() {
+                break;
+            }
+
+            // increase adjacent energy
+            for idx in flash {
+                if idx.1 > 0 {
+                    // up left
+                    if idx.0 > 0 {
+                        *self.get_mut(idx.1 - 1, idx.0 - 1).unwrap() += 1;
+                    }
+
+                    // up
+                    *self.get_mut(idx.1 - 1, idx.0).unwrap() += 1;
+
+                    // up right
+                    if let Some(r) = self.get_mut(idx.1 - 1, idx.0 + 1) {
+                        *r += 1;
+                    }
+                }
+
+                // right
+                if let Some(r) = self.get_mut(idx.1, idx.0 + 1) {
+                    *r += 1;
+                }
+
+                // down right
+                if let Some(r) = self.get_mut(idx.1 + 1, idx.0 + 1) {
+                    *r += 1;
+                }
+
+                // down
+                if let Some(r) = self.get_mut(idx.1 + 1, idx.0) {
+                    *r += 1;
+                }
+
+                if idx.0 > 0 {
+                    // down left
+                    if let Some(r) = self.get_mut(idx.1 + 1, idx.0 - 1) {
+                        *r += 1;
+                    }
+
+                    // left
+                    *self.get_mut(idx.1, idx.0 - 1).unwrap() += 1;
+                }
+            }
+        }
+
+        // step 3: everything that flashed goes back to 0
+        for flash in &flashed {
+            *self.get_mut(flash.1, flash.0).unwrap() = 0;
+        }
+
+        flashed.len()
+    }
+}
+
+impl From<Vec<Vec<usize>>> for Grid {
+    fn from(grid: Vec<Vec<usize>>) -> Self {
+        Self { grid }
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.grid {
+            for v in row {
+                write!(f, "{}", v)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(mut grid: Grid) -> usize {
+    let mut total = 0;
+    for _ in 0..100 {
+        total += grid.step();
+    }
+
+    println!("There were {} flashes in total", total);
+    total
+}
+
+pub fn part2(mut grid: Grid) -> usize {
+    let mut step = 0;
+    loop {
+        step += 1;
+
+        let flashed = grid.step();
+        if flashed == grid.size() {
+            break;
+        }
+    }
+
+    println!("First synchronized flash step is {}", step);
+    step
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    let grid: Vec<Vec<usize>> = parse_all(input, grid(|ch| ch.to_digit(10).map(|v| v as usize)))?;
+
+    Ok(grid.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut grid = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(grid.step(), 9);
+        assert_eq!(grid.to_string(), "34543\n40004\n50005\n40004\n34543\n");
+
+        assert_eq!(grid.step(), 0);
+        assert_eq!(grid.to_string(), "45654\n51115\n61116\n51115\n45654\n");
+    }
+
+    #[test]
+    fn example_parts() {
+        // the small example only publishes the first couple of steps,
+        // these were checked against a separate simulation
+        let grid = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(grid.clone()), 259);
+        assert_eq!(part2(grid), 6);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("2199943210\n3987894921\n98567x6789\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 6));
+        assert_eq!(err.message, "expected a grid cell");
+    }
+}
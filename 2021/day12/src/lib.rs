@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+
+use aoc_graph::Graph;
+use aoc_parse::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Cave {
+    Start,
+    Small(&'static str),
+    Large(&'static str),
+    End,
+}
+
+impl From<&'static str> for Cave {
+    fn from(v: &'static str) -> Self {
+        if v == "start" {
+            Self::Start
+        } else if v == "end" {
+            Self::End
+        } else if v.chars().all(|ch| ch.is_lowercase()) {
+            Self::Small(v)
+        } else {
+            Self::Large(v)
+        }
+    }
+}
+
+impl Cave {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Small(name) => name,
+            Self::Large(name) => name,
+            Self::End => "end",
+        }
+    }
+}
+
+// the kind of every cave, by node index
+fn cave_kinds(graph: &Graph<'static>) -> Vec<Cave> {
+    graph.names().iter().map(|name| Cave::from(*name)).collect()
+}
+
+fn part1_visit(
+    cave: usize,
+    graph: &Graph<'static>,
+    kinds: &[Cave],
+    mut visited: HashSet<usize>,
+) -> usize {
+    let kind = kinds[cave];
+
+    // can't revisit small caves (or start / end)
+    if !matches!(kind, Cave::Large(_)) && visited.contains(&cave) {
+        assert!(kind != Cave::End);
+
+        return 0;
+    }
+
+    // if we hit the end, we have a complete path
+    if kind == Cave::End {
+        return 1;
+    }
+
+    visited.insert(cave);
+
+    let mut value = 0;
+
+    for cave in graph.neighbors(cave) {
+        value += part1_visit(cave, graph, kinds, visited.clone());
+    }
+
+    value
+}
+
+pub fn part1(graph: &Graph<'static>) -> usize {
+    let start = graph.index_of(Cave::Start.name()).unwrap();
+    let total = part1_visit(start, graph, &cave_kinds(graph), HashSet::new());
+
+    println!("Total paths: {}", total);
+    total
+}
+
+fn part2_visit(
+    cave: usize,
+    graph: &Graph<'static>,
+    kinds: &[Cave],
+    mut visited: HashSet<usize>,
+    mut special_small: Option<usize>,
+) -> usize {
+    let kind = kinds[cave];
+
+    // only allow revisiting a single small cave once
+    // (but never start / end)
+    if !matches!(kind, Cave::Large(_)) && visited.contains(&cave) {
+        assert!(kind != Cave::End);
+
+        if special_small.is_some() || kind == Cave::Start {
+            return 0;
+        }
+
+        special_small = Some(cave);
+    }
+
+    // if we hit the end, we have a complete path
+    if kind == Cave::End {
+        return 1;
+    }
+
+    visited.insert(cave);
+
+    let mut value = 0;
+
+    for cave in graph.neighbors(cave) {
+        value += part2_visit(cave, graph, kinds, visited.clone(), special_small);
+    }
+
+    value
+}
+
+pub fn part2(graph: &Graph<'static>) -> usize {
+    let start = graph.index_of(Cave::Start.name()).unwrap();
+    let total = part2_visit(start, graph, &cave_kinds(graph), HashSet::new(), None);
+
+    println!("Total paths: {}", total);
+    total
+}
+
+pub fn parse(input: &'static str) -> Result<Graph<'static>, ParseError> {
+    let paths: Vec<(&str, &str)> = input
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            let Some((from, to)) = x.split_once('-') else {
+                return Some(Err(ParseError::new(input, x, "expected from-to")));
+            };
+
+            Some(Ok((from, to)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut graph = Graph::undirected();
+    for path in paths {
+        graph.add_edge(path.0, path.1, ());
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let graph = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&graph), 10);
+        assert_eq!(part2(&graph), 36);
+    }
+
+    #[test]
+    fn example_larger() {
+        let graph = parse(include_str!("../examples/example2.txt")).unwrap();
+
+        assert_eq!(part1(&graph), 19);
+        assert_eq!(part2(&graph), 103);
+    }
+
+    #[test]
+    fn example_even_larger() {
+        let graph = parse(include_str!("../examples/example3.txt")).unwrap();
+
+        assert_eq!(part1(&graph), 226);
+        assert_eq!(part2(&graph), 3509);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("start-A\nA=end\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "A=end");
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+
+use aoc_ocr::OcrError;
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    grid: Vec<Vec<bool>>,
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.grid {
+            for v in row {
+                write!(f, "{}", if *v { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Vec<(usize, usize)>> for Grid {
+    fn from(input: Vec<(usize, usize)>) -> Self {
+        let (width, height) = input
+            .iter()
+            .fold((0, 0), |a, &v| (a.0.max(v.0), a.1.max(v.1)));
+        assert!(width > 0 && height > 0);
+
+        let mut grid = vec![vec![false; width + 1]; height + 1];
+        for v in input {
+            grid[v.1][v.0] = true;
+        }
+
+        Self { grid }
+    }
+}
+
+impl Grid {
+    fn width(&self) -> usize {
+        self.grid[0].len()
+    }
+
+    fn height(&self) -> usize {
+        self.grid.len()
+    }
+
+    fn dot_count(&self) -> usize {
+        self.grid
+            .iter()
+            .flat_map(|y| y.iter().filter(|&&x| x))
+            .count()
+    }
+
+    fn fold(&self, folds: impl AsRef<[Fold]>) -> Self {
+        let fold = folds.as_ref()[0];
+
+        let (width, height) = match fold {
+            Fold::Horizontal(y) => (self.width(), self.height() - y - 1),
+            Fold::Vertical(x) => (self.width() - x - 1, self.height()),
+        };
+
+        let grid: Vec<Vec<bool>> = self
+            .grid
+            .iter()
+            .take(height)
+            .cloned()
+            .map(|mut x| {
+                x.truncate(width);
+                x
+            })
+            .collect();
+        let mut folded = Self { grid };
+
+        match fold {
+            Fold::Horizontal(v) => {
+                for y in self.height() - v..self.height() {
+                    for x in 0..self.width() {
+                        let yf = y - v - 1;
+                        let ys = self.height() - yf - 1;
+                        folded.grid[yf][x] |= self.grid[ys][x];
+                    }
+                }
+            }
+            Fold::Vertical(v) => {
+                for y in 0..self.height() {
+                    for x in self.width() - v..self.width() {
+                        let xf = x - v - 1;
+                        let xs = self.width() - xf - 1;
+                        folded.grid[y][xf] |= self.grid[y][xs];
+                    }
+                }
+            }
+        };
+
+        if folds.as_ref().len() > 1 {
+            folded.fold(&folds.as_ref()[1..])
+        } else {
+            folded
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fold {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+pub fn part1(grid: &Grid, folds: impl AsRef<[Fold]>) -> usize {
+    let folded = grid.fold(&folds.as_ref()[..1]);
+    let dot_count = folded.dot_count();
+    println!("There are {} dots visible after 1 fold", dot_count);
+    dot_count
+}
+
+pub fn part2(grid: &Grid, folds: impl AsRef<[Fold]>) -> Result<String, OcrError> {
+    let folded = grid.fold(folds);
+    let dot_count = folded.dot_count();
+    println!("There are {} dots visible after all folds", dot_count);
+    #[cfg(feature = "debugvis")]
+    println!("{}", folded);
+
+    let letters = aoc_ocr::recognize(&folded.grid)?;
+    println!("Letters: {}", letters);
+    Ok(letters)
+}
+
+pub fn parse(input: &str) -> Result<(Grid, Vec<Fold>), ParseError> {
+    let (dots, folds) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::truncated(input, "expected a blank line and then the folds"))?;
+    let dots: Vec<(usize, usize)> = dots
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            let Some((dx, dy)) = x.split_once(',') else {
+                return Some(Err(ParseError::new(input, x, "expected x,y")));
+            };
+            Some(parse_at(input, dx).and_then(|dx| Ok((dx, parse_at(input, dy)?))))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let folds: Vec<Fold> = folds
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            let fold = match x.split_once('=') {
+                Some(("fold along x", v)) => parse_at(input, v).map(Fold::Vertical),
+                Some(("fold along y", v)) => parse_at(input, v).map(Fold::Horizontal),
+                _ => Err(ParseError::new(input, x, "expected fold along x=n or y=n")),
+            };
+            Some(fold)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((dots.into(), folds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (grid, folds) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&grid, &folds), 17);
+
+        // the example folds into a square rather than letters
+        let folded = grid.fold(&folds);
+        assert_eq!(folded.dot_count(), 16);
+        assert_eq!(
+            folded.to_string(),
+            "#####\n#...#\n#...#\n#...#\n#####\n.....\n.....\n"
+        );
+        assert!(part2(&grid, &folds).is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("6,10\n0,14\n9,x\n\nfold along y=7\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+
+        let err = parse("6,10\n0,14\n\nfold along y=7\nfold along z=5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.message, "expected fold along x=n or y=n");
+
+        let err = parse("6,10\n0,14\n").unwrap_err();
+        assert_eq!(err.message, "expected a blank line and then the folds");
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use aoc_parse::ParseError;
+
+// solution here largely taken from https://github.com/3ach
+
+// pair -> the element inserted between them
+type Rules = HashMap<(char, char), char>;
+
+fn step(pairs: HashMap<(char, char), usize>, rules: &Rules) -> HashMap<(char, char), usize> {
+    let mut result = HashMap::new();
+    for (pair, count) in &pairs {
+        let ch = rules.get(pair).unwrap();
+
+        let a = (pair.0, *ch);
+        let entry = result.entry(a).or_insert(0);
+        *entry += *count;
+
+        let b = (*ch, pair.1);
+        let entry = result.entry(b).or_insert(0);
+        *entry += *count;
+    }
+    result
+}
+
+pub fn run(template: impl AsRef<str>, rules: &Rules, steps: usize) -> usize {
+    // get the initial count of pairs
+    let mut pairs: HashMap<(char, char), usize> = HashMap::new();
+    for window in template.as_ref().as_bytes().windows(2) {
+        let pair = (window[0] as char, window[1] as char);
+        let entry = pairs.entry(pair).or_insert(0);
+        *entry += 1;
+    }
+
+    for _ in 0..steps {
+        pairs = step(pairs, rules);
+    }
+
+    // count the characters
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for (pair, count) in &pairs {
+        let entry = counts.entry(pair.0).or_insert(0);
+        *entry += *count;
+
+        let entry = counts.entry(pair.1).or_insert(0);
+        *entry += *count;
+    }
+
+    // bump the first and last template characters
+    *counts
+        .entry(template.as_ref().chars().next().unwrap())
+        .or_insert(0) += 1;
+    *counts
+        .entry(template.as_ref().chars().last().unwrap())
+        .or_insert(0) += 1;
+
+    // account for doubling up on everything
+    for count in counts.values_mut() {
+        *count /= 2;
+    }
+
+    // find the min / max counts
+    let (min, max) = counts
+        .values()
+        .fold((usize::MAX, 0_usize), |(min, max), &count| {
+            (count.min(min), count.max(max))
+        });
+    max - min
+}
+
+pub fn parse(input: &str) -> Result<(&str, Rules), ParseError> {
+    let (template, rules) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::truncated(input, "expected a blank line and then the rules"))?;
+    let template = template.trim();
+    if template.is_empty() {
+        return Err(ParseError::new(
+            input,
+            template,
+            "expected a polymer template",
+        ));
+    }
+
+    let rules = rules
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            let rule = match x.split_once("->").map(|(x, y)| (x.trim(), y.trim())) {
+                Some((x, y)) if x.chars().count() == 2 && y.chars().count() == 1 => {
+                    let mut x = x.chars();
+                    Ok((
+                        (x.next().unwrap(), x.next().unwrap()),
+                        y.chars().next().unwrap(),
+                    ))
+                }
+                _ => Err(ParseError::new(input, x, "expected a pair rule, AB -> C")),
+            };
+            Some(rule)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((template, rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (template, rules) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(run(template, &rules, 10), 1588);
+        assert_eq!(run(template, &rules, 40), 2188189693529);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("NNCB\n\nCH -> B\nHHH -> N\n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.text, "HHH -> N");
+
+        let err = parse("NNCB\nCH -> B\n").unwrap_err();
+        assert_eq!(err.message, "expected a blank line and then the rules");
+    }
+}
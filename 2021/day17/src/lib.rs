@@ -0,0 +1,130 @@
+use std::cmp::Ordering;
+
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+fn update_velocity(velocity: (isize, isize)) -> (isize, isize) {
+    let x = match 0.cmp(&velocity.0) {
+        Ordering::Greater => velocity.0 + 1,
+        Ordering::Less => velocity.0 - 1,
+        Ordering::Equal => 0,
+    };
+
+    (x, velocity.1 - 1)
+}
+
+fn update_position(position: (isize, isize), velocity: (isize, isize)) -> (isize, isize) {
+    (position.0 + velocity.0, position.1 + velocity.1)
+}
+
+fn simulate(
+    xmin: isize,
+    xmax: isize,
+    ymin: isize,
+    ymax: isize,
+    initial_velocity: (isize, isize),
+) -> Option<isize> {
+    let mut position = (0, 0);
+    let mut velocity = initial_velocity;
+
+    let mut highest = isize::MIN;
+    loop {
+        if position.1 > highest {
+            highest = position.1;
+        }
+
+        // overshot the target
+        if position.0 > xmax || position.1 < ymin {
+            return None;
+        }
+
+        // hit the mark
+        if (xmin..=xmax).contains(&position.0) && (ymin..=ymax).contains(&position.1) {
+            return Some(highest);
+        }
+
+        position = update_position(position, velocity);
+        velocity = update_velocity(velocity);
+    }
+}
+
+pub fn part1(xmin: isize, xmax: isize, ymin: isize, ymax: isize) -> isize {
+    let mut maximum = None;
+
+    // this is the most garbage brute force way of doing this ...
+    // I'm pretty sure this is a simple math problem to solve in reality
+    for vx in 0..xmax {
+        for vy in 0..ymin.abs() {
+            let highest = simulate(xmin, xmax, ymin, ymax, (vx, vy));
+            if let Some(highest) = highest {
+                if highest > maximum.unwrap_or(isize::MIN) {
+                    maximum = Some(highest);
+                }
+            }
+        }
+    }
+
+    let maximum = maximum.unwrap();
+    println!("Maximum: {}", maximum);
+
+    maximum
+}
+
+pub fn part2(xmin: isize, xmax: isize, ymin: isize, ymax: isize) -> usize {
+    let xstart = 0;
+    let xend = xmax * 2;
+    let ystart = ymin * 2;
+    let yend = ymax.abs() * 2;
+
+    // this is the most garbage brute force way of doing this ...
+    // I'm pretty sure this is a simple math problem to solve in reality
+    let mut hits = 0;
+    for vx in xstart..xend {
+        for vy in ystart..yend {
+            let highest = simulate(xmin, xmax, ymin, ymax, (vx, vy));
+            if highest.is_some() {
+                hits += 1;
+            }
+        }
+    }
+
+    println!("Velocities that hit the target: {}", hits);
+
+    hits
+}
+
+pub fn parse(input: &str) -> Result<(isize, isize, isize, isize), ParseError> {
+    let re = Regex::new(r"^target area: x=(-?\d+)\.\.(-?\d+), y=(-?\d+)\.\.(-?\d+)$").unwrap();
+    let captures = re.captures(input.trim()).ok_or_else(|| {
+        ParseError::new(input, input.trim(), "expected target area: x=a..b, y=c..d")
+    })?;
+
+    let xmin = parse_at(input, captures.get(1).unwrap().as_str())?;
+    let xmax = parse_at(input, captures.get(2).unwrap().as_str())?;
+    let ymin = parse_at(input, captures.get(3).unwrap().as_str())?;
+    let ymax = parse_at(input, captures.get(4).unwrap().as_str())?;
+
+    Ok((xmin, xmax, ymin, ymax))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (xmin, xmax, ymin, ymax) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(xmin, xmax, ymin, ymax), 45);
+        assert_eq!(part2(xmin, xmax, ymin, ymax), 112);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("target area: x=20..30, y=-10\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+
+        let err = parse("target area: x=20..30, y=-99999999999999999999..-5").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 26));
+    }
+}
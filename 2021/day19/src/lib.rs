@@ -0,0 +1,317 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+
+use glam::IVec3;
+
+#[inline]
+fn rotate_right(mut v: IVec3) -> IVec3 {
+    let z = v.z;
+    v.z = v.y;
+    v.y = v.x;
+    v.x = z;
+
+    v
+}
+
+#[inline]
+#[allow(clippy::manual_swap)]
+fn swap_xy(mut v: IVec3) -> IVec3 {
+    let x = v.x;
+    v.x = v.y;
+    v.y = x;
+
+    v
+}
+
+#[inline]
+#[allow(clippy::manual_swap)]
+fn swap_yz(mut v: IVec3) -> IVec3 {
+    let z = v.z;
+    v.z = v.y;
+    v.y = z;
+
+    v
+}
+
+#[inline]
+#[allow(clippy::manual_swap)]
+fn swap_xz(mut v: IVec3) -> IVec3 {
+    let z = v.z;
+    v.z = v.x;
+    v.x = z;
+
+    v
+}
+
+fn test_beacons(beacon: IVec3) -> Vec<IVec3> {
+    // the 24 ways a scanner can be facing,
+    // an even shuffle of the axes with an even number of them flipped
+    // or an odd shuffle with an odd number flipped (anything else is a mirror image)
+    let even_flips = [
+        IVec3::new(1, 1, 1),
+        IVec3::new(-1, -1, 1),
+        IVec3::new(1, -1, -1),
+        IVec3::new(-1, 1, -1),
+    ];
+    let odd_flips = [
+        IVec3::new(-1, 1, 1),
+        IVec3::new(1, -1, 1),
+        IVec3::new(1, 1, -1),
+        IVec3::new(-1, -1, -1),
+    ];
+
+    // x, y, z / z, x, y / y, z, x
+    let b1 = rotate_right(beacon);
+    let b2 = rotate_right(b1);
+    let even = [beacon, b1, b2];
+
+    // y, x, z / x, z, y / z, y, x
+    let odd = [swap_xy(beacon), swap_yz(beacon), swap_xz(beacon)];
+
+    let mut beacons = Vec::with_capacity(24);
+    for b in even {
+        beacons.extend(even_flips.iter().map(|flip| b * *flip));
+    }
+    for b in odd {
+        beacons.extend(odd_flips.iter().map(|flip| b * *flip));
+    }
+
+    beacons
+}
+
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    position: IVec3,
+
+    beacons: Vec<IVec3>,
+}
+
+impl Scanner {
+    fn extents(&self) -> (IVec3, IVec3) {
+        let (min, max) = self.beacons.iter().fold(
+            (
+                IVec3::new(i32::MAX, i32::MAX, i32::MAX),
+                IVec3::new(i32::MIN, i32::MIN, i32::MIN),
+            ),
+            |acc, &x| (acc.0.min(x), acc.1.max(x)),
+        );
+
+        (min, max)
+    }
+
+    fn beacon(&self, idx: usize) -> IVec3 {
+        self.beacons[idx] + self.position
+    }
+
+    #[allow(dead_code)]
+    fn check_overlaps(&self, mut scanner: Scanner, position: IVec3) -> usize {
+        scanner.position = position;
+
+        //println!("compare {} to {}", self.position, scanner.position);
+
+        let mut overlapping = 0;
+        for idx in 0..scanner.beacons.len() {
+            let beacon = scanner.beacon(idx);
+            let test = test_beacons(beacon);
+
+            if self.beacons.iter().any(|&x| {
+                test.iter().any(|&y| {
+                    //println!("comparing {} and {}", x, beacon);
+                    if x == y {
+                        //println!("overlapping {} and {}", x, y);
+                        return true;
+                    }
+
+                    //println!("not overlapping {} and {}", x, y);
+
+                    false
+                })
+            }) {
+                overlapping += 1;
+            }
+        }
+
+        overlapping
+    }
+
+    // find the first position where the required number of beacons overlap
+    fn overlaps(&self, scanner: &Scanner, required: usize) -> Option<IVec3> {
+        let (mut min, mut max) = self.extents();
+        min *= 2;
+        max *= 2;
+        println!("min: {}, max: {}", min, max);
+
+        /*for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                for z in min.z..=max.z {
+                    let position = IVec3::new(x, y, z);
+                    if position == IVec3::ZERO {
+                        println!("zero");
+                    }
+
+                    let overlapping = self.check_overlaps(scanner.clone(), position);
+
+                    if overlapping >= required {
+                        println!("found it!");
+                        return Some(IVec3::default());
+                    }
+                }
+            }
+        }*/
+
+        // test x-axis
+        let mut xscanner = scanner.clone();
+        let mut xbeacons = HashSet::new();
+        for x in min.x..=max.x {
+            xscanner.position.x = x;
+            for idx in 0..scanner.beacons.len() {
+                let beacon = scanner.beacon(idx);
+                let test = test_beacons(beacon);
+                if self
+                    .beacons
+                    .iter()
+                    .any(|&a| test.iter().any(|&b| a.x == b.x))
+                {
+                    xbeacons.insert(idx);
+                }
+            }
+        }
+        println!("matched {} x beacons", xbeacons.len());
+
+        // test y-axis
+        let mut yscanner = scanner.clone();
+        let mut ybeacons = HashSet::new();
+        for y in min.y..=max.y {
+            yscanner.position.y = y;
+            for idx in xbeacons.drain() {
+                let beacon = scanner.beacon(idx);
+                let test = test_beacons(beacon);
+                if self
+                    .beacons
+                    .iter()
+                    .any(|&a| test.iter().any(|&b| a.y == b.y))
+                {
+                    ybeacons.insert(idx);
+                }
+            }
+        }
+        println!("matched {} y beacons", ybeacons.len());
+
+        // test z-axis
+        let mut zscanner = scanner.clone();
+        let mut zbeacons = HashSet::new();
+        for z in min.z..=max.z {
+            zscanner.position.z = z;
+            for idx in ybeacons.drain() {
+                let beacon = scanner.beacon(idx);
+                let test = test_beacons(beacon);
+                if self
+                    .beacons
+                    .iter()
+                    .any(|&a| test.iter().any(|&b| a.z == b.z))
+                {
+                    zbeacons.insert(idx);
+                }
+            }
+        }
+        println!("matched {} z beacons", zbeacons.len());
+
+        if zbeacons.len() >= required {
+            println!("found it!");
+            return Some(IVec3::default());
+        }
+
+        None
+    }
+}
+
+impl FromStr for Scanner {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let beacons = input
+            .lines()
+            .skip(1)
+            .map(|x| {
+                let x = x.trim();
+                let pos = x
+                    .split(',')
+                    .map(|v| parse_at(input, v))
+                    .collect::<Result<Vec<i32>, _>>()?;
+                if pos.len() != 3 {
+                    return Err(ParseError::new(input, x, "expected x,y,z"));
+                }
+                Ok(IVec3::new(pos[0], pos[1], pos[2]))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            position: IVec3::default(),
+
+            beacons,
+        })
+    }
+}
+
+pub fn part1(scanners: impl AsRef<[Scanner]>) {
+    let scanners = scanners.as_ref();
+
+    for (idx, scanner) in scanners.iter().enumerate().skip(1) {
+        let overlapped = scanners[0].overlaps(scanner, 6);
+        println!("scanner 0 overlapped scanner {} at {:?}", idx, overlapped);
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    input
+        .trim()
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|err: ParseError| err.within(input, x)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the same scanner in a few different orientations,
+    // there should be one orientation that takes the first to each of the others
+    #[test]
+    fn orientations() {
+        let scanners = parse(include_str!("../examples/orientations.txt")).unwrap();
+
+        let reference = &scanners[0];
+        for scanner in &scanners[1..] {
+            let orientation = (0..test_beacons(IVec3::ZERO).len()).find(|&i| {
+                reference
+                    .beacons
+                    .iter()
+                    .zip(&scanner.beacons)
+                    .all(|(a, b)| test_beacons(*a)[i] == *b)
+            });
+            assert!(orientation.is_some(), "{:?}", scanner.beacons);
+        }
+    }
+
+    // rotations only, no mirror images
+    #[test]
+    fn rotations() {
+        let x = IVec3::new(1, 0, 0);
+        let y = IVec3::new(0, 1, 0);
+        let z = IVec3::new(0, 0, 1);
+
+        let (xs, ys, zs) = (test_beacons(x), test_beacons(y), test_beacons(z));
+        assert_eq!(xs.len(), 24);
+
+        let mut seen = HashSet::new();
+        for i in 0..xs.len() {
+            // the determinant of the rotation matrix
+            assert_eq!(xs[i].cross(ys[i]).dot(zs[i]), 1);
+            assert!(seen.insert((xs[i], ys[i], zs[i])));
+        }
+    }
+}
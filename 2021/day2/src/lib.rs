@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Copy, Clone)]
+pub enum Direction {
+    Forward,
+    Down,
+    Up,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "forward" => Ok(Self::Forward),
+            "down" => Ok(Self::Down),
+            "up" => Ok(Self::Up),
+            _ => Err(ParseError::new(
+                value,
+                value,
+                "expected forward, down or up",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Command {
+    pub direction: Direction,
+    pub amount: isize,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (direction, amount) = value
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(value, value, "expected a direction and an amount"))?;
+        Ok(Command {
+            direction: direction
+                .parse()
+                .map_err(|err: ParseError| err.within(value, direction))?,
+            amount: parse_at(value, amount.trim())?,
+        })
+    }
+}
+
+pub fn part1(commands: impl AsRef<[Command]>) -> isize {
+    let mut horizontal = 0;
+    let mut vertical = 0;
+
+    for command in commands.as_ref() {
+        match command.direction {
+            Direction::Forward => horizontal += command.amount,
+            Direction::Down => vertical += command.amount,
+            Direction::Up => vertical -= command.amount,
+        }
+    }
+
+    println!(
+        "Horizontal: {}, Vertical: {}, Result: {}",
+        horizontal,
+        vertical,
+        horizontal * vertical
+    );
+    horizontal * vertical
+}
+
+pub fn part2(commands: impl AsRef<[Command]>) -> isize {
+    let mut aim = 0;
+    let mut horizontal = 0;
+    let mut depth = 0;
+
+    for command in commands.as_ref() {
+        match command.direction {
+            Direction::Forward => {
+                horizontal += command.amount;
+                depth += aim * command.amount;
+            }
+            Direction::Down => aim += command.amount,
+            Direction::Up => aim -= command.amount,
+        }
+    }
+
+    println!(
+        "Horizontal: {}, Depth: {}, Result: {}",
+        horizontal,
+        depth,
+        horizontal * depth
+    );
+    horizontal * depth
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|err: ParseError| err.within(input, x)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let commands = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&commands), 150);
+        assert_eq!(part2(&commands), 900);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("forward 5\ndown 5\nbackward 8").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "backward");
+
+        let err = parse("forward 5\ndown").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
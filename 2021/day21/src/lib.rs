@@ -0,0 +1,263 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use aoc_parse::{parse_at, ParseError};
+use cached::proc_macro::cached;
+use regex::Regex;
+
+#[derive(Debug)]
+struct DeterministicDie {
+    sides: usize,
+    counter: RefCell<usize>,
+    rolls: RefCell<usize>,
+}
+
+impl DeterministicDie {
+    fn new(sides: usize) -> Self {
+        Self {
+            sides,
+            counter: RefCell::new(0),
+            rolls: RefCell::new(0),
+        }
+    }
+
+    fn roll(&self) -> usize {
+        let counter = *self.counter.borrow();
+        let ret = 1 + counter;
+
+        *self.counter.borrow_mut() = (counter + 1) % self.sides;
+        *self.rolls.borrow_mut() += 1;
+
+        ret
+    }
+
+    fn roll_count(&self) -> usize {
+        *self.rolls.borrow()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pawn {
+    position: usize,
+    score: usize,
+}
+
+impl Pawn {
+    fn new(position: usize) -> Self {
+        Self { position, score: 0 }
+    }
+
+    fn move_spaces(&mut self, track_len: usize, spaces: usize) {
+        self.position = (self.position + spaces) % track_len;
+        self.score += self.position + 1;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Universe {
+    player1: Pawn,
+    player2: Pawn,
+}
+
+impl Universe {
+    fn new(player1: Pawn, player2: Pawn) -> Self {
+        Self { player1, player2 }
+    }
+}
+
+pub fn part1(mut player1: Pawn, mut player2: Pawn, track_len: usize, max_score: usize) -> usize {
+    let die = DeterministicDie::new(100);
+
+    let loser_score = loop {
+        let spaces = die.roll() + die.roll() + die.roll();
+        player1.move_spaces(track_len, spaces);
+        if player1.score >= max_score {
+            break player2.score;
+        }
+
+        let spaces = die.roll() + die.roll() + die.roll();
+        player2.move_spaces(track_len, spaces);
+        if player2.score >= max_score {
+            break player1.score;
+        }
+    };
+
+    let total = loser_score * die.roll_count();
+    println!(
+        "Loser total {}*{} = {}",
+        loser_score,
+        die.roll_count(),
+        total
+    );
+
+    total
+}
+
+const SPACES: [(usize, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+#[cached]
+fn simulate_player1(
+    universe: Universe,
+    track_len: usize,
+    max_score: usize,
+) -> (u64, HashMap<Universe, u64>) {
+    let mut spawned = vec![universe; SPACES.len()];
+    let mut universes = HashMap::new();
+    let mut player1wins = 0;
+    for (i, universe) in spawned.iter_mut().enumerate() {
+        let spaces = SPACES[i];
+        universe.player1.move_spaces(track_len, spaces.0);
+        if universe.player1.score >= max_score {
+            player1wins += spaces.1;
+        } else {
+            *universes.entry(universe.clone()).or_insert(0) += spaces.1;
+        }
+    }
+    (player1wins, universes)
+}
+
+#[cached]
+fn simulate_player2(
+    universe: Universe,
+    track_len: usize,
+    max_score: usize,
+) -> (u64, HashMap<Universe, u64>) {
+    let mut spawned = vec![universe; SPACES.len()];
+    let mut universes = HashMap::new();
+    let mut player2wins = 0;
+    for (i, universe) in spawned.iter_mut().enumerate() {
+        let spaces = SPACES[i];
+        universe.player2.move_spaces(track_len, spaces.0);
+        if universe.player2.score >= max_score {
+            player2wins += spaces.1;
+        } else {
+            *universes.entry(universe.clone()).or_insert(0) += spaces.1;
+        }
+    }
+    (player2wins, universes)
+}
+
+#[cached]
+fn simulate(
+    universe: Universe,
+    track_len: usize,
+    max_score: usize,
+) -> (u64, u64, HashMap<Universe, u64>) {
+    let mut player1wins = 0;
+    let mut player2wins = 0;
+
+    // spawn all of the possible universes for player 1
+    let (wins, mut spawned) = simulate_player1(universe, track_len, max_score);
+    player1wins += wins;
+
+    // spawn all of the possible universes for player 2
+    let spawned: Vec<HashMap<Universe, u64>> = spawned
+        .drain()
+        .map(|(universe, count)| {
+            let (wins, mut spawned) = simulate_player2(universe, track_len, max_score);
+            player2wins += wins * count;
+            spawned.values_mut().for_each(|c| *c *= count);
+            spawned
+        })
+        .collect();
+
+    // combine
+    let mut universes = HashMap::new();
+    for entry in spawned {
+        for (universe, count) in entry {
+            *universes.entry(universe).or_insert(0) += count;
+        }
+    }
+
+    (player1wins, player2wins, universes)
+}
+
+pub fn part2(player1: Pawn, player2: Pawn, track_len: usize, max_score: usize) -> (u64, u64) {
+    let mut universes = HashMap::new();
+    universes.insert(Universe::new(player1, player2), 1);
+
+    let mut player1wins = 0_u64;
+    let mut player2wins = 0_u64;
+
+    while !universes.is_empty() {
+        // spawn all of the possible universes
+        let spawned: Vec<HashMap<Universe, u64>> = universes
+            .drain()
+            .map(|(universe, count)| {
+                let (p1wins, p2wins, mut spawned) = simulate(universe, track_len, max_score);
+                player1wins += p1wins * count;
+                player2wins += p2wins * count;
+                spawned.values_mut().for_each(|c| *c *= count);
+                spawned
+            })
+            .collect();
+
+        // combine
+        for entry in spawned {
+            for (universe, count) in entry {
+                *universes.entry(universe).or_insert(0) += count;
+            }
+        }
+    }
+
+    println!(
+        "Player 1 won {} universes, player 2 won {} universes",
+        player1wins, player2wins
+    );
+
+    (player1wins, player2wins)
+}
+
+pub fn parse(input: &str) -> Result<(Pawn, Pawn), ParseError> {
+    let re = Regex::new(
+        r"^Player 1 starting position: (\d+)\r?
+Player 2 starting position: (\d+)$",
+    )
+    .unwrap();
+    let captures = re.captures(input.trim()).ok_or_else(|| {
+        ParseError::new(
+            input,
+            input.trim(),
+            "expected both players' starting positions",
+        )
+    })?;
+
+    // positions start at 1
+    let start = |idx| {
+        let text = captures.get(idx).unwrap().as_str();
+        match parse_at::<usize>(input, text)? {
+            0 => Err(ParseError::new(input, text, "expected a position from 1")),
+            position => Ok(position),
+        }
+    };
+    let p1start = start(1)?;
+    let p2start = start(2)?;
+
+    Ok((Pawn::new(p1start - 1), Pawn::new(p2start - 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (player1, player2) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(player1, player2, 10, 1000), 739785);
+        assert_eq!(
+            part2(player1, player2, 10, 21),
+            (444356092776315, 341960390180808)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let err =
+            parse("Player 1 starting position: 4\nPlayer 2 starting position: 0\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 29));
+
+        let err = parse("Player 1 starting position: 4\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
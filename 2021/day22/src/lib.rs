@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(\w+) x=(-?\d+)..(-?\d+),y=(-?\d+)..(-?\d+),z=(-?\d+)..(-?\d+)").unwrap()
+});
+
+#[derive(Debug)]
+enum Toggle {
+    On,
+    Off,
+}
+
+impl FromStr for Toggle {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "on" => Ok(Self::On),
+            "off" => Ok(Self::Off),
+            _ => Err(ParseError::new(input, input, "expected on or off")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    toggle: Toggle,
+
+    x: std::ops::RangeInclusive<isize>,
+    y: std::ops::RangeInclusive<isize>,
+    z: std::ops::RangeInclusive<isize>,
+}
+
+impl Instruction {
+    fn on(&self) -> bool {
+        matches!(self.toggle, Toggle::On)
+    }
+
+    fn is_part1_valid(&self) -> bool {
+        (*self.x.start() <= 50 && *self.x.end() >= -50)
+            && (*self.y.start() <= 50 && *self.y.end() >= -50)
+            && (*self.z.start() <= 50 && *self.z.end() >= -50)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let captures = REGEX
+            .captures(input)
+            .ok_or_else(|| ParseError::new(input, input, "expected on|off x=a..b,y=a..b,z=a..b"))?;
+
+        let toggle: Toggle = parse_at(input, &captures[1])?;
+        let range = |start, end| -> Result<_, ParseError> {
+            Ok(parse_at(input, &captures[start])?..=parse_at(input, &captures[end])?)
+        };
+
+        Ok(Self {
+            toggle,
+            x: range(2, 3)?,
+            y: range(4, 5)?,
+            z: range(6, 7)?,
+        })
+    }
+}
+
+pub fn part1(instructions: impl AsRef<[Instruction]>) -> usize {
+    let mut reactor = HashMap::new();
+
+    for instruction in instructions.as_ref() {
+        if !instruction.is_part1_valid() {
+            continue;
+        }
+
+        for x in instruction.x.clone() {
+            if !(-50..=50).contains(&x) {
+                continue;
+            }
+
+            for y in instruction.y.clone() {
+                if !(-50..=50).contains(&y) {
+                    continue;
+                }
+
+                for z in instruction.z.clone() {
+                    if !(-50..=50).contains(&z) {
+                        continue;
+                    }
+
+                    let coord = (x, y, z);
+                    reactor.insert(coord, instruction.on());
+                }
+            }
+        }
+    }
+
+    let enabled: usize = reactor.iter().filter(|(_, &v)| v).count();
+    println!("There are {} valid enabled cubes", enabled);
+
+    enabled
+}
+
+#[allow(dead_code)]
+pub fn part2(instructions: impl AsRef<[Instruction]>) {
+    let mut reactor = HashMap::new();
+
+    // TODO: the ranges here are too big for this
+    // so we need to find a more optimal way of handling this
+    for instruction in instructions.as_ref() {
+        for x in instruction.x.clone() {
+            for y in instruction.y.clone() {
+                for z in instruction.z.clone() {
+                    let coord = (x, y, z);
+                    reactor.insert(coord, instruction.on());
+                }
+            }
+        }
+    }
+
+    let enabled: usize = reactor.iter().filter(|(_, &v)| v).count();
+    println!("There are {} enabled cubes", enabled);
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|err: ParseError| err.within(input, x)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // part 2 isn't finished yet, so only part 1 is checked here
+
+    #[test]
+    fn example() {
+        let instructions = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(instructions), 39);
+    }
+}
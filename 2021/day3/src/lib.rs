@@ -0,0 +1,142 @@
+use aoc_parse::ParseError;
+
+fn most_common_values(values: impl AsRef<[String]>) -> Vec<usize> {
+    // assume all the values are the same length
+    let bitcount = values.as_ref()[0].len();
+
+    let mut x = vec![0_isize; bitcount];
+    for value in values.as_ref() {
+        for (i, c) in value.chars().enumerate() {
+            x[i] += if c == '0' { -1 } else { 1 };
+        }
+    }
+
+    // convert the result to 0 / 1
+    x.iter().map(|&v| usize::from(v >= 0)).collect()
+}
+
+pub fn part1(values: impl AsRef<[String]>) -> usize {
+    let m = most_common_values(values);
+
+    let mut v = 0;
+    for (i, x) in m.iter().enumerate() {
+        let mask = x << (m.len() - 1 - i);
+        v |= mask;
+    }
+
+    let mut nv = v;
+    for i in 0..m.len() {
+        let mask = 1 << i;
+        nv ^= mask;
+    }
+
+    println!("{} * {} = {}", v, nv, v * nv);
+    v * nv
+}
+
+pub fn part2(values: &[String]) -> usize {
+    let mut oxygen = values.to_owned();
+    let mut co2 = values.to_owned();
+
+    let mut i = 0;
+    loop {
+        if oxygen.len() == 1 && co2.len() == 1 {
+            break;
+        }
+
+        if oxygen.len() > 1 {
+            let m = most_common_values(&oxygen);
+            oxygen.retain(|x| {
+                let ch = x.chars().nth(i).unwrap();
+                if m[i] == 0 {
+                    ch == '0'
+                } else {
+                    ch == '1'
+                }
+            });
+        }
+
+        if co2.len() > 1 {
+            let m = most_common_values(&co2);
+            co2.retain(|x| {
+                let ch = x.chars().nth(i).unwrap();
+                if m[i] == 0 {
+                    ch == '1'
+                } else {
+                    ch == '0'
+                }
+            });
+        }
+
+        i += 1;
+    }
+
+    let oxygen = usize::from_str_radix(&oxygen[0], 2).unwrap();
+    let co2 = usize::from_str_radix(&co2[0], 2).unwrap();
+
+    println!("{} * {} = {}", oxygen, co2, oxygen * co2);
+    oxygen * co2
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let values = input
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>();
+
+    // the parts assume every value is the same number of bits
+    let Some(bitcount) = values.first().map(|x| x.len()) else {
+        return Err(ParseError::truncated(input, "expected some values"));
+    };
+    if bitcount > usize::BITS as usize {
+        return Err(ParseError::new(input, values[0], "too many bits"));
+    }
+
+    values
+        .into_iter()
+        .map(|x| {
+            if let Some((idx, ch)) = x.char_indices().find(|(_, ch)| *ch != '0' && *ch != '1') {
+                let ch = &x[idx..idx + ch.len_utf8()];
+                return Err(ParseError::new(input, ch, "expected 0 or 1"));
+            }
+
+            if x.len() != bitcount {
+                return Err(ParseError::new(
+                    input,
+                    x,
+                    format!("expected {} bits", bitcount),
+                ));
+            }
+
+            Ok(x.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 198);
+        assert_eq!(part2(&values), 230);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("00100\n11110\n10I10\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.text, "I");
+
+        let err = parse("00100\n11110\n1011\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.message, "expected 5 bits");
+
+        let err = parse("\n").unwrap_err();
+        assert_eq!(err.message, "expected some values");
+    }
+}
@@ -0,0 +1,178 @@
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    grid: Vec<Vec<(usize, bool)>>,
+
+    score: Option<usize>,
+}
+
+impl Board {
+    // rows are slices of source, so errors can point at them
+    fn new(source: &str, rows: &[&str]) -> Result<Self, ParseError> {
+        if rows.len() != 5 {
+            return Err(ParseError::truncated(source, "expected 5 rows per board"));
+        }
+
+        let grid = rows
+            .iter()
+            .map(|line| {
+                let row = line
+                    .split_whitespace()
+                    .map(|x| Ok((parse_at(source, x)?, false)))
+                    .collect::<Result<Vec<_>, ParseError>>()?;
+                if row.len() != 5 {
+                    return Err(ParseError::new(source, line, "expected 5 numbers"));
+                }
+                Ok(row)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { grid, score: None })
+    }
+
+    fn mark(&mut self, drawing: usize) {
+        if self.score.is_some() {
+            return;
+        }
+
+        for row in &mut self.grid {
+            for col in row {
+                if col.0 == drawing {
+                    col.1 = true;
+                    break;
+                }
+            }
+        }
+
+        self.check_winner(drawing);
+    }
+
+    fn check_winner(&mut self, drawing: usize) {
+        if self.score.is_some() {
+            return;
+        }
+
+        for row in &self.grid {
+            if row.iter().all(|x| x.1) {
+                self.score = Some(self.score(drawing));
+                return;
+            }
+        }
+
+        for col in 0..self.grid[0].len() {
+            if self.grid.iter().all(|row| row[col].1) {
+                self.score = Some(self.score(drawing));
+                return;
+            }
+        }
+    }
+
+    fn score(&self, drawing: usize) -> usize {
+        let score: usize = self
+            .grid
+            .iter()
+            .flatten()
+            .map(|col| if col.1 { 0 } else { col.0 })
+            .sum();
+        score * drawing
+    }
+}
+
+pub fn part1(drawings: impl AsRef<[usize]>, mut boards: Vec<Board>) -> usize {
+    for (di, drawing) in drawings.as_ref().iter().enumerate() {
+        for (bi, board) in boards.iter_mut().enumerate() {
+            board.mark(*drawing);
+            if let Some(score) = board.score {
+                println!(
+                    "board {} is the winner after drawing {} ({}): {}",
+                    bi, di, drawing, score
+                );
+                return score;
+            }
+        }
+    }
+
+    panic!("no winning board!");
+}
+
+pub fn part2(drawings: impl AsRef<[usize]>, mut boards: Vec<Board>) -> usize {
+    // reverse removal from https://github.com/zertosh/
+    let mut winners = Vec::with_capacity(boards.len());
+    for drawing in drawings.as_ref() {
+        for i in (0..boards.len()).rev() {
+            boards[i].mark(*drawing);
+            if boards[i].score.is_some() {
+                let board = boards.remove(i);
+                winners.push(board);
+            }
+        }
+
+        if boards.is_empty() {
+            break;
+        }
+    }
+
+    let last = winners.last().unwrap();
+
+    println!("last winning board score is {}", last.score.unwrap());
+    last.score.unwrap()
+}
+
+pub fn parse(input: &str) -> Result<(Vec<usize>, Vec<Board>), ParseError> {
+    let drawings: Vec<usize> = input
+        .lines()
+        .next()
+        .unwrap_or("")
+        .split(',')
+        .map(|x| parse_at(input, x.trim()))
+        .collect::<Result<_, _>>()?;
+
+    let board_input: Vec<&str> = input
+        .lines()
+        .skip(1)
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            Some(x)
+        })
+        .collect();
+
+    let boards = board_input
+        .chunks(5)
+        .map(|rows| Board::new(input, rows))
+        .collect::<Result<_, _>>()?;
+
+    Ok((drawings, boards))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (drawings, boards) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&drawings, boards.clone()), 4512);
+        assert_eq!(part2(&drawings, boards), 1924);
+    }
+
+    #[test]
+    fn malformed() {
+        let board =
+            "22 13 17 11  0\n 8  2 23  4 24\n21  9 14 16  7\n 6 10  3 18  5\n 1 12 20 15 19\n";
+
+        let err = parse(&format!("7,4,x\n\n{}", board)).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+
+        let err = parse(&format!("7,4\n\n{}\n{}", board, &board[..40])).unwrap_err();
+        assert_eq!(err.message, "expected 5 rows per board");
+
+        let err = parse(&format!("7,4\n\n{}", board.replace(" 7\n", "\n"))).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (5, "expected 5 numbers"));
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Vec2 {
+    x: isize,
+    y: isize,
+}
+
+impl FromStr for Vec2 {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> Result<Self, Self::Err> {
+        let v = item
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(item, item.trim(), "expected x,y"))?;
+        Ok(Self {
+            x: parse_at(item, v.0.trim())?,
+            y: parse_at(item, v.1.trim())?,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Grid {
+    grid: Vec<Vec<usize>>,
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} rows", self.grid.len())?;
+        for row in &self.grid {
+            for col in row {
+                if *col == 0 {
+                    write!(f, ".")?;
+                } else {
+                    write!(f, "{}", col)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Grid {
+    fn new(width: usize, height: usize) -> Self {
+        let mut grid = Vec::with_capacity(height + 1);
+        for _ in 0..grid.capacity() {
+            grid.push(vec![0; width + 1]);
+        }
+        Self { grid }
+    }
+
+    fn set(&mut self, pos: Vec2) {
+        let x = pos.x as usize;
+        let y = pos.y as usize;
+
+        let row = self.grid.get_mut(y).unwrap();
+        row[x] = row.get(x).unwrap() + 1;
+    }
+
+    fn apply_path(&mut self, path: (Vec2, Vec2), allow_diagonal: bool) {
+        let dx = path.1.x - path.0.x;
+        let dy = path.1.y - path.0.y;
+        if dx != 0 && dy != 0 && (!allow_diagonal || dx.abs() != dy.abs()) {
+            return;
+        }
+
+        let mut v = path.0;
+        while v.x != path.1.x || v.y != path.1.y {
+            self.set(v);
+
+            v.x += dx.signum();
+            v.y += dy.signum();
+        }
+        self.set(v);
+    }
+
+    fn dangerous_area_count(&self) -> usize {
+        let mut count = 0;
+        for row in &self.grid {
+            for col in row {
+                if *col >= 2 {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+pub fn part1(mut grid: Grid, paths: impl AsRef<[(Vec2, Vec2)]>) -> usize {
+    for path in paths.as_ref() {
+        grid.apply_path(*path, false);
+    }
+    //println!("{}", grid);
+
+    println!("There are {} dangerous areas", grid.dangerous_area_count());
+    grid.dangerous_area_count()
+}
+
+pub fn part2(mut grid: Grid, paths: impl AsRef<[(Vec2, Vec2)]>) -> usize {
+    for path in paths.as_ref() {
+        grid.apply_path(*path, true);
+    }
+    //println!("{}", grid);
+
+    println!("There are {} dangerous areas", grid.dangerous_area_count());
+    grid.dangerous_area_count()
+}
+
+pub fn parse(input: &str) -> Result<(Grid, Vec<(Vec2, Vec2)>), ParseError> {
+    let paths: Vec<(Vec2, Vec2)> = input
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            let v = x
+                .split_once("->")
+                .ok_or_else(|| ParseError::new(input, x, "expected x1,y1 -> x2,y2"))?;
+            let start =
+                v.0.parse()
+                    .map_err(|err: ParseError| err.within(input, v.0))?;
+            let end =
+                v.1.parse()
+                    .map_err(|err: ParseError| err.within(input, v.1))?;
+            Ok((start, end))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let width = paths.iter().fold(0, |v, p| p.0.x.max(p.1.x).max(v));
+    let height = paths.iter().fold(0, |v, p| p.0.y.max(p.1.y).max(v));
+    let grid = Grid::new(width as usize, height as usize);
+
+    Ok((grid, paths))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (grid, paths) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(grid.clone(), &paths), 5);
+        assert_eq!(part2(grid, &paths), 12);
+    }
+}
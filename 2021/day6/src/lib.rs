@@ -0,0 +1,70 @@
+use aoc_parse::{parse_at, ParseError};
+
+// I originally tried to brute force simulate this one
+// but at 256 days that becomes impossible, even with rayon added in to help
+// taking inspiration again from https://github.com/zertosh/
+// the better approach is to just keep a count of the number of fish at each age
+// and use Vec's rotate_left to cycle them
+
+pub fn simulate(initialages: impl AsRef<[usize]>, days: usize) -> usize {
+    // count the number of fish at each initial age
+    let mut ages: [usize; 9] = [0; 9];
+    for age in initialages.as_ref() {
+        ages[*age] += 1;
+    }
+
+    // run the simulation
+    for _ in 0..days {
+        // cycle the set of ages
+        // this adds n new fish at age 8
+        ages.rotate_left(1);
+
+        // add back in all of the fish
+        // that started over at age 6
+        ages[6] += ages[8];
+    }
+
+    let count: usize = ages.iter().sum();
+    println!("After {} days there are {} lanternfish", days, count);
+
+    count
+}
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            let v = x.split(',').map(|x| parse_at(input, x.trim()));
+            Some(v)
+        })
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let initialages = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(simulate(&initialages, 18), 26);
+        assert_eq!(simulate(&initialages, 80), 5934);
+        assert_eq!(simulate(&initialages, 256), 26984457539);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("3,4,3,1,,2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+
+        let err = parse("3,4,3,a,2\n").unwrap_err();
+        assert_eq!(err.text, "a");
+    }
+}
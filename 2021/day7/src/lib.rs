@@ -0,0 +1,101 @@
+use aoc_parse::{parse_at, ParseError};
+
+pub fn part1(positions: Vec<usize>) -> isize {
+    let (min, max) = positions
+        .iter()
+        .fold((0, 0), |acc, &x| (acc.0.min(x), acc.1.max(x)));
+
+    let mut v = vec![0; max - min];
+    for position in positions {
+        for x in min..max {
+            let fuel = (position as isize - x as isize).abs();
+            v[x - min] += fuel;
+        }
+    }
+
+    let cheapest = *v.iter().min().unwrap();
+    println!("Cheapest move: {}", cheapest);
+
+    // https://github.com/zertosh/ :
+    /*
+    positions.sort();
+
+    let median = positions[positions.len() / 2];
+    let min_fuel = positions
+        .iter()
+        .map(|x| if *x < median { median - x } else { x - median })
+        .sum::<usize>();
+    */
+
+    cheapest
+}
+
+pub fn part2(positions: Vec<usize>) -> isize {
+    let (min, max) = positions
+        .iter()
+        .fold((0, 0), |acc, &x| (acc.0.min(x), acc.1.max(x)));
+
+    let mut v = vec![0; max - min];
+    for position in positions {
+        for x in min..max {
+            let fuel = (position as isize - x as isize).abs();
+            // (0..=fuel).sum() == fuel * (fuel + 1) / 2
+            v[x - min] += fuel * (fuel + 1) / 2;
+        }
+    }
+
+    let cheapest = *v.iter().min().unwrap();
+    println!("Cheapest move: {}", cheapest);
+
+    // https://github.com/zertosh/ :
+    /*
+    let avg = input.iter().sum::<usize>() / input.len();
+    let min_fuel = input
+        .iter()
+        .map(|x| if *x < avg { avg - x } else { x - avg })
+        .map(|x| {
+            (x.pow(2) + x) / 2 // https://en.wikipedia.org/wiki/Summation
+        })
+        .sum::<usize>();
+    */
+
+    cheapest
+}
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            let v = x.split(',').map(|x| parse_at(input, x.trim()));
+            Some(v)
+        })
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let positions = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(positions.clone()), 37);
+        assert_eq!(part2(positions), 168);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("3,4,3,1,,2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+
+        let err = parse("3,4,3,a,2\n").unwrap_err();
+        assert_eq!(err.text, "a");
+    }
+}
@@ -0,0 +1,359 @@
+//! Times parsing, part 1 and part 2 for every day
+//!
+//! `cargo bench -p bench` runs everything, `cargo bench -p bench -- day7/` just the one day.
+//! `-- --save-baseline before` keeps the numbers around and `-- --baseline before` compares against them.
+
+use std::hint::black_box;
+use std::io::Write;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, BatchSize, Bencher, BenchmarkGroup, Criterion};
+use gag::Gag;
+
+// the days print their answers (and sometimes whole grids) as they go,
+// that's just noise in a bench so stdout is gagged while a day is running
+fn quiet<T>(f: impl FnOnce() -> T) -> T {
+    // only one gag can be open at a time, if that fails it's just noisy
+    let gag = Gag::stdout().ok();
+    let v = f();
+    std::io::stdout().flush().unwrap();
+    drop(gag);
+    v
+}
+
+// criterion prints its results after the routine is done, so those still show up
+struct QuietGroup<'a>(BenchmarkGroup<'a, WallTime>);
+
+impl QuietGroup<'_> {
+    fn sample_size(&mut self, n: usize) -> &mut Self {
+        self.0.sample_size(n);
+        self
+    }
+
+    fn bench_function<F: FnMut(&mut Bencher)>(&mut self, id: &str, mut f: F) -> &mut Self {
+        self.0.bench_function(id, |b| quiet(|| f(b)));
+        self
+    }
+
+    fn finish(self) {
+        self.0.finish();
+    }
+}
+
+// every day is a library crate, this brings its parse and parts into a module to bench them
+macro_rules! day {
+    ($day:ident, |$group:ident, $input:ident| $body:block) => {
+        // not every day needs batching
+        #[allow(unused_imports)]
+        mod $day {
+            use super::{black_box, quiet, BatchSize, QuietGroup};
+            use ::$day::*;
+
+            pub fn bench(c: &mut criterion::Criterion) {
+                let $input = include_str!(concat!("../../", stringify!($day), "/input.txt"));
+
+                let mut $group = QuietGroup(c.benchmark_group(stringify!($day)));
+                $group.sample_size(10);
+                $body
+                $group.finish();
+            }
+        }
+    };
+}
+
+day!(day1, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| values.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day2, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day3, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day4, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day5, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (stacks, moves) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || stacks.clone(),
+            |stacks| part1(stacks, &moves),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || stacks.clone(),
+            |stacks| part2(stacks, &moves),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day6, |group, input| {
+    // no parsing, the parts work right on the signal
+    let input = input.trim();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(input))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(input))));
+});
+
+day!(day7, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let root = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(root.clone()))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(root.clone()))));
+});
+
+day!(day8, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day9, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (width, height, start, values) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(width, height, start, black_box(&values)))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| part2(width, height, start, black_box(&values)))
+    });
+});
+
+day!(day10, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day11, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || values.clone(),
+            |values| simulate(values, 20, true),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || values.clone(),
+            |values| simulate(values, 10000, false),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day12, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (values, start, end) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || values.clone(),
+            |values| part1(values, start, end),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || values.clone(),
+            |values| part2(values, end),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day13, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| flatten(&values), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day14, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| values.clone().into(), part1, BatchSize::SmallInput)
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| values.clone().into(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day15, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let map = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&map), 2000000)));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&map), 4000000)));
+});
+
+day!(day16, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    // part 1 doesn't finish on the real input yet
+});
+
+day!(day17, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day18, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day19, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    // part 1 still trips one of its own asserts on the real input
+});
+
+day!(day20, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| values.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day21, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| values.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day22, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (map, instructions) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || map.clone(),
+            |map| part1(map, &instructions),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || map.clone(),
+            |map| part2(map, &instructions),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day23, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    // the elves can't be cloned so just parse them again for every run
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| parse(input).unwrap(), part1, BatchSize::SmallInput)
+    });
+});
+
+day!(day24, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    // part 1 doesn't finish on the real input yet
+});
+
+day!(day25, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+});
+
+criterion_group!(
+    benches,
+    day1::bench,
+    day2::bench,
+    day3::bench,
+    day4::bench,
+    day5::bench,
+    day6::bench,
+    day7::bench,
+    day8::bench,
+    day9::bench,
+    day10::bench,
+    day11::bench,
+    day12::bench,
+    day13::bench,
+    day14::bench,
+    day15::bench,
+    day16::bench,
+    day17::bench,
+    day18::bench,
+    day19::bench,
+    day20::bench,
+    day21::bench,
+    day22::bench,
+    day23::bench,
+    day24::bench,
+    day25::bench
+);
+
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    aoc_bench::print_summary(aoc_bench::criterion_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/.."
+    )));
+}
@@ -0,0 +1,57 @@
+use aoc_parse::{parse_at, ParseError};
+
+pub fn part1(values: impl AsRef<[usize]>) -> usize {
+    let max = *values.as_ref().iter().max().unwrap();
+
+    println!("Max calories: {}", max);
+
+    max
+}
+
+pub fn part2(values: impl Into<Vec<usize>>) -> usize {
+    let mut values = values.into();
+    values.sort();
+    values.reverse();
+
+    let total: usize = values.iter().take(3).sum();
+
+    println!("Top 3 total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    // blank lines split up the elves
+    let values = input
+        .lines()
+        .map(|x| match x.trim() {
+            "" => Ok(None),
+            x => parse_at(input, x).map(Some),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(values
+        .split(|x| x.is_none())
+        .map(|x| x.iter().flatten().sum::<usize>())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 24000);
+        assert_eq!(part2(values), 45000);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("1000\n2000\n\n4000\nfive\n").unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.text, "five");
+    }
+}
@@ -0,0 +1,170 @@
+use std::cell::RefCell;
+
+use aoc_ocr::OcrError;
+use aoc_parse::combinators::{parse_lines, signed, IResult};
+use aoc_parse::ParseError;
+use nom::{branch::alt, bytes::complete::tag, combinator::map, error::context, sequence::preceded};
+
+#[derive(Debug)]
+struct Cpu {
+    x: RefCell<i32>,
+    cycle: RefCell<i32>,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Self {
+            x: RefCell::new(1),
+            cycle: RefCell::new(1),
+        }
+    }
+
+    fn execute<F>(&self, instruction: Instruction, mut cycle: F)
+    where
+        F: FnMut(i32, i32),
+    {
+        match instruction {
+            Instruction::NoOp => {
+                cycle(*self.cycle.borrow(), *self.x.borrow());
+                *self.cycle.borrow_mut() += 1;
+            }
+            Instruction::AddX(v) => {
+                for _ in 0..instruction.cycles() - 1 {
+                    cycle(*self.cycle.borrow(), *self.x.borrow());
+                    *self.cycle.borrow_mut() += 1;
+                }
+
+                cycle(*self.cycle.borrow(), *self.x.borrow());
+                *self.cycle.borrow_mut() += 1;
+
+                *self.x.borrow_mut() += v;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Instruction {
+    NoOp,
+    AddX(i32),
+}
+
+impl Instruction {
+    fn cycles(&self) -> usize {
+        match self {
+            Self::NoOp => 1,
+            Self::AddX(_) => 2,
+        }
+    }
+}
+
+fn parse_noop(input: &str) -> IResult<'_, Instruction> {
+    map(tag("noop"), |_| Instruction::NoOp)(input)
+}
+
+fn parse_addx(input: &str) -> IResult<'_, Instruction> {
+    map(preceded(tag("addx "), signed), Instruction::AddX)(input)
+}
+
+fn parse_instruction(i: &str) -> IResult<'_, Instruction> {
+    context("noop or addx", alt((parse_noop, parse_addx)))(i)
+}
+
+pub fn part1(instructions: impl AsRef<[Instruction]>) -> i32 {
+    let cpu = Cpu::new();
+
+    let mut total = 0;
+    for instruction in instructions.as_ref() {
+        if *cpu.cycle.borrow() > 220 {
+            break;
+        }
+
+        cpu.execute(*instruction, |cycle, x| {
+            if (cycle - 20) % 40 == 0 {
+                let s = cycle * x;
+                total += s;
+            }
+        });
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+fn draw(instructions: impl AsRef<[Instruction]>) -> String {
+    let cpu = Cpu::new();
+
+    let mut screen = String::new();
+    for instruction in instructions.as_ref() {
+        cpu.execute(*instruction, |cycle, spos| {
+            // cycles count from 1 but pixels count from 0
+            let ppos = (cycle - 1) % 40;
+
+            // is at least one pixel of the sprite visible?
+            if ppos == spos - 1 || ppos == spos || ppos == spos + 1 {
+                screen.push('#');
+            } else {
+                screen.push('.');
+            }
+
+            if ppos == 39 {
+                screen.push('\n');
+            }
+        });
+    }
+
+    screen
+}
+
+pub fn part2(instructions: impl AsRef<[Instruction]>) -> Result<String, OcrError> {
+    let screen = draw(instructions);
+    #[cfg(feature = "debugvis")]
+    print!("{}", screen);
+
+    let letters = aoc_ocr::recognize_str(&screen)?;
+    println!("Letters: {}", letters);
+
+    Ok(letters)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, parse_instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 13140);
+
+        // the example doesn't spell anything so just check the pixels
+        assert_eq!(
+            draw(&values),
+            "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+"
+        );
+        assert!(part2(&values).is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("noop\naddx 3\naddx x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 6));
+        assert_eq!(err.message, "expected a number");
+
+        let err = parse("noop\nmulx 3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected noop or addx");
+    }
+}
@@ -0,0 +1,263 @@
+// NOTE: I absolutely had to cheat on the math required for part 2 ... thanks https://www.reddit.com/r/adventofcode/
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use aoc_math::lcm_all;
+use aoc_parse::combinators::{comma_list, key_value, parse_blocks, unsigned, IResult};
+use aoc_parse::ParseError;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::line_ending,
+    combinator::{map, value},
+    error::context,
+    sequence::{delimited, preceded, terminated, tuple},
+};
+
+#[derive(Debug, Copy, Clone)]
+enum Operation {
+    Square,
+    Mult(usize),
+    Add(usize),
+}
+
+impl Operation {
+    fn perform(&self, old: usize) -> usize {
+        match self {
+            Self::Square => {
+                let new = old * old;
+                #[cfg(feature = "debugmonkeys")]
+                println!("    Worry level is multiplied by itself to {}.", new);
+                new
+            }
+            Self::Mult(v) => {
+                let new = old * v;
+                #[cfg(feature = "debugmonkeys")]
+                println!("    Worry level is multiplied by {} to {}.", v, new);
+                new
+            }
+            Self::Add(v) => {
+                let new = old + v;
+                #[cfg(feature = "debugmonkeys")]
+                println!("    Worry level increases by {} to {}.", v, new);
+                new
+            }
+        }
+    }
+}
+
+// new = old * 19
+fn parse_operation(input: &str) -> IResult<'_, Operation> {
+    preceded(
+        tag("new = old "),
+        context(
+            "* old, * n or + n",
+            alt((
+                value(Operation::Square, tag("* old")),
+                map(preceded(tag("* "), unsigned), Operation::Mult),
+                map(preceded(tag("+ "), unsigned), Operation::Add),
+            )),
+        ),
+    )(input)
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Test {
+    divisible_by: usize,
+    true_throw: usize,
+    false_throw: usize,
+}
+
+impl Test {
+    fn test(&self, worry_level: usize) -> usize {
+        if worry_level.is_multiple_of(self.divisible_by) {
+            #[cfg(feature = "debugmonkeys")]
+            println!(
+                "    Current worry level is divisible by {}.",
+                self.divisible_by
+            );
+            self.true_throw
+        } else {
+            #[cfg(feature = "debugmonkeys")]
+            println!(
+                "    Current worry level is not divisible by {}.",
+                self.divisible_by
+            );
+            self.false_throw
+        }
+    }
+}
+
+fn parse_test(input: &str) -> IResult<'_, Test> {
+    let throw = || preceded(tag("throw to monkey "), unsigned);
+
+    map(
+        tuple((
+            key_value(tag("Test"), preceded(tag("divisible by "), unsigned)),
+            preceded(line_ending, key_value(tag("If true"), throw())),
+            preceded(line_ending, key_value(tag("If false"), throw())),
+        )),
+        |((_, divisible_by), (_, true_throw), (_, false_throw))| Test {
+            divisible_by,
+            true_throw,
+            false_throw,
+        },
+    )(input)
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    worry_levels: VecDeque<usize>,
+    operation: Operation,
+    test: Test,
+
+    inspected_count: usize,
+}
+
+impl Monkey {
+    fn inspect_items(&mut self, monkeys: impl AsRef<[RefCell<Monkey>]>, lcm: Option<usize>) {
+        let mut throws = vec![];
+        for worry_level in self.worry_levels.iter_mut() {
+            #[cfg(feature = "debugmonkeys")]
+            println!(
+                "  Monkey inspects an item with a worry level of {}.",
+                *worry_level
+            );
+
+            // inspect the item
+            *worry_level = self.operation.perform(*worry_level);
+
+            // manage worry level
+            if let Some(lcm) = lcm {
+                *worry_level %= lcm;
+                #[cfg(feature = "debugmonkeys")]
+                println!("    Managing worry level by {} to {}.", lcm, *worry_level);
+            } else {
+                *worry_level /= 3;
+                #[cfg(feature = "debugmonkeys")]
+                println!(
+                    "    Monkey gets bored with item. Worry level is divided by 3 to {}.",
+                    *worry_level
+                );
+            }
+
+            // test the item and throw it
+            // (assuming here we never throw back to ourself)
+            let throw = self.test.test(*worry_level);
+            #[cfg(feature = "debugmonkeys")]
+            println!(
+                "    Item with worry level {} is thrown to monkey {}.",
+                worry_level, throw
+            );
+            throws.push(throw);
+
+            self.inspected_count += 1;
+        }
+
+        for throw in throws {
+            let worry_level = self.worry_levels.pop_front().unwrap();
+            monkeys.as_ref()[throw]
+                .borrow_mut()
+                .worry_levels
+                .push_back(worry_level);
+        }
+    }
+}
+
+fn parse_monkey(input: &str) -> IResult<'_, Monkey> {
+    // monkeys are always in order so the number isn't needed
+    let header = delimited(tag("Monkey "), unsigned::<usize>, tag(":"));
+
+    map(
+        tuple((
+            terminated(header, line_ending),
+            terminated(
+                key_value(tag("Starting items"), comma_list(unsigned)),
+                line_ending,
+            ),
+            terminated(key_value(tag("Operation"), parse_operation), line_ending),
+            parse_test,
+        )),
+        |(_, (_, worry_levels), (_, operation), test)| Monkey {
+            worry_levels: worry_levels.into(),
+            operation,
+            test,
+            inspected_count: 0,
+        },
+    )(input)
+}
+
+pub fn simulate(mut monkeys: Vec<RefCell<Monkey>>, rounds: usize, relief: bool) -> usize {
+    // for the no-relief simulation, we need the lowest common multiple
+    // for all of the monkey tests
+    let lcm = if !relief {
+        let lcm = lcm_all(
+            monkeys
+                .iter()
+                .map(|monkey| monkey.borrow().test.divisible_by),
+        );
+        Some(lcm.unwrap())
+    } else {
+        None
+    };
+
+    let mut round = 0;
+    loop {
+        if round >= rounds {
+            break;
+        }
+
+        #[allow(clippy::unused_enumerate_index)]
+        for (_i, monkey) in monkeys.iter().enumerate() {
+            #[cfg(feature = "debugmonkeys")]
+            println!("Monkey {}:", _i);
+            monkey.borrow_mut().inspect_items(&monkeys, lcm);
+        }
+
+        #[cfg(feature = "debugrounds")]
+        {
+            println!(
+                "After round {} the monkeys are holding items with these worry levels:",
+                round + 1
+            );
+            for (i, monkey) in monkeys.iter().enumerate() {
+                println!("Monkey {}: {:?}", i, monkey.borrow().worry_levels);
+            }
+        }
+
+        round += 1;
+    }
+
+    #[cfg(feature = "debugrounds")]
+    println!("{:?}", monkeys);
+
+    monkeys.sort_by(|a, b| {
+        b.borrow()
+            .inspected_count
+            .partial_cmp(&a.borrow().inspected_count)
+            .unwrap()
+    });
+
+    monkeys[0].borrow().inspected_count * monkeys[1].borrow().inspected_count
+}
+
+pub fn parse(input: &str) -> Result<Vec<RefCell<Monkey>>, ParseError> {
+    Ok(parse_blocks(input, parse_monkey)?
+        .into_iter()
+        .map(RefCell::new)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(simulate(values.clone(), 20, true), 10605);
+        assert_eq!(simulate(values, 10000, false), 2713310158);
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+impl FromStr for Position {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let expected = || ParseError::new(v, v, "expected x=X, y=Y");
+        let (x, y) = v.split_once(", ").ok_or_else(expected)?;
+
+        let (_, x) = x.split_once('=').ok_or_else(expected)?;
+        let (_, y) = y.split_once('=').ok_or_else(expected)?;
+
+        Ok(Self {
+            x: parse_at(v, x)?,
+            y: parse_at(v, y)?,
+        })
+    }
+}
+
+impl Position {
+    fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    #[inline]
+    fn distance(&self, other: &Position) -> usize {
+        ((other.x - self.x).abs() + (other.y - self.y).abs()) as usize
+    }
+}
+
+#[derive(Debug)]
+struct Sensor {
+    position: Position,
+    beacon_position: Position,
+    beacon_distance: usize,
+}
+
+impl From<(Position, Position)> for Sensor {
+    fn from(v: (Position, Position)) -> Self {
+        Self {
+            position: v.0,
+            beacon_position: v.1,
+            beacon_distance: v.0.distance(&v.1),
+        }
+    }
+}
+
+impl Sensor {
+    // the edges just outside of the sensor range lie on 4 diagonal lines
+    // returns the y intercepts of the y = x + c lines and the y = -x + c lines
+    fn boundary_lines(&self) -> ([i64; 2], [i64; 2]) {
+        let r = self.beacon_distance as i64 + 1;
+        let (x, y) = (self.position.x, self.position.y);
+
+        ([y - x - r, y - x + r], [y + x - r, y + x + r])
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    sensors: Vec<Sensor>,
+}
+
+impl From<Vec<(Position, Position)>> for Map {
+    fn from(v: Vec<(Position, Position)>) -> Self {
+        Self {
+            sensors: v.into_iter().map(Sensor::from).collect(),
+        }
+    }
+}
+
+impl Map {
+    fn can_have_beacon_at(&self, position: Position) -> bool {
+        for sensor in &self.sensors {
+            // is this position close enough to the sensor to eliminate?
+            if sensor.position.distance(&position) <= sensor.beacon_distance {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+pub fn part1(map: &Map, row: i64) -> usize {
+    // each sensor covers a span of the row that shrinks the further away it is,
+    // so merge those spans and count them up instead of checking every position
+    let mut spans = map
+        .sensors
+        .iter()
+        .filter_map(|sensor| {
+            let reach = sensor.beacon_distance as i64 - (sensor.position.y - row).abs();
+            (reach >= 0).then(|| (sensor.position.x - reach, sensor.position.x + reach))
+        })
+        .collect::<Vec<_>>();
+    spans.sort_unstable();
+
+    let mut total = 0;
+    let mut covered_to = i64::MIN;
+    for (start, end) in spans {
+        let start = start.max(covered_to + 1);
+        if start <= end {
+            total += (end - start + 1) as usize;
+            covered_to = end;
+        }
+    }
+
+    // beacons we already know about are inside the spans, but they're obviously not empty
+    let beacons = map
+        .sensors
+        .iter()
+        .filter(|sensor| sensor.beacon_position.y == row)
+        .map(|sensor| sensor.beacon_position.x)
+        .collect::<HashSet<_>>();
+    total -= beacons.len();
+
+    println!("{} positions with no beacon", total);
+
+    total
+}
+
+pub fn part2(map: &Map, max: i64) -> Option<i64> {
+    // there's only one spot the beacon could be, so it has to be right outside
+    // the range of multiple sensors, which means it's on the intersection of
+    // one sensor's rising boundary and another sensor's falling boundary
+    // (unless it's up against the edge of the search area, then the edge does
+    // the job of one of those boundaries, or both of them in a corner)
+    let (rising, falling): (Vec<_>, Vec<_>) = map
+        .sensors
+        .iter()
+        .map(|sensor| sensor.boundary_lines())
+        .unzip();
+    let rising = rising.into_iter().flatten().collect::<HashSet<_>>();
+    let falling = falling.into_iter().flatten().collect::<HashSet<_>>();
+
+    let intersections = rising
+        .iter()
+        .flat_map(|a| falling.iter().map(move |b| (a, b)))
+        .filter_map(|(a, b)| {
+            // y = x + a and y = -x + b only meet on a whole position if the parity matches
+            if (b - a) % 2 != 0 {
+                return None;
+            }
+
+            Some(Position::new((b - a) / 2, (a + b) / 2))
+        });
+
+    // where the boundaries cross the edges
+    let edges = rising
+        .iter()
+        .flat_map(|a| {
+            [
+                Position::new(0, *a),
+                Position::new(max, max + a),
+                Position::new(-a, 0),
+                Position::new(max - a, max),
+            ]
+        })
+        .chain(falling.iter().flat_map(|b| {
+            [
+                Position::new(0, *b),
+                Position::new(max, b - max),
+                Position::new(*b, 0),
+                Position::new(b - max, max),
+            ]
+        }));
+
+    let corners = [
+        Position::new(0, 0),
+        Position::new(max, 0),
+        Position::new(0, max),
+        Position::new(max, max),
+    ];
+
+    let signal = intersections
+        .chain(edges)
+        .chain(corners)
+        .filter(|position| {
+            position.x >= 0 && position.y >= 0 && position.x <= max && position.y <= max
+        })
+        .find(|position| map.can_have_beacon_at(*position))?;
+
+    let frequency = signal.x * 4000000 + signal.y;
+    println!("Signal at ({}, {}): {}", signal.x, signal.y, frequency);
+
+    Some(frequency)
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let re = Regex::new(r"Sensor at (.+): closest beacon is at (.+)").unwrap();
+
+    let position = |text: &str| {
+        text.parse::<Position>()
+            .map_err(|err| err.within(input, text))
+    };
+
+    let objects = input
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            let captures = re.captures(x).ok_or_else(|| {
+                ParseError::new(input, x, "expected Sensor at ...: closest beacon is at ...")
+            })?;
+
+            let sensor = position(captures.get(1).unwrap().as_str())?;
+            let beacon = position(captures.get(2).unwrap().as_str())?;
+
+            Ok((sensor, beacon))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if objects.is_empty() {
+        return Err(ParseError::truncated(input, "expected at least one sensor"));
+    }
+
+    Ok(objects.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let map = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&map, 10), 26);
+        assert_eq!(part2(&map, 20), Some(56000011));
+    }
+
+    #[test]
+    fn row_with_gaps() {
+        let map = parse(concat!(
+            "Sensor at x=0, y=0: closest beacon is at x=2, y=0\n",
+            "Sensor at x=1, y=0: closest beacon is at x=1, y=1\n",
+            "Sensor at x=10, y=0: closest beacon is at x=10, y=1\n",
+        ))
+        .unwrap();
+
+        // -2..=2 and 9..=11, less the beacon at 2
+        assert_eq!(part1(&map, 0), 7);
+        assert_eq!(part1(&map, 1), 2);
+        assert_eq!(part1(&map, 5), 0);
+    }
+
+    #[test]
+    fn beacon_on_edge() {
+        let map = parse(concat!(
+            "Sensor at x=4, y=7: closest beacon is at x=9, y=7\n",
+            "Sensor at x=7, y=6: closest beacon is at x=11, y=6\n",
+            "Sensor at x=1, y=7: closest beacon is at x=6, y=7\n",
+            "Sensor at x=2, y=0: closest beacon is at x=7, y=0\n",
+        ))
+        .unwrap();
+
+        assert_eq!(part2(&map, 6), Some(6 * 4000000 + 2));
+    }
+
+    #[test]
+    fn beacon_in_corner() {
+        let map = parse(concat!(
+            "Sensor at x=2, y=2: closest beacon is at x=5, y=2\n",
+            "Sensor at x=0, y=0: closest beacon is at x=0, y=1\n",
+            "Sensor at x=0, y=4: closest beacon is at x=0, y=3\n",
+            "Sensor at x=4, y=4: closest beacon is at x=4, y=3\n",
+        ))
+        .unwrap();
+
+        assert_eq!(part2(&map, 4), Some(4 * 4000000));
+    }
+
+    #[test]
+    fn no_beacon() {
+        let map = parse("Sensor at x=2, y=2: closest beacon is at x=6, y=2\n").unwrap();
+
+        assert_eq!(part2(&map, 4), None);
+    }
+
+    #[test]
+    fn no_sensors() {
+        let err = parse("\n").unwrap_err();
+        assert_eq!(err.message, "expected at least one sensor");
+    }
+}
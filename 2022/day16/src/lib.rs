@@ -0,0 +1,210 @@
+use aoc_graph::Graph;
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+const TOTAL_MINUTES: usize = 30;
+
+#[derive(Debug)]
+struct Valve {
+    idx: usize,
+    name: &'static str,
+    flow_rate: usize,
+}
+
+#[derive(Debug)]
+pub struct Valves {
+    // indexed the same as the tunnel graph
+    valves: Vec<Valve>,
+
+    // shortest distance between every pair of valves
+    // this makes the tunnel graph fully connected
+    distances: Vec<Vec<Option<usize>>>,
+}
+
+impl Valves {
+    fn new(tunnels: &Graph<'static, usize>, flow_rates: &[(&'static str, usize)]) -> Self {
+        let mut valves = flow_rates
+            .iter()
+            .map(|&(name, flow_rate)| Valve {
+                idx: tunnels.index_of(name).unwrap(),
+                name,
+                flow_rate,
+            })
+            .collect::<Vec<_>>();
+        valves.sort_unstable_by_key(|valve| valve.idx);
+
+        Self {
+            valves,
+            distances: tunnels.floyd_warshall(),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&Valve> {
+        self.valves.iter().find(|valve| valve.name == name)
+    }
+
+    // (valve, distance) to every other valve
+    fn paths(&self, from: &Valve) -> impl Iterator<Item = (&Valve, usize)> {
+        let from = from.idx;
+        self.valves
+            .iter()
+            .filter(move |valve| valve.idx != from)
+            .map(move |valve| (valve, self.distances[from][valve.idx].unwrap()))
+    }
+}
+
+impl Valve {
+    // returns max (visited, minutes, pressure, total)
+    // (absolute brute force solution)
+    fn highest_pressure_path(
+        &self,
+        valves: &Valves,
+        mut minutes: usize,
+        mut pressure: usize,
+        mut total: usize,
+        visited: &mut Vec<usize>,
+        _depth: usize,
+    ) -> (bool, usize, usize, usize) {
+        let _pad = _depth * 2;
+
+        // is this valve already visited?
+        if visited.contains(&self.idx) {
+            //println!("{:_pad$}- Already visited {}", ' ', self.name);
+            return (false, minutes, pressure, total);
+        }
+
+        // have we run out of time (including time to open this valve)?
+        let max_minutes = TOTAL_MINUTES - usize::from(self.flow_rate > 0);
+        if minutes >= max_minutes {
+            //println!("{:_pad$}- Ran out of time to visit {}", ' ', self.name);
+            return (false, minutes, pressure, total);
+        }
+
+        /*println!(
+            "{:_pad$}-Visiting {}: {} minutes, {} pressure, {} total flow",
+            ' ', self.name, minutes, pressure, total
+        );*/
+
+        // open the valve
+        if self.flow_rate > 0 {
+            minutes += 1;
+            total += pressure;
+            pressure += self.flow_rate;
+        }
+
+        visited.push(self.idx);
+
+        let mut max = (minutes, pressure, total);
+
+        for (valve, distance) in valves.paths(self) {
+            let (v, m, p, t) = valve.highest_pressure_path(
+                valves,
+                minutes + distance,
+                pressure,
+                total + (distance * pressure),
+                visited,
+                _depth + 1,
+            );
+            if !v {
+                continue;
+            }
+
+            /*println!(
+                "{:_pad$}-Visited {} from {} and got {} minutes, {} pressure, {} total flow",
+                ' ', valve.name, self.name, m, p, t
+            );*/
+
+            // TODO: print what we're comparing
+
+            /*let current = max.2 + ((TOTAL_MINUTES - max.0) * pressure);
+            let new = t + ((TOTAL_MINUTES - m) * p);
+            if p > max.1 && new >= current {
+                max = (m, p, t);
+            }*/
+
+            if t > max.2 {
+                max = (m, p, t)
+            }
+        }
+
+        visited.pop();
+
+        /*println!(
+            "{:_pad$}-Finished visiting {}: {} minutes, {} pressure, {} total flow",
+            ' ', self.name, max.0, max.1, max.2
+        );*/
+
+        (true, max.0, max.1, max.2)
+    }
+}
+
+pub fn part1(valves: &Valves) -> usize {
+    let mut visited = Vec::with_capacity(valves.valves.len());
+    let (_, minutes, pressure, mut total) =
+        valves
+            .get("AA")
+            .unwrap()
+            .highest_pressure_path(valves, 0, 0, 0, &mut visited, 0);
+    println!(
+        "Total: {} ({} pressure in {} minutes)",
+        total, pressure, minutes
+    );
+    total += (TOTAL_MINUTES - minutes) * pressure;
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &'static str) -> Result<Valves, ParseError> {
+    let re =
+        Regex::new(r"^Valve (.+) has flow rate=(\d+); tunnels? leads? to valves? (.+)$").unwrap();
+
+    // tunnels are paths with a distance of 1
+    let mut tunnels = Graph::directed();
+    let mut flow_rates = vec![];
+    for x in input.lines() {
+        let x = x.trim();
+        if x.is_empty() {
+            continue;
+        }
+
+        let captures = re.captures(x).ok_or_else(|| {
+            ParseError::new(
+                input,
+                x,
+                "expected Valve XX has flow rate=n; tunnels lead to valves ...",
+            )
+        })?;
+
+        let name = captures.get(1).unwrap().as_str();
+        let rate = parse_at(input, captures.get(2).unwrap().as_str())?;
+
+        tunnels.add_node(name);
+        for connection in captures.get(3).unwrap().as_str().split(',') {
+            tunnels.add_edge(name, connection.trim(), 1);
+        }
+
+        flow_rates.push((name, rate));
+    }
+
+    Ok(Valves::new(&tunnels, &flow_rates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let valves = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&valves), 1651);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB\nValve BB has flow rate 13; tunnels lead to valves CC, AA\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
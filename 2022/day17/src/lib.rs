@@ -0,0 +1,255 @@
+use std::fmt;
+
+use aoc_cycle::find_cycle;
+use aoc_parse::ParseError;
+
+const CHAMBER_WIDTH: usize = 7;
+
+#[derive(Debug, Copy, Clone)]
+pub enum JetDirection {
+    Left,
+    Right,
+}
+
+// each rock is a set of rows from the bottom up,
+// one bit per column with the left wall on the high bit,
+// already spawned 2 units away from the left wall
+const ROCKS: [&[u8]; 5] = [
+    // horizontal
+    &[0b0011110],
+    // cross
+    &[0b0001000, 0b0011100, 0b0001000],
+    // el
+    &[0b0011100, 0b0000100, 0b0000100],
+    // vertical
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    // square
+    &[0b0011000, 0b0011000],
+];
+
+const LEFT_WALL: u8 = 1 << (CHAMBER_WIDTH - 1);
+const RIGHT_WALL: u8 = 1;
+
+#[derive(Debug, Clone)]
+struct Chamber {
+    // settled rocks from the bottom up, only the part of the tower
+    // a falling rock can still get into is kept
+    rows: Vec<u8>,
+    // rows dropped off the bottom, still counted in the height
+    trimmed: usize,
+
+    // the next rock and jet to use
+    rock: usize,
+    jet: usize,
+}
+
+impl fmt::Display for Chamber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows.iter().rev() {
+            write!(f, "|")?;
+            for x in 0..CHAMBER_WIDTH {
+                let bit = LEFT_WALL >> x;
+                write!(f, "{}", if row & bit != 0 { '#' } else { '.' })?;
+            }
+            writeln!(f, "|")?;
+        }
+        writeln!(f, "+-------+")?;
+
+        Ok(())
+    }
+}
+
+impl Chamber {
+    fn new() -> Self {
+        Self {
+            rows: vec![],
+            trimmed: 0,
+            rock: 0,
+            jet: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.trimmed + self.rows.len()
+    }
+
+    fn intersects(&self, rock: &[u8], bottom: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(y, row)| self.rows.get(bottom + y).is_some_and(|r| r & row != 0))
+    }
+
+    // lowest row a falling rock could still reach, flood filling from above the tower
+    // down and sideways through the gaps (a rock is never anywhere a single cell can't get to)
+    fn lowest_reachable(&self) -> usize {
+        let top = self.rows.len();
+        let mut seen = vec![0_u8; top + 1];
+        let mut stack = vec![];
+        for x in 0..CHAMBER_WIDTH {
+            let bit = LEFT_WALL >> x;
+            seen[top] |= bit;
+            stack.push((top, bit));
+        }
+
+        let mut lowest = top;
+        while let Some((y, bit)) = stack.pop() {
+            lowest = lowest.min(y);
+
+            let mut next = vec![];
+            if bit != LEFT_WALL {
+                next.push((y, bit << 1));
+            }
+            if bit != RIGHT_WALL {
+                next.push((y, bit >> 1));
+            }
+            if y > 0 {
+                next.push((y - 1, bit));
+            }
+
+            for (y, bit) in next {
+                let filled = self.rows.get(y).is_some_and(|row| row & bit != 0);
+                if !filled && seen[y] & bit == 0 {
+                    seen[y] |= bit;
+                    stack.push((y, bit));
+                }
+            }
+        }
+
+        lowest
+    }
+
+    fn push(&self, rock: &mut [u8], bottom: usize, direction: JetDirection) {
+        let mut pushed = rock.to_vec();
+        for row in &mut pushed {
+            match direction {
+                JetDirection::Left => {
+                    if *row & LEFT_WALL != 0 {
+                        return;
+                    }
+                    *row <<= 1;
+                }
+                JetDirection::Right => {
+                    if *row & RIGHT_WALL != 0 {
+                        return;
+                    }
+                    *row >>= 1;
+                }
+            }
+        }
+
+        if !self.intersects(&pushed, bottom) {
+            rock.copy_from_slice(&pushed);
+        }
+    }
+
+    fn drop_rock(&mut self, jets: &[JetDirection]) {
+        let mut rock = ROCKS[self.rock].to_vec();
+        self.rock = (self.rock + 1) % ROCKS.len();
+
+        let mut bottom = self.rows.len() + 3;
+        loop {
+            self.push(&mut rock, bottom, jets[self.jet]);
+            self.jet = (self.jet + 1) % jets.len();
+
+            if bottom == 0 {
+                // only the real floor, the rows under a trimmed tower can't be reached
+                assert!(
+                    self.trimmed == 0,
+                    "rock fell into the trimmed part of the tower"
+                );
+                break;
+            }
+            if self.intersects(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        for (y, row) in rock.iter().enumerate() {
+            if bottom + y >= self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[bottom + y] |= row;
+        }
+
+        // keep the row under the lowest reachable one so rocks still land on it,
+        // everything below that is never going to matter again
+        let trim = self.lowest_reachable().saturating_sub(1);
+        if trim > 0 {
+            self.rows.drain(..trim);
+            self.trimmed += trim;
+        }
+    }
+}
+
+// dropping rocks one at a time gets way too slow for part 2,
+// but the rock, the jet and the shape of the top of the tower
+// are all that decide what happens next and they eventually repeat
+fn tower_height(jets: &[JetDirection], rocks: usize) -> usize {
+    let cycle = find_cycle(
+        Chamber::new(),
+        |chamber| {
+            let mut chamber = chamber.clone();
+            chamber.drop_rock(jets);
+            chamber
+        },
+        |chamber| (chamber.rock, chamber.jet, chamber.rows.clone()),
+    );
+    //println!("cycle starts at {} (period {})", cycle.start, cycle.period);
+
+    cycle.extrapolate(rocks, |chamber| chamber.height())
+}
+
+pub fn part1(jets: impl AsRef<[JetDirection]>) -> usize {
+    let jets = jets.as_ref();
+
+    let mut chamber = Chamber::new();
+    for _ in 0..2022 {
+        chamber.drop_rock(jets);
+    }
+    //println!("{}", chamber);
+
+    // the cycle skipping has to agree with just dropping the rocks
+    let height = chamber.height();
+    assert!(height == tower_height(jets, 2022));
+
+    println!("Final height: {}", height);
+
+    height
+}
+
+pub fn part2(jets: impl AsRef<[JetDirection]>) -> usize {
+    let height = tower_height(jets.as_ref(), 1000000000000);
+
+    println!("Final height: {}", height);
+
+    height
+}
+
+pub fn parse(input: &str) -> Result<Vec<JetDirection>, ParseError> {
+    let jets = input.trim();
+    jets.char_indices()
+        .map(|(idx, x)| match x {
+            '<' => Ok(JetDirection::Left),
+            '>' => Ok(JetDirection::Right),
+            _ => Err(ParseError::new(
+                input,
+                &jets[idx..idx + x.len_utf8()],
+                "expected < or >",
+            )),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 3068);
+        assert_eq!(part2(&values), 1514285714288);
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Cube {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl From<(i64, i64, i64)> for Cube {
+    fn from(v: (i64, i64, i64)) -> Self {
+        Self {
+            x: v.0,
+            y: v.1,
+            z: v.2,
+        }
+    }
+}
+
+impl Cube {
+    fn neighbors(&self) -> [Cube; 6] {
+        [
+            // left / right
+            (self.x - 1, self.y, self.z).into(),
+            (self.x + 1, self.y, self.z).into(),
+            // bottom / top
+            (self.x, self.y - 1, self.z).into(),
+            (self.x, self.y + 1, self.z).into(),
+            // behind / front
+            (self.x, self.y, self.z - 1).into(),
+            (self.x, self.y, self.z + 1).into(),
+        ]
+    }
+
+    fn unconnected_sides(&self, cubes: &HashSet<Cube>) -> usize {
+        self.neighbors()
+            .iter()
+            .filter(|neighbor| !cubes.contains(neighbor))
+            .count()
+    }
+}
+
+pub fn part1(cubes: &HashSet<Cube>) -> usize {
+    let total = cubes
+        .iter()
+        .map(|cube| cube.unconnected_sides(cubes))
+        .sum::<usize>();
+
+    println!("Total unconnected sides: {}", total);
+
+    total
+}
+
+pub fn part2(cubes: &HashSet<Cube>) -> usize {
+    // pad the bounding box by 1 so the steam can get all the way around the droplet
+    let minx = cubes.iter().map(|c| c.x).min().unwrap() - 1;
+    let maxx = cubes.iter().map(|c| c.x).max().unwrap() + 1;
+
+    let miny = cubes.iter().map(|c| c.y).min().unwrap() - 1;
+    let maxy = cubes.iter().map(|c| c.y).max().unwrap() + 1;
+
+    let minz = cubes.iter().map(|c| c.z).min().unwrap() - 1;
+    let maxz = cubes.iter().map(|c| c.z).max().unwrap() + 1;
+
+    let in_bounds = |c: &Cube| {
+        (minx..=maxx).contains(&c.x) && (miny..=maxy).contains(&c.y) && (minz..=maxz).contains(&c.z)
+    };
+
+    // flood fill the steam from outside the droplet
+    // every time it bumps into lava that's an exterior face
+    // (air pockets inside the droplet are never reached)
+    let start = Cube::from((minx, miny, minz));
+
+    let mut total = 0;
+
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(steam) = queue.pop_front() {
+        for neighbor in steam.neighbors() {
+            if !in_bounds(&neighbor) {
+                continue;
+            }
+
+            if cubes.contains(&neighbor) {
+                total += 1;
+                continue;
+            }
+
+            if visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    println!("Total exterior sides: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<HashSet<Cube>, ParseError> {
+    input
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            let c = x.split(',').collect::<Vec<_>>();
+            let cube = match c[..] {
+                [x, y, z] => parse_at(input, x)
+                    .and_then(|x| Ok((x, parse_at(input, y)?, parse_at(input, z)?).into())),
+                _ => Err(ParseError::new(input, x, "expected x,y,z")),
+            };
+
+            Some(cube)
+        })
+        .collect::<Result<HashSet<_>, _>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 64);
+        assert_eq!(part2(&values), 58);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("2,2,2\n1,2\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected x,y,z");
+
+        let err = parse("2,2,2\n1,2,q\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_parse::ParseError;
+
+const PART_1_SCORE: [[usize; 3]; 3] = [
+    [3_usize, 6_usize, 0_usize],
+    [0_usize, 3_usize, 6_usize],
+    [6_usize, 0_usize, 3_usize],
+];
+
+pub fn part1(rounds: impl AsRef<[(usize, usize)]>) -> usize {
+    let total: usize = rounds
+        .as_ref()
+        .iter()
+        .map(|(a, b)| *b + 1 + PART_1_SCORE[*a][*b])
+        .sum();
+
+    println!("Total: {}", total);
+
+    total
+}
+
+const PART_2_CHOICE: [[usize; 3]; 3] = [
+    [3_usize, 1_usize, 2_usize],
+    [1_usize, 2_usize, 3_usize],
+    [2_usize, 3_usize, 1_usize],
+];
+
+pub fn part2(rounds: impl AsRef<[(usize, usize)]>) -> usize {
+    let total: usize = rounds
+        .as_ref()
+        .iter()
+        .map(|(a, b)| *b * 3 + PART_2_CHOICE[*a][*b])
+        .sum();
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            let (a, b) = x
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(input, x, "expected two moves"))?;
+
+            let a = match a {
+                "A" => 0,
+                "B" => 1,
+                "C" => 2,
+                _ => return Err(ParseError::new(input, a, "expected A, B or C")),
+            };
+
+            let b = match b {
+                "X" => 0,
+                "Y" => 1,
+                "Z" => 2,
+                _ => return Err(ParseError::new(input, b, "expected X, Y or Z")),
+            };
+
+            Ok((a, b))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 15);
+        assert_eq!(part2(&values), 12);
+    }
+}
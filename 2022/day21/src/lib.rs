@@ -0,0 +1,373 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use aoc_parse::combinators::{key_value, parse_lines, signed, word, IResult};
+use aoc_parse::ParseError;
+use nom::{
+    branch::alt,
+    character::complete::{alpha1, one_of, space1},
+    combinator::map,
+    error::context,
+    sequence::{delimited, tuple},
+};
+
+#[derive(Debug, Clone)]
+enum MonkeyJobOperation {
+    Equality(String, String),
+    Addition(String, String),
+    Subtraction(String, String),
+    Multiplication(String, String),
+    Division(String, String),
+}
+
+impl MonkeyJobOperation {
+    fn left(&self) -> &String {
+        match self {
+            Self::Equality(x, _) => x,
+            Self::Addition(x, _) => x,
+            Self::Subtraction(x, _) => x,
+            Self::Multiplication(x, _) => x,
+            Self::Division(x, _) => x,
+        }
+    }
+
+    fn right(&self) -> &String {
+        match self {
+            Self::Equality(_, y) => y,
+            Self::Addition(_, y) => y,
+            Self::Subtraction(_, y) => y,
+            Self::Multiplication(_, y) => y,
+            Self::Division(_, y) => y,
+        }
+    }
+
+    // returns the operation result and whether the human value was used
+    fn calculate(&self, monkeys: &HashMap<String, Monkey>) -> (i64, bool) {
+        match self {
+            Self::Addition(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                (av + bv, ah || bh)
+            }
+            Self::Subtraction(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                (av - bv, ah || bh)
+            }
+            Self::Multiplication(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                (av * bv, ah || bh)
+            }
+            Self::Division(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                (av / bv, ah || bh)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_human_value(&self, monkeys: &HashMap<String, Monkey>, result: Option<i64>) -> i64 {
+        match self {
+            Self::Equality(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                if ah {
+                    a.get_human_value(monkeys, Some(bv))
+                } else if bh {
+                    b.get_human_value(monkeys, Some(av))
+                } else {
+                    unreachable!()
+                }
+            }
+            Self::Addition(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                // a + b = r
+                if ah {
+                    // a = r - b
+                    a.get_human_value(monkeys, Some(result.unwrap() - bv))
+                } else if bh {
+                    // b = r - a
+                    b.get_human_value(monkeys, Some(result.unwrap() - av))
+                } else {
+                    unreachable!()
+                }
+            }
+            Self::Subtraction(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                // a - b = r
+                if ah {
+                    // a = r + b
+                    a.get_human_value(monkeys, Some(result.unwrap() + bv))
+                } else if bh {
+                    // b = -(r - a) = a - r
+                    b.get_human_value(monkeys, Some(av - result.unwrap()))
+                } else {
+                    unreachable!()
+                }
+            }
+            Self::Multiplication(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                // a * b = r
+                if ah {
+                    // a = r / b
+                    a.get_human_value(monkeys, Some(result.unwrap() / bv))
+                } else if bh {
+                    // b = r / a
+                    b.get_human_value(monkeys, Some(result.unwrap() / av))
+                } else {
+                    unreachable!()
+                }
+            }
+            Self::Division(x, y) => {
+                let a = monkeys.get(x).unwrap();
+                let (av, ah) = a.value(monkeys);
+
+                let b = monkeys.get(y).unwrap();
+                let (bv, bh) = b.value(monkeys);
+
+                // a / b = r
+                if ah {
+                    // a = r * b
+                    a.get_human_value(monkeys, Some(result.unwrap() * bv))
+                } else if bh {
+                    // b = 1 / (r / a) = a / r
+                    b.get_human_value(monkeys, Some(av / result.unwrap()))
+                } else {
+                    unreachable!()
+                }
+            }
+        }
+    }
+}
+
+// pppw + sjmn
+fn parse_monkey_job_operation(input: &str) -> IResult<'_, MonkeyJobOperation> {
+    map(
+        tuple((word, delimited(space1, one_of("+-*/"), space1), word)),
+        |(x, op, y)| {
+            let (x, y) = (x.to_owned(), y.to_owned());
+            match op {
+                '+' => MonkeyJobOperation::Addition(x, y),
+                '-' => MonkeyJobOperation::Subtraction(x, y),
+                '*' => MonkeyJobOperation::Multiplication(x, y),
+                '/' => MonkeyJobOperation::Division(x, y),
+                _ => unreachable!(),
+            }
+        },
+    )(input)
+}
+
+#[derive(Debug, Clone)]
+enum MonkeyJob {
+    Number(i64),
+    Operation(MonkeyJobOperation, RefCell<Option<(i64, bool)>>),
+}
+
+impl MonkeyJob {
+    fn left(&self) -> &String {
+        match self {
+            Self::Operation(op, _) => op.left(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn right(&self) -> &String {
+        match self {
+            Self::Operation(op, _) => op.right(),
+            _ => unreachable!(),
+        }
+    }
+
+    // returns the value and whether the human value was used
+    fn value(&self, monkeys: &HashMap<String, Monkey>, is_human: bool) -> (i64, bool) {
+        match self {
+            Self::Number(v) => (*v, is_human),
+            Self::Operation(op, v) => {
+                if let Some(v) = *v.borrow() {
+                    //println!("cache hit!");
+                    return v;
+                }
+
+                let r = op.calculate(monkeys);
+
+                // only cache values that don't depend on the human value
+                if !r.1 {
+                    *v.borrow_mut() = Some(r);
+                }
+
+                r
+            }
+        }
+    }
+
+    fn get_human_value(
+        &self,
+        monkeys: &HashMap<String, Monkey>,
+        is_human: bool,
+        result: Option<i64>,
+    ) -> i64 {
+        match self {
+            Self::Number(v) => {
+                if !is_human {
+                    *v
+                } else {
+                    result.unwrap()
+                }
+            }
+            Self::Operation(op, _) => op.get_human_value(monkeys, result),
+        }
+    }
+}
+
+impl From<i64> for MonkeyJob {
+    fn from(v: i64) -> Self {
+        Self::Number(v)
+    }
+}
+
+impl From<MonkeyJobOperation> for MonkeyJob {
+    fn from(v: MonkeyJobOperation) -> Self {
+        Self::Operation(v, RefCell::new(None))
+    }
+}
+
+fn parse_monkey_job_number(input: &str) -> IResult<'_, MonkeyJob> {
+    map(signed::<i64>, Into::into)(input)
+}
+
+fn parse_monkey_job(input: &str) -> IResult<'_, MonkeyJob> {
+    context(
+        "a number or an operation",
+        alt((
+            parse_monkey_job_number,
+            map(parse_monkey_job_operation, Into::into),
+        )),
+    )(input)
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    name: String,
+    job: MonkeyJob,
+
+    is_human: bool,
+}
+
+impl Monkey {
+    fn value(&self, monkeys: &HashMap<String, Monkey>) -> (i64, bool) {
+        self.job.value(monkeys, self.is_human)
+    }
+
+    fn get_human_value(&self, monkeys: &HashMap<String, Monkey>, result: Option<i64>) -> i64 {
+        self.job.get_human_value(monkeys, self.is_human, result)
+    }
+}
+
+impl From<(String, MonkeyJob)> for Monkey {
+    fn from(v: (String, MonkeyJob)) -> Self {
+        let is_human = v.0 == "humn";
+        Self {
+            name: v.0,
+            job: v.1,
+            is_human,
+        }
+    }
+}
+
+fn parse_monkey_name(input: &str) -> IResult<'_, String> {
+    map(context("a monkey name", alpha1), Into::into)(input)
+}
+
+fn parse_monkey(input: &str) -> IResult<'_, Monkey> {
+    map(key_value(parse_monkey_name, parse_monkey_job), Into::into)(input)
+}
+
+pub fn part1(monkeys: &HashMap<String, Monkey>) -> i64 {
+    let (rv, _) = monkeys.get("root").unwrap().value(monkeys);
+    println!("Root value: {}", rv);
+
+    rv
+}
+
+pub fn part2(mut monkeys: HashMap<String, Monkey>) -> i64 {
+    monkeys.get_mut("root").unwrap().job = {
+        let job = &monkeys.get("root").unwrap().job;
+        MonkeyJob::Operation(
+            MonkeyJobOperation::Equality(job.left().clone(), job.right().clone()),
+            RefCell::new(None),
+        )
+    };
+
+    let human_value = monkeys.get("root").unwrap().get_human_value(&monkeys, None);
+    println!("Human value: {}", human_value);
+
+    human_value
+}
+
+pub fn parse(input: &str) -> Result<HashMap<String, Monkey>, ParseError> {
+    Ok(parse_lines(input, parse_monkey)?
+        .into_iter()
+        .map(|x| (x.name.clone(), x))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 152);
+        assert_eq!(part2(values), 301);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("root: pppw + sjmn\ndbpl: 5\ncczh: sllz % lgvd\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 7));
+        assert_eq!(err.message, "expected a number or an operation");
+
+        let err = parse("root: pppw + sjmn\ndbpl 5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.message, "expected ':'");
+    }
+}
@@ -0,0 +1,612 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use aoc_parse::combinators::{blank_line, grid, parse_all, unsigned, IResult};
+use aoc_parse::ParseError;
+use nom::{
+    branch::alt, character::complete::char, combinator::map, error::context, multi::many1,
+    sequence::separated_pair,
+};
+
+fn modulus(n: i64, m: i64) -> i64 {
+    ((n % m) + m) % m
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Turn {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl From<char> for Turn {
+    fn from(v: char) -> Self {
+        match v {
+            'R' => Self::Clockwise,
+            'L' => Self::CounterClockwise,
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn parse_turn(input: &str) -> IResult<'_, Turn> {
+    map(alt((char('R'), char('L'))), Into::into)(input)
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Turn(Turn),
+    Distance(usize),
+}
+
+impl From<Turn> for Instruction {
+    fn from(v: Turn) -> Self {
+        Self::Turn(v)
+    }
+}
+
+impl From<usize> for Instruction {
+    fn from(v: usize) -> Self {
+        Self::Distance(v)
+    }
+}
+
+fn parse_instruction_turn(input: &str) -> IResult<'_, Instruction> {
+    map(parse_turn, Into::into)(input)
+}
+
+fn parse_instruction_distance(input: &str) -> IResult<'_, Instruction> {
+    map(unsigned::<usize>, Into::into)(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<'_, Instruction> {
+    context(
+        "a distance, R or L",
+        alt((parse_instruction_turn, parse_instruction_distance)),
+    )(input)
+}
+
+fn parse_instructions(input: &str) -> IResult<'_, Vec<Instruction>> {
+    many1(parse_instruction)(input)
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Direction {
+    x: i64,
+    y: i64,
+}
+
+impl Direction {
+    fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    fn from_value(v: usize) -> Self {
+        match v {
+            0 => Self::new(1, 0),
+            1 => Self::new(0, 1),
+            2 => Self::new(-1, 0),
+            3 => Self::new(0, -1),
+            _ => unreachable!(),
+        }
+    }
+
+    fn as_value(&self) -> usize {
+        if self.x > 0 {
+            0
+        } else if self.y > 0 {
+            1
+        } else if self.x < 0 {
+            2
+        } else if self.y < 0 {
+            3
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+impl Direction {
+    fn turn(&mut self, turn: Turn) {
+        // NOTE: these are backwards because our y is down, not up
+        match turn {
+            Turn::Clockwise => {
+                let x = self.x;
+                self.x = -self.y;
+                self.y = x;
+            }
+            Turn::CounterClockwise => {
+                let x = self.x;
+                self.x = self.y;
+                self.y = -x;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TileType {
+    None,
+    Open,
+    Wall,
+}
+
+// the error is the character that isn't a tile
+impl TryFrom<char> for TileType {
+    type Error = char;
+
+    fn try_from(v: char) -> Result<Self, Self::Error> {
+        match v {
+            ' ' => Ok(Self::None),
+            '.' => Ok(Self::Open),
+            '#' => Ok(Self::Wall),
+            _ => Err(v),
+        }
+    }
+}
+
+impl fmt::Display for TileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::None => write!(f, " "),
+            Self::Open => write!(f, "."),
+            Self::Wall => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Tile {
+    r#type: TileType,
+
+    // where moving in each direction ends up
+    // and the direction we're facing once we get there
+    connections: [(Position, Direction); 4],
+}
+
+impl Tile {
+    fn is_none(&self) -> bool {
+        self.r#type == TileType::None
+    }
+
+    fn is_open(&self) -> bool {
+        self.r#type == TileType::Open
+    }
+
+    fn get_next_position(&self, direction: Direction) -> (Position, Direction) {
+        self.connections[direction.as_value()]
+    }
+}
+
+impl From<TileType> for Tile {
+    fn from(v: TileType) -> Self {
+        Self {
+            r#type: v,
+            connections: [(Position::default(), Direction::default()); 4],
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.r#type)
+    }
+}
+
+type Vec3 = [i64; 3];
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: Vec3, s: i64) -> Vec3 {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn dot(a: Vec3, b: Vec3) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// where a face of the net ends up on the cube
+// normal points out of the cube, right and down are the net's x and y
+#[derive(Debug, Copy, Clone)]
+struct FaceOrientation {
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl Default for FaceOrientation {
+    fn default() -> Self {
+        Self {
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }
+    }
+}
+
+impl FaceOrientation {
+    fn direction(&self, direction: Direction) -> Vec3 {
+        add(
+            scale(self.right, direction.x),
+            scale(self.down, direction.y),
+        )
+    }
+
+    // folds over the edge in the given direction,
+    // whatever was pointing that way becomes the new normal
+    // and the old normal now points back into the cube
+    fn fold(&self, direction: Direction) -> Self {
+        match direction.as_value() {
+            0 => Self {
+                normal: self.right,
+                right: scale(self.normal, -1),
+                down: self.down,
+            },
+            1 => Self {
+                normal: self.down,
+                right: self.right,
+                down: scale(self.normal, -1),
+            },
+            2 => Self {
+                normal: scale(self.right, -1),
+                right: self.normal,
+                down: self.down,
+            },
+            3 => Self {
+                normal: scale(self.down, -1),
+                right: self.right,
+                down: self.normal,
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    tiles: Vec<Vec<Tile>>,
+}
+
+impl Map {
+    fn init_part1(&mut self) {
+        let mut tiles = self
+            .tiles
+            .iter()
+            .map(|x| {
+                x.iter()
+                    .map(|y| RefCell::new(y.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let height = tiles.len();
+        for (y, row) in tiles.iter().enumerate() {
+            let width = row.len();
+            for (x, tile) in row.iter().enumerate() {
+                if !tile.borrow().is_open() {
+                    continue;
+                }
+
+                // right (0)
+                let mut t = (x + 1) % width;
+                loop {
+                    assert!(t != x);
+
+                    if !row.get(t).unwrap().borrow().is_none() {
+                        tile.borrow_mut().connections[0] =
+                            (Position::new(t, y), Direction::from_value(0));
+                        break;
+                    }
+
+                    t = (t + 1) % width;
+                }
+
+                // down (1)
+                let mut t = (y + 1) % height;
+                loop {
+                    assert!(t != y);
+
+                    let row = tiles.get(t).unwrap();
+                    if x >= row.len() {
+                        t = (t + 1) % height;
+                        continue;
+                    }
+
+                    if !row.get(x).unwrap().borrow().is_none() {
+                        tile.borrow_mut().connections[1] =
+                            (Position::new(x, t), Direction::from_value(1));
+                        break;
+                    }
+
+                    t = (t + 1) % height;
+                }
+
+                // left (2)
+                let mut t = modulus(x as i64 - 1, width as i64) as usize;
+                loop {
+                    assert!(t != x);
+
+                    if !row.get(t).unwrap().borrow().is_none() {
+                        tile.borrow_mut().connections[2] =
+                            (Position::new(t, y), Direction::from_value(2));
+                        break;
+                    }
+
+                    t = modulus(t as i64 - 1, width as i64) as usize;
+                }
+
+                // up (3)
+                let mut t = modulus(y as i64 - 1, height as i64) as usize;
+                loop {
+                    assert!(t != y);
+
+                    let row = tiles.get(t).unwrap();
+                    if x >= row.len() {
+                        t = modulus(t as i64 - 1, height as i64) as usize;
+                        continue;
+                    }
+
+                    if !row.get(x).unwrap().borrow().is_none() {
+                        tile.borrow_mut().connections[3] =
+                            (Position::new(x, t), Direction::from_value(3));
+                        break;
+                    }
+
+                    t = modulus(t as i64 - 1, height as i64) as usize;
+                }
+            }
+        }
+
+        self.tiles = tiles
+            .drain(..)
+            .map(|mut x| x.drain(..).map(|x| x.into_inner()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+    }
+
+    fn init_part2(&mut self) {
+        // every tile in the net is on the surface of the cube
+        let area = self.tiles.iter().flatten().filter(|t| !t.is_none()).count();
+        let size = ((area / 6) as f64).sqrt() as usize;
+        assert!(size * size * 6 == area);
+
+        let faces = self.fold_cube(size);
+        let face_normals = faces
+            .iter()
+            .map(|(&face, orientation)| (orientation.normal, face))
+            .collect::<HashMap<_, _>>();
+
+        let n = size as i64;
+        for y in 0..self.tiles.len() {
+            for x in 0..self.tiles[y].len() {
+                if !self.tiles[y][x].is_open() {
+                    continue;
+                }
+
+                let face = faces.get(&(x / size, y / size)).unwrap();
+                let (i, j) = ((x % size) as i64, (y % size) as i64);
+
+                for v in 0..4 {
+                    let direction = Direction::from_value(v);
+                    let (ni, nj) = (i + direction.x, j + direction.y);
+
+                    // staying on the same face is just a normal step
+                    if (0..n).contains(&ni) && (0..n).contains(&nj) {
+                        let next = Position::new(
+                            (x as i64 + direction.x) as usize,
+                            (y as i64 + direction.y) as usize,
+                        );
+                        self.tiles[y][x].connections[v] = (next, direction);
+                        continue;
+                    }
+
+                    // otherwise go over the edge in 3d and find where we land
+                    // (the tile centers are at odd coordinates on a cube spanning -n..n)
+                    let edge = face.direction(direction);
+                    let p = add(
+                        add(scale(face.normal, n), scale(face.right, 2 * i - n + 1)),
+                        scale(face.down, 2 * j - n + 1),
+                    );
+                    let q = sub(add(p, edge), face.normal);
+
+                    let next_face = *face_normals.get(&edge).unwrap();
+                    let next = faces.get(&next_face).unwrap();
+                    let ni = (dot(q, next.right) + n - 1) / 2;
+                    let nj = (dot(q, next.down) + n - 1) / 2;
+
+                    // we're now heading away from the face we left
+                    let away = scale(face.normal, -1);
+                    let next_direction = (0..4)
+                        .map(Direction::from_value)
+                        .find(|&d| next.direction(d) == away)
+                        .unwrap();
+
+                    let next_position = Position::new(
+                        next_face.0 * size + ni as usize,
+                        next_face.1 * size + nj as usize,
+                    );
+                    self.tiles[y][x].connections[v] = (next_position, next_direction);
+                }
+            }
+        }
+    }
+
+    // walks the net folding each face onto the cube
+    // returns the 3d orientation of each face, keyed on its position in the net
+    fn fold_cube(&self, size: usize) -> HashMap<(usize, usize), FaceOrientation> {
+        let is_face = |fx: usize, fy: usize| {
+            self.tiles
+                .get(fy * size)
+                .and_then(|row| row.get(fx * size))
+                .map(|tile| !tile.is_none())
+                .unwrap_or_default()
+        };
+
+        let start = ((0..).find(|&fx| is_face(fx, 0)).unwrap(), 0);
+
+        let mut faces = HashMap::from([(start, FaceOrientation::default())]);
+        let mut queue = VecDeque::from([start]);
+        while let Some((fx, fy)) = queue.pop_front() {
+            let orientation = *faces.get(&(fx, fy)).unwrap();
+            for v in 0..4 {
+                let direction = Direction::from_value(v);
+                let (nx, ny) = (fx as i64 + direction.x, fy as i64 + direction.y);
+                if nx < 0 || ny < 0 || !is_face(nx as usize, ny as usize) {
+                    continue;
+                }
+
+                let next = (nx as usize, ny as usize);
+                if faces.contains_key(&next) {
+                    continue;
+                }
+
+                faces.insert(next, orientation.fold(direction));
+                queue.push_back(next);
+            }
+        }
+
+        assert!(faces.len() == 6);
+        faces
+    }
+
+    fn get_start_position(&self) -> Position {
+        for x in 0..self.tiles[0].len() {
+            let position = Position::new(x, 0);
+            let tile = self.get_tile(position);
+            if tile.is_open() {
+                return position;
+            }
+        }
+        unreachable!()
+    }
+
+    fn get_tile(&self, position: Position) -> &Tile {
+        &self.tiles[position.y][position.x]
+    }
+
+    fn get_next_position(
+        &self,
+        mut position: Position,
+        direction: Direction,
+        distance: usize,
+    ) -> (Position, Direction, usize) {
+        let mut direction = direction;
+        let mut tile = &self.tiles[position.y][position.x];
+        for v in 0..distance {
+            let (next, next_direction) = tile.get_next_position(direction);
+            tile = &self.tiles[next.y][next.x];
+            if !tile.is_open() {
+                return (position, direction, v + 1);
+            }
+            position = next;
+            direction = next_direction;
+        }
+        (position, direction, distance)
+    }
+}
+
+fn parse_map(input: &str) -> IResult<'_, Map> {
+    map(
+        grid(|c| TileType::try_from(c).ok().map(Tile::from)),
+        |tiles| Map { tiles },
+    )(input)
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.tiles {
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(mut map: Map, instructions: impl AsRef<[Instruction]>) -> usize {
+    map.init_part1();
+
+    let mut position = map.get_start_position();
+    let mut direction = Direction::new(1, 0);
+
+    for instruction in instructions.as_ref() {
+        match instruction {
+            Instruction::Distance(distance) => {
+                let (p, d, _d) = map.get_next_position(position, direction, *distance);
+                position = p;
+                direction = d;
+            }
+            Instruction::Turn(turn) => {
+                direction.turn(*turn);
+            }
+        }
+    }
+
+    let total = (1000 * (position.y + 1)) + (4 * (position.x + 1)) + direction.as_value();
+    println!("Final password: {}", total);
+
+    total
+}
+
+pub fn part2(mut map: Map, instructions: impl AsRef<[Instruction]>) -> usize {
+    map.init_part2();
+
+    let mut position = map.get_start_position();
+    let mut direction = Direction::new(1, 0);
+
+    for instruction in instructions.as_ref() {
+        match instruction {
+            Instruction::Distance(distance) => {
+                let (p, d, _d) = map.get_next_position(position, direction, *distance);
+                position = p;
+                direction = d;
+            }
+            Instruction::Turn(turn) => {
+                direction.turn(*turn);
+            }
+        }
+    }
+
+    let total = (1000 * (position.y + 1)) + (4 * (position.x + 1)) + direction.as_value();
+    println!("Final password: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<(Map, Vec<Instruction>), ParseError> {
+    parse_all(
+        input,
+        separated_pair(parse_map, blank_line, parse_instructions),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (map, instructions) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(map.clone(), &instructions), 6032);
+        assert_eq!(part2(map, instructions), 5031);
+    }
+}
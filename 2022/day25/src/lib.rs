@@ -0,0 +1,312 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+
+// balanced base 5, each digit is -2..=2
+fn digit_to_char(d: i64) -> char {
+    match d {
+        2 => '2',
+        1 => '1',
+        0 => '0',
+        -1 => '-',
+        -2 => '=',
+        _ => unreachable!("invalid snafu digit {}", d),
+    }
+}
+
+fn char_to_digit(ch: char) -> Option<i64> {
+    match ch {
+        '2' => Some(2),
+        '1' => Some(1),
+        '0' => Some(0),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snafu {
+    snafu: String,
+    decimal: i64,
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        // in i128 since the last digit can pull a value back into range for an i64
+        let mut decimal = 0_i128;
+        for (idx, ch) in v.char_indices() {
+            let d = char_to_digit(ch).ok_or_else(|| {
+                ParseError::new(v, &v[idx..idx + ch.len_utf8()], "invalid snafu digit")
+            })?;
+            decimal = decimal
+                .checked_mul(5)
+                .and_then(|decimal| decimal.checked_add(d as i128))
+                .ok_or_else(|| ParseError::new(v, v, "snafu number is too big"))?;
+        }
+        let decimal =
+            i64::try_from(decimal).map_err(|_| ParseError::new(v, v, "snafu number is too big"))?;
+
+        Ok(Self {
+            snafu: v.to_owned(),
+            decimal,
+        })
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(v: i64) -> Self {
+        if v == 0 {
+            return Self {
+                snafu: "0".to_owned(),
+                decimal: 0,
+            };
+        }
+
+        // regular base 5, except 3 and 4 become -2 and -1
+        // and carry 1 into the next digit to make up for it
+        // (n - d can go past i64::MIN / MAX, so do the work in i128)
+        let mut digits = vec![];
+        let mut n = v as i128;
+        while n != 0 {
+            let d = match n.rem_euclid(5) {
+                r @ 0..=2 => r,
+                r => r - 5,
+            };
+            digits.push(digit_to_char(d as i64));
+            n = (n - d) / 5;
+        }
+
+        Self {
+            snafu: digits.iter().rev().collect(),
+            decimal: v,
+        }
+    }
+}
+
+impl Snafu {
+    // adds the digits directly in snafu, without going through decimal
+    fn add_digits(&self, other: &Snafu) -> String {
+        let mut a = self
+            .snafu
+            .chars()
+            .rev()
+            .map(|ch| char_to_digit(ch).unwrap());
+        let mut b = other
+            .snafu
+            .chars()
+            .rev()
+            .map(|ch| char_to_digit(ch).unwrap());
+
+        let mut digits = vec![];
+        let mut carry = 0;
+        loop {
+            let (x, y) = (a.next(), b.next());
+            if x.is_none() && y.is_none() && carry == 0 {
+                break;
+            }
+
+            let mut d = x.unwrap_or_default() + y.unwrap_or_default() + carry;
+            carry = 0;
+            if d > 2 {
+                d -= 5;
+                carry = 1;
+            } else if d < -2 {
+                d += 5;
+                carry = -1;
+            }
+            digits.push(d);
+        }
+
+        // drop any leading zeros left over from cancelled out digits
+        while digits.len() > 1 && digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        if digits.is_empty() {
+            return "0".to_owned();
+        }
+
+        digits.iter().rev().map(|&d| digit_to_char(d)).collect()
+    }
+}
+
+impl Snafu {
+    // None if the sum doesn't fit in an i64
+    fn checked_add(&self, other: &Snafu) -> Option<Snafu> {
+        Some(Snafu {
+            decimal: self.decimal.checked_add(other.decimal)?,
+            snafu: self.add_digits(other),
+        })
+    }
+}
+
+impl Add for &Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: &Snafu) -> Self::Output {
+        self.checked_add(other)
+            .unwrap_or_else(|| panic!("{} + {} overflows an i64", self, other))
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: Snafu) -> Self::Output {
+        &self + &other
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::from(0), |acc, x| &acc + x)
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::from(0), |acc, x| acc + x)
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.snafu)
+    }
+}
+
+pub fn part1(values: impl AsRef<[Snafu]>) -> Result<String, String> {
+    /*for value in values.as_ref() {
+        println!("{} {}", value, value.decimal);
+    }*/
+
+    let total = values
+        .as_ref()
+        .iter()
+        .try_fold(Snafu::from(0), |acc, x| acc.checked_add(x))
+        .ok_or_else(|| "the total overflows an i64".to_owned())?;
+
+    // the digit-wise sum should agree with converting the decimal sum
+    assert!(total == Snafu::from(total.decimal));
+
+    println!("Total: {} ({})", total, total.decimal);
+
+    Ok(total.to_string())
+}
+
+pub fn parse(input: &str) -> Result<Vec<Snafu>, ParseError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse().map_err(|err: ParseError| err.within(input, x)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(values), Ok("2=-1=0".to_owned()));
+    }
+
+    #[test]
+    fn examples() {
+        for (decimal, snafu) in [
+            (1, "1"),
+            (2, "2"),
+            (3, "1="),
+            (4, "1-"),
+            (5, "10"),
+            (8, "2="),
+            (9, "2-"),
+            (10, "20"),
+            (15, "1=0"),
+            (20, "1-0"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+        ] {
+            assert_eq!(Snafu::from(decimal).snafu, snafu);
+            assert_eq!(snafu.parse::<Snafu>().unwrap().decimal, decimal);
+        }
+    }
+
+    #[test]
+    fn invalid_digit() {
+        let err = "1=3".parse::<Snafu>().unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (3, "3"));
+
+        let err = parse("1=\n2-\n1=3").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+    }
+
+    #[test]
+    fn extremes() {
+        for n in [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX] {
+            let snafu = Snafu::from(n);
+            let parsed = snafu.to_string().parse::<Snafu>().unwrap();
+            assert_eq!(parsed.decimal, n, "{} -> {}", n, snafu);
+        }
+
+        // one more than i64::MAX
+        let err = "1110--=-02=100==0-0=11=1122=".parse::<Snafu>().unwrap_err();
+        assert_eq!(err.message, "snafu number is too big");
+
+        // and way out past an i128
+        let err = "2".repeat(60).parse::<Snafu>().unwrap_err();
+        assert_eq!(err.message, "snafu number is too big");
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(n in any::<i64>()) {
+            let snafu = Snafu::from(n);
+            let parsed = snafu.to_string().parse::<Snafu>().unwrap();
+            prop_assert_eq!(parsed.decimal, n);
+            prop_assert_eq!(parsed, snafu);
+        }
+
+        #[test]
+        fn digit_addition(a in i64::MIN / 2..=i64::MAX / 2, b in i64::MIN / 2..=i64::MAX / 2) {
+            prop_assert_eq!(&Snafu::from(a) + &Snafu::from(b), Snafu::from(a + b));
+        }
+
+        #[test]
+        fn addition_near_max(a in i64::MAX - 1000..=i64::MAX, b in 0..=1000_i64) {
+            let sum = Snafu::from(a).checked_add(&Snafu::from(b));
+            prop_assert_eq!(sum, a.checked_add(b).map(Snafu::from));
+        }
+    }
+
+    #[test]
+    fn example_sum() {
+        let total: Snafu = [
+            "1=-0-2", "12111", "2=0=", "21", "2=01", "111", "20012", "112", "1=-1=", "1-12", "12",
+            "1=", "122",
+        ]
+        .into_iter()
+        .map(|snafu| snafu.parse::<Snafu>().unwrap())
+        .sum();
+
+        assert_eq!(total.decimal, 4890);
+        assert_eq!(total.to_string(), "2=-1=0");
+    }
+
+    #[test]
+    fn total_overflow() {
+        let values = parse(&format!("{}\n1\n", Snafu::from(i64::MAX))).unwrap();
+        assert!(part1(values).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_parse::{parse_at, ParseError};
+
+// a note from https://fasterthanli.me/series/advent-of-code-2022/part-4
+// ranges in Rust have a contains() method (std::ops::RangeInclusive)
+
+// first and last section an elf is assigned
+type Sections = (usize, usize);
+
+pub fn part1(values: impl AsRef<[(Sections, Sections)]>) -> usize {
+    let mut total = 0;
+    for value in values.as_ref() {
+        if value.0 .0 <= value.1 .0 && value.0 .1 >= value.1 .1
+            || value.1 .0 <= value.0 .0 && value.1 .1 >= value.0 .1
+        {
+            total += 1;
+        }
+    }
+
+    println!("Total completely overlapping assignments: {}", total);
+
+    total
+}
+
+pub fn part2(values: impl AsRef<[(Sections, Sections)]>) -> usize {
+    let mut total = 0;
+    for value in values.as_ref() {
+        if value.0 .0 <= value.1 .0 && value.0 .1 >= value.1 .0
+            || value.1 .0 <= value.0 .0 && value.1 .1 >= value.0 .0
+        {
+            total += 1;
+        }
+    }
+
+    println!("Total overlapping assignments: {}", total);
+
+    total
+}
+
+// a-b, as a slice of source
+fn range(source: &str, text: &str) -> Result<Sections, ParseError> {
+    let (a, b) = text
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(source, text, "expected a range, a-b"))?;
+    Ok((parse_at(source, a)?, parse_at(source, b)?))
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Sections, Sections)>, ParseError> {
+    input
+        .lines()
+        .filter_map(|x| {
+            let x = x.trim();
+            if x.is_empty() {
+                return None;
+            }
+
+            let Some(pairs) = x.split_once(',') else {
+                return Some(Err(ParseError::new(input, x, "expected a pair of ranges")));
+            };
+            Some(range(input, pairs.0).and_then(|a| Ok((a, range(input, pairs.1)?))))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 2);
+        assert_eq!(part2(&values), 4);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("2-4,6-8\n2-3,4\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.message, "expected a range, a-b");
+
+        let err = parse("2-4,6-8\n2-3\n").unwrap_err();
+        assert_eq!(err.message, "expected a pair of ranges");
+    }
+}
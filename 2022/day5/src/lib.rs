@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct Move {
+    amount: usize,
+    start: usize,
+    end: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
+        let captures = re
+            .captures(line)
+            .ok_or_else(|| ParseError::new(line, line, "expected move N from A to B"))?;
+
+        // stacks are numbered from 1
+        let stack = |idx| {
+            let text = captures.get(idx).unwrap().as_str();
+            match parse_at::<usize>(line, text)? {
+                0 => Err(ParseError::new(line, text, "stacks start at 1")),
+                stack => Ok(stack - 1),
+            }
+        };
+
+        Ok(Self {
+            amount: parse_at(line, captures.get(1).unwrap().as_str())?,
+            start: stack(2)?,
+            end: stack(3)?,
+        })
+    }
+}
+
+pub fn part1(mut stacks: Vec<Vec<char>>, moves: impl AsRef<[Move]>) -> String {
+    for r#move in moves.as_ref() {
+        for _ in 0..r#move.amount {
+            let x = stacks[r#move.start].pop().unwrap();
+            stacks[r#move.end].push(x);
+        }
+    }
+
+    let mut top = String::with_capacity(stacks.len());
+    for mut stack in stacks {
+        top.push(stack.pop().unwrap());
+    }
+
+    println!("Top: {}", top);
+
+    top
+}
+
+pub fn part2(mut stacks: Vec<Vec<char>>, moves: impl AsRef<[Move]>) -> String {
+    for r#move in moves.as_ref() {
+        let mut s = Vec::with_capacity(r#move.amount);
+        for _ in 0..r#move.amount {
+            let x = stacks[r#move.start].pop().unwrap();
+            s.push(x);
+        }
+        stacks[r#move.end].extend(s.iter().rev());
+    }
+
+    let mut top = String::with_capacity(stacks.len());
+    for mut stack in stacks {
+        top.push(stack.pop().unwrap());
+    }
+
+    println!("Top: {}", top);
+
+    top
+}
+
+pub fn parse(input: &str) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+    let (stacks_input, moves) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::truncated(
+            input,
+            "expected a blank line between the stacks and the moves",
+        )
+    })?;
+
+    // the last line numbers the stacks
+    let numbers = stacks_input.lines().last().unwrap_or("");
+    let stack_count = match numbers.split_ascii_whitespace().last() {
+        Some(count) => parse_at::<usize>(input, count)?,
+        None => {
+            return Err(ParseError::new(
+                input,
+                numbers,
+                "expected the stack numbers",
+            ))
+        }
+    };
+
+    let mut stacks = Vec::with_capacity(stack_count);
+    for _ in 0..stack_count {
+        stacks.push(vec![]);
+    }
+
+    for x in stacks_input.lines().rev().skip(1) {
+        for (idx, x) in x.as_bytes().chunks(4).enumerate() {
+            let x = std::str::from_utf8(x).unwrap().trim();
+            if x.is_empty() {
+                continue;
+            }
+
+            match (stacks.get_mut(idx), x.chars().nth(1)) {
+                (Some(stack), Some(ch)) if x.len() == 3 => stack.push(ch),
+                (None, _) => {
+                    return Err(ParseError::new(input, x, "more stacks than are numbered"))
+                }
+                _ => return Err(ParseError::new(input, x, "expected a crate like [A]")),
+            }
+        }
+    }
+
+    let moves = moves
+        .lines()
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            let r#move = x.parse::<Move>().map_err(|err| err.within(input, x))?;
+            if r#move.start >= stack_count || r#move.end >= stack_count {
+                return Err(ParseError::new(
+                    input,
+                    x,
+                    format!("there are only {} stacks", stack_count),
+                ));
+            }
+            Ok(r#move)
+        })
+        .collect::<Result<Vec<Move>, _>>()?;
+
+    Ok((stacks, moves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (stacks, moves) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(stacks.clone(), &moves), "CMZ");
+        assert_eq!(part2(stacks, &moves), "MCD");
+    }
+}
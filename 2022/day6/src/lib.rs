@@ -0,0 +1,50 @@
+use itertools::Itertools;
+
+// NOTE: all_unique() builds a HashSet under the hood so this solution is not allocation-free
+
+pub fn part1(input: impl AsRef<str>) -> usize {
+    let mut n = 0;
+    for x in input.as_ref().as_bytes().windows(4) {
+        if x.iter().all_unique() {
+            break;
+        }
+        n += 1;
+    }
+    n += 4;
+
+    println!("Packet start: {}", n);
+
+    n
+}
+
+pub fn part2(input: impl AsRef<str>) -> usize {
+    let mut n = 0;
+    for x in input.as_ref().as_bytes().windows(14) {
+        if x.iter().all_unique() {
+            break;
+        }
+        n += 1;
+    }
+    n += 14;
+
+    println!("Message start: {}", n);
+
+    n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // each line is a separate datastream
+
+    #[test]
+    fn example() {
+        let expected = [(7, 19), (5, 23), (6, 23), (10, 29)];
+        let input = include_str!("../examples/example.txt");
+
+        for (line, expected) in input.lines().zip(expected) {
+            assert_eq!((part1(line), part2(line)), expected, "{}", line);
+        }
+    }
+}
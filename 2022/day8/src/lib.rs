@@ -0,0 +1,108 @@
+use aoc_parse::combinators::{grid, parse_all};
+use aoc_parse::ParseError;
+
+pub fn part1(grid: &[Vec<u32>]) -> usize {
+    let mut visible = 0;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &h) in row.iter().enumerate() {
+            let left_visible = x == 0 || row.iter().take(x).all(|&v| v < h);
+            if left_visible {
+                visible += 1;
+                continue;
+            }
+
+            let right_visible = x == row.len() - 1 || row.iter().skip(x + 1).all(|&v| v < h);
+            if right_visible {
+                visible += 1;
+                continue;
+            }
+
+            let top_visible = y == 0 || grid.iter().take(y).all(|v| v[x] < h);
+            if top_visible {
+                visible += 1;
+                continue;
+            }
+
+            let bottom_visible = y == grid.len() - 1 || grid.iter().skip(y + 1).all(|v| v[x] < h);
+            if bottom_visible {
+                visible += 1;
+                continue;
+            }
+        }
+    }
+
+    println!("Visible trees: {}", visible);
+
+    visible
+}
+
+pub fn part2(grid: &[Vec<u32>]) -> usize {
+    let mut scores = Vec::with_capacity(grid.len() * grid[0].len());
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &h) in row.iter().enumerate() {
+            let mut left_score = 0;
+            for &v in row.iter().take(x).rev() {
+                left_score += 1;
+                if v >= h {
+                    break;
+                }
+            }
+
+            let mut right_score = 0;
+            for &v in row.iter().skip(x + 1) {
+                right_score += 1;
+                if v >= h {
+                    break;
+                }
+            }
+
+            let mut top_score = 0;
+            for v in grid.iter().take(y).rev() {
+                top_score += 1;
+                if v[x] >= h {
+                    break;
+                }
+            }
+
+            let mut bottom_score = 0;
+            for v in grid.iter().skip(y + 1) {
+                bottom_score += 1;
+                if v[x] >= h {
+                    break;
+                }
+            }
+
+            let score = left_score * right_score * top_score * bottom_score;
+            scores.push(score);
+        }
+    }
+
+    let highest = *scores.iter().max().unwrap();
+    println!("Highest score: {}", highest);
+
+    highest
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse_all(input, grid(|ch| ch.to_digit(10)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 21);
+        assert_eq!(part2(&values), 8);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("30373\n25512\n65 32\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.message, "expected a grid cell");
+    }
+}
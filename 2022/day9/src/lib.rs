@@ -0,0 +1,305 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    #[inline]
+    fn distance(&self, other: &Position) -> (i32, i32) {
+        (other.x - self.x, other.y - self.y)
+    }
+
+    #[inline]
+    fn is_touching(&self, other: &Position) -> bool {
+        let distance = self.distance(other);
+        distance.0.abs() <= 1 && distance.1.abs() <= 1
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(ParseError::new(v, v, "expected U, D, L or R")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Instruction {
+    direction: Direction,
+    amount: usize,
+}
+
+impl Instruction {
+    fn new(direction: Direction, amount: usize) -> Self {
+        Self { direction, amount }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let d = match self.direction {
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{} {}", d, self.amount)
+    }
+}
+
+#[derive(Debug)]
+struct Grid {
+    grid: Vec<Vec<usize>>,
+
+    knots: Vec<RefCell<Position>>,
+    start: Position,
+}
+
+impl Grid {
+    fn new(width: usize, height: usize, start: Position, knot_count: usize) -> Self {
+        let mut grid = Vec::with_capacity(height);
+        for _ in 0..height {
+            grid.push(vec![0; width]);
+        }
+        grid[start.y as usize][start.x as usize] = 1;
+
+        #[allow(clippy::let_and_return)]
+        let this = Self {
+            grid,
+            knots: vec![RefCell::new(start); knot_count],
+            start,
+        };
+
+        #[cfg(feature = "debugvis")]
+        {
+            println!("== Initial State == ");
+            println!("{}", this);
+        }
+
+        this
+    }
+
+    fn update(&mut self, instruction: Instruction) {
+        let mut remaining = instruction.amount;
+        loop {
+            if remaining < 1 {
+                break;
+            }
+
+            // move the head according to the instruction
+            {
+                let mut head = self.knots.first().unwrap().borrow_mut();
+                match instruction.direction {
+                    Direction::Up => head.y += 1,
+                    Direction::Down => head.y -= 1,
+                    Direction::Left => head.x -= 1,
+                    Direction::Right => head.x += 1,
+                }
+            }
+            remaining -= 1;
+
+            // move the other knots to follow the knot in front of them
+            for (i, knot) in self.knots.iter().skip(1).enumerate() {
+                let parent = self.knots.get(i).unwrap().borrow();
+
+                // if we're touching our parent we don't need to move
+                if knot.borrow().is_touching(&parent) {
+                    #[cfg(feature = "debugvis")]
+                    println!("{}", self);
+                    continue;
+                }
+
+                let distance = knot.borrow().distance(&parent);
+                {
+                    let mut knot = knot.borrow_mut();
+                    knot.x += distance.0.clamp(-1, 1);
+                    knot.y += distance.1.clamp(-1, 1);
+                }
+
+                let knot = knot.borrow();
+                assert!(knot.is_touching(&parent));
+
+                // track the cells the tail touches
+                if i == self.knots.len() - 2 {
+                    self.grid[knot.y as usize][knot.x as usize] += 1;
+                }
+
+                #[cfg(feature = "debugvis")]
+                println!("{}", self);
+            }
+        }
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let head = self.knots[0].borrow();
+
+        for (y, row) in self.grid.iter().enumerate() {
+            let y = y as i32;
+            for (x, v) in row.iter().enumerate() {
+                let x = x as i32;
+
+                let ch = if x == head.x && y == head.y {
+                    'H'
+                } else {
+                    let mut t = None;
+                    for (i, knot) in self.knots.iter().skip(1).enumerate() {
+                        let knot = knot.borrow();
+
+                        if x == knot.x && y == knot.y {
+                            t = Some(i + 1);
+                            break;
+                        }
+                    }
+
+                    if let Some(t) = t {
+                        t.to_string().chars().next().unwrap()
+                    } else if x == self.start.x && y == self.start.y {
+                        assert!(self.grid[y as usize][x as usize] >= 1);
+                        's'
+                    } else if *v == 0 {
+                        '.'
+                    } else {
+                        assert!(*v >= 1);
+                        '#'
+                    }
+                };
+
+                write!(f, "{}", ch)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part1(
+    width: usize,
+    height: usize,
+    start: Position,
+    instructions: impl AsRef<[Instruction]>,
+) -> usize {
+    let mut grid = Grid::new(width, height, start, 2);
+
+    for instruction in instructions.as_ref() {
+        #[cfg(feature = "debugvis")]
+        println!("== {} == ", instruction);
+        grid.update(*instruction);
+    }
+
+    let count = grid.grid.iter().flatten().filter(|&&x| x >= 1).count();
+    println!("Visited at least once count: {}", count);
+
+    count
+}
+
+pub fn part2(
+    width: usize,
+    height: usize,
+    start: Position,
+    instructions: impl AsRef<[Instruction]>,
+) -> usize {
+    let mut grid = Grid::new(width, height, start, 10);
+
+    for instruction in instructions.as_ref() {
+        #[cfg(feature = "debugvis")]
+        println!("== {} == ", instruction);
+        grid.update(*instruction);
+    }
+
+    let count = grid.grid.iter().flatten().filter(|&&x| x >= 1).count();
+    println!("Visited at least once count: {}", count);
+
+    count
+}
+
+pub fn parse(input: &str) -> Result<(usize, usize, Position, Vec<Instruction>), ParseError> {
+    let mut y = 0;
+    let mut max_y = 0;
+    let mut min_y = 0;
+    let mut x = 0;
+    let mut max_x = 0;
+    let mut min_x = 0;
+
+    let values = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (d, a) = line.split_once(' ').ok_or_else(|| {
+                ParseError::new(input, line, "expected a direction and an amount")
+            })?;
+            let i = Instruction::new(parse_at(input, d)?, parse_at(input, a)?);
+
+            match i.direction {
+                Direction::Up => y += i.amount as i32,
+                Direction::Down => y -= i.amount as i32,
+                Direction::Left => x -= i.amount as i32,
+                Direction::Right => x += i.amount as i32,
+            }
+
+            max_y = y.max(max_y);
+            min_y = y.min(min_y);
+            max_x = x.max(max_x);
+            min_x = x.min(min_x);
+
+            Ok(i)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let height = ((max_y - min_y).abs() + 1) as usize;
+    let width = ((max_x - min_x).abs() + 1) as usize;
+    let start = Position::new(min_x.abs(), min_y.abs());
+
+    Ok((width, height, start, values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (width, height, start, values) =
+            parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(width, height, start, &values), 13);
+        assert_eq!(part2(width, height, start, &values), 1);
+    }
+
+    #[test]
+    fn example2() {
+        let (width, height, start, values) =
+            parse(include_str!("../examples/example2.txt")).unwrap();
+
+        assert_eq!(part2(width, height, start, &values), 36);
+    }
+}
@@ -0,0 +1,306 @@
+//! Times parsing, part 1 and part 2 for every day
+//!
+//! `cargo bench -p bench` runs everything, `cargo bench -p bench -- day7/` just the one day.
+//! `-- --save-baseline before` keeps the numbers around and `-- --baseline before` compares against them.
+
+use std::hint::black_box;
+use std::io::Write;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, BatchSize, Bencher, BenchmarkGroup, Criterion};
+use gag::Gag;
+
+// the days print their answers (and sometimes whole grids) as they go,
+// that's just noise in a bench so stdout is gagged while a day is running
+fn quiet<T>(f: impl FnOnce() -> T) -> T {
+    // only one gag can be open at a time, if that fails it's just noisy
+    let gag = Gag::stdout().ok();
+    let v = f();
+    std::io::stdout().flush().unwrap();
+    drop(gag);
+    v
+}
+
+// criterion prints its results after the routine is done, so those still show up
+struct QuietGroup<'a>(BenchmarkGroup<'a, WallTime>);
+
+impl QuietGroup<'_> {
+    fn sample_size(&mut self, n: usize) -> &mut Self {
+        self.0.sample_size(n);
+        self
+    }
+
+    fn bench_function<F: FnMut(&mut Bencher)>(&mut self, id: &str, mut f: F) -> &mut Self {
+        self.0.bench_function(id, |b| quiet(|| f(b)));
+        self
+    }
+
+    fn finish(self) {
+        self.0.finish();
+    }
+}
+
+// every day is a library crate, this brings its parse and parts into a module to bench them
+macro_rules! day {
+    ($day:ident, |$group:ident, $input:ident| $body:block) => {
+        // not every day needs batching
+        #[allow(unused_imports)]
+        mod $day {
+            use super::{black_box, quiet, BatchSize, QuietGroup};
+            use ::$day::*;
+
+            pub fn bench(c: &mut criterion::Criterion) {
+                let $input = include_str!(concat!("../../", stringify!($day), "/input.txt"));
+
+                let mut $group = QuietGroup(c.benchmark_group(stringify!($day)));
+                $group.sample_size(10);
+                $body
+                $group.finish();
+            }
+        }
+    };
+}
+
+day!(day1, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day2, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+});
+
+day!(day3, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grid = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&grid))));
+
+    let (_, symbols) = quiet(|| part1(&grid));
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| symbols.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day4, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let cards = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&cards))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&cards))));
+});
+
+day!(day5, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (seeds, almanac) = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&seeds), &almanac)));
+
+    // part 2 still brute forces every seed and runs out of memory
+});
+
+day!(day6, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let races = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&races))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&races))));
+});
+
+day!(day7, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input), false)));
+
+    // part 2 parses the jokers differently, the hands for each part get set up outside the timing
+    let hands = parse(input, false).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| hands.clone(), part1, BatchSize::SmallInput)
+    });
+
+    let hands = parse(input, true).unwrap();
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| hands.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day8, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (directions, nodes) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(black_box(&directions), &nodes))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| part2(black_box(&directions), &nodes))
+    });
+});
+
+day!(day9, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let sequences = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&sequences))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&sequences))));
+});
+
+day!(day10, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grid = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&grid))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&grid))));
+});
+
+day!(day11, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grid = parse(input);
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| grid.clone(), part1, BatchSize::SmallInput)
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| grid.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day12, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let records = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || records.clone(),
+            |mut records| part1(&mut records),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day13, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grids = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&grids))));
+
+    // the grids can't be cloned so just parse them again for every run
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| parse(input).unwrap(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day14, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (rocks, width, height) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || rocks.clone(),
+            |rocks| part1(rocks, width, height),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || rocks.clone(),
+            |rocks| part2(rocks, width, height),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day15, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let sequence = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&sequence))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&sequence))));
+});
+
+day!(day16, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grid = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&grid))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&grid))));
+});
+
+day!(day17, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grid = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&grid))));
+});
+
+day!(day18, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let plan = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&plan))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&plan))));
+});
+
+day!(day19, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (workflows, parts) = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&workflows), &parts)));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&workflows))));
+});
+
+day!(day20, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let modules = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| modules.clone(), part1, BatchSize::SmallInput)
+    });
+});
+
+day!(day21, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grid = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&grid))));
+});
+
+criterion_group!(
+    benches,
+    day1::bench,
+    day2::bench,
+    day3::bench,
+    day4::bench,
+    day5::bench,
+    day6::bench,
+    day7::bench,
+    day8::bench,
+    day9::bench,
+    day10::bench,
+    day11::bench,
+    day12::bench,
+    day13::bench,
+    day14::bench,
+    day15::bench,
+    day16::bench,
+    day17::bench,
+    day18::bench,
+    day19::bench,
+    day20::bench,
+    day21::bench,
+);
+
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    aoc_bench::print_summary(aoc_bench::criterion_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/.."
+    )));
+}
@@ -0,0 +1,464 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, strum::EnumString)]
+enum PipeType {
+    #[strum(serialize = ".")]
+    Ground,
+
+    #[strum(serialize = "S")]
+    Start,
+
+    #[strum(serialize = "|")]
+    Vertical,
+
+    #[strum(serialize = "-")]
+    Horizontal,
+
+    #[strum(serialize = "L")]
+    NEBend,
+
+    #[strum(serialize = "J")]
+    NWBend,
+
+    #[strum(serialize = "7")]
+    SWBend,
+
+    #[strum(serialize = "F")]
+    SEBend,
+}
+
+impl Direction {
+    fn opposite(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+impl PipeType {
+    fn connects(&self, direction: Direction) -> bool {
+        matches!(
+            (self, direction),
+            (
+                PipeType::Vertical | PipeType::NEBend | PipeType::NWBend,
+                Direction::Up
+            ) | (
+                PipeType::Vertical | PipeType::SEBend | PipeType::SWBend,
+                Direction::Down
+            ) | (
+                PipeType::Horizontal | PipeType::NWBend | PipeType::SWBend,
+                Direction::Left
+            ) | (
+                PipeType::Horizontal | PipeType::NEBend | PipeType::SEBend,
+                Direction::Right
+            )
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Pipe {
+    r#type: PipeType,
+    coords: (usize, usize),
+
+    min_distance: RefCell<Option<usize>>,
+}
+
+impl TryFrom<(usize, usize, char)> for Pipe {
+    type Error = char;
+
+    fn try_from(v: (usize, usize, char)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            r#type: PipeType::from_str(&v.2.to_string()).map_err(|_| v.2)?,
+            coords: (v.0, v.1),
+            min_distance: RefCell::new(None),
+        })
+    }
+}
+
+impl Pipe {
+    fn neighbor<'a>(&self, grid: &'a [Vec<Pipe>], direction: Direction) -> Option<&'a Pipe> {
+        let (x, y) = self.coords;
+        match direction {
+            Direction::Up => grid.get(y.checked_sub(1)?)?.get(x),
+            Direction::Down => grid.get(y + 1)?.get(x),
+            Direction::Left => grid.get(y)?.get(x.checked_sub(1)?),
+            Direction::Right => grid.get(y)?.get(x + 1),
+        }
+    }
+
+    // the start pipe is whatever connects the two neighbors that connect back to it
+    fn resolve_type(&self, grid: &[Vec<Pipe>]) -> PipeType {
+        if self.r#type != PipeType::Start {
+            return self.r#type;
+        }
+
+        let connected = |direction: Direction| {
+            self.neighbor(grid, direction)
+                .map(|pipe| pipe.r#type.connects(direction.opposite()))
+                .unwrap_or_default()
+        };
+
+        match (
+            connected(Direction::Up),
+            connected(Direction::Down),
+            connected(Direction::Left),
+            connected(Direction::Right),
+        ) {
+            (true, true, false, false) => PipeType::Vertical,
+            (false, false, true, true) => PipeType::Horizontal,
+            (true, false, false, true) => PipeType::NEBend,
+            (true, false, true, false) => PipeType::NWBend,
+            (false, true, true, false) => PipeType::SWBend,
+            (false, true, false, true) => PipeType::SEBend,
+            v => unreachable!("ambiguous start pipe: {:?}", v),
+        }
+    }
+
+    fn is_start(&self) -> bool {
+        self.r#type == PipeType::Start
+    }
+
+    fn start_direction(&self, grid: &[Vec<Pipe>]) -> Direction {
+        let r#type = self.resolve_type(grid);
+        match r#type {
+            PipeType::Vertical => Direction::Up,
+            PipeType::Horizontal => Direction::Right,
+            PipeType::NEBend | PipeType::SEBend => Direction::Left,
+            PipeType::NWBend | PipeType::SWBend => Direction::Right,
+            _ => unreachable!("unexpected start pipe: {:?}", r#type),
+        }
+    }
+
+    fn reverse_direction(&self, grid: &[Vec<Pipe>]) -> Direction {
+        let r#type = self.resolve_type(grid);
+        match r#type {
+            PipeType::Vertical => Direction::Down,
+            PipeType::Horizontal => Direction::Left,
+            PipeType::NEBend | PipeType::NWBend => Direction::Down,
+            PipeType::SEBend | PipeType::SWBend => Direction::Up,
+            _ => unreachable!("unexpected reverse pipe: {:?}", r#type),
+        }
+    }
+
+    fn next<'a>(&self, grid: &'a [Vec<Pipe>], direction: Direction) -> (&'a Pipe, Direction) {
+        let r#type = self.resolve_type(grid);
+        match r#type {
+            PipeType::Vertical => match direction {
+                Direction::Up => (
+                    grid.get(self.coords.1 - 1)
+                        .unwrap()
+                        .get(self.coords.0)
+                        .unwrap(),
+                    Direction::Up,
+                ),
+                Direction::Down => (
+                    grid.get(self.coords.1 + 1)
+                        .unwrap()
+                        .get(self.coords.0)
+                        .unwrap(),
+                    Direction::Down,
+                ),
+                _ => unreachable!("invalid vertical direction {:?}", direction),
+            },
+            PipeType::Horizontal => match direction {
+                Direction::Left => (
+                    grid.get(self.coords.1)
+                        .unwrap()
+                        .get(self.coords.0 - 1)
+                        .unwrap(),
+                    Direction::Left,
+                ),
+                Direction::Right => (
+                    grid.get(self.coords.1)
+                        .unwrap()
+                        .get(self.coords.0 + 1)
+                        .unwrap(),
+                    Direction::Right,
+                ),
+                _ => unreachable!("invalid horizontal direction {:?}", direction),
+            },
+            PipeType::NEBend => match direction {
+                Direction::Down => (
+                    grid.get(self.coords.1)
+                        .unwrap()
+                        .get(self.coords.0 + 1)
+                        .unwrap(),
+                    Direction::Right,
+                ),
+                Direction::Left => (
+                    grid.get(self.coords.1 - 1)
+                        .unwrap()
+                        .get(self.coords.0)
+                        .unwrap(),
+                    Direction::Up,
+                ),
+                _ => unreachable!("invalid nebend direction {:?}", direction),
+            },
+            PipeType::SEBend => match direction {
+                Direction::Up => (
+                    grid.get(self.coords.1)
+                        .unwrap()
+                        .get(self.coords.0 + 1)
+                        .unwrap(),
+                    Direction::Right,
+                ),
+                Direction::Left => (
+                    grid.get(self.coords.1 + 1)
+                        .unwrap()
+                        .get(self.coords.0)
+                        .unwrap(),
+                    Direction::Down,
+                ),
+                _ => unreachable!("invalid sebend direction {:?}", direction),
+            },
+            PipeType::NWBend => match direction {
+                Direction::Down => (
+                    grid.get(self.coords.1)
+                        .unwrap()
+                        .get(self.coords.0 - 1)
+                        .unwrap(),
+                    Direction::Left,
+                ),
+                Direction::Right => (
+                    grid.get(self.coords.1 - 1)
+                        .unwrap()
+                        .get(self.coords.0)
+                        .unwrap(),
+                    Direction::Up,
+                ),
+                _ => unreachable!("invalid nwbend direction {:?}", direction),
+            },
+            PipeType::SWBend => match direction {
+                Direction::Up => (
+                    grid.get(self.coords.1)
+                        .unwrap()
+                        .get(self.coords.0 - 1)
+                        .unwrap(),
+                    Direction::Left,
+                ),
+                Direction::Right => (
+                    grid.get(self.coords.1 + 1)
+                        .unwrap()
+                        .get(self.coords.0)
+                        .unwrap(),
+                    Direction::Down,
+                ),
+                _ => unreachable!("invalid swbend direction {:?}", direction),
+            },
+            _ => unreachable!("unexpected pipe: {:?}", r#type),
+        }
+    }
+}
+
+pub fn part1(grid: &[Vec<Pipe>]) -> usize {
+    let start = find_start(grid);
+
+    //println!("forward");
+    let mut node = start;
+    let mut direction = start.start_direction(grid);
+    let mut distance = 0;
+    loop {
+        (node, direction) = node.next(grid, direction);
+        //println!("next node: {:?}", node);
+        if node.is_start() {
+            break;
+        }
+
+        distance += 1;
+
+        let node_distance = (*node.min_distance.borrow()).unwrap_or(usize::MAX);
+        node.min_distance.replace(Some(node_distance.min(distance)));
+    }
+
+    //println!("reverse");
+    let mut node = start;
+    let mut direction = start.reverse_direction(grid);
+    let mut distance = 0;
+    loop {
+        (node, direction) = node.next(grid, direction);
+        //println!("next node: {:?}", node);
+        if node.is_start() {
+            break;
+        }
+
+        distance += 1;
+
+        let node_distance = (*node.min_distance.borrow()).unwrap_or(usize::MAX);
+        node.min_distance.replace(Some(node_distance.min(distance)));
+    }
+
+    let max_distance = grid
+        .iter()
+        .flat_map(|row| row.iter().filter_map(|pipe| *pipe.min_distance.borrow()))
+        .max()
+        .unwrap();
+
+    println!("Max distance: {}", max_distance);
+
+    max_distance
+}
+
+fn find_start(grid: &[Vec<Pipe>]) -> &Pipe {
+    grid.iter()
+        .find_map(|row| row.iter().find(|pipe| pipe.is_start()))
+        .unwrap()
+}
+
+fn trace_loop(grid: &[Vec<Pipe>]) -> HashSet<(usize, usize)> {
+    let start = find_start(grid);
+
+    let mut main_loop = HashSet::from([start.coords]);
+
+    let mut node = start;
+    let mut direction = start.start_direction(grid);
+    loop {
+        (node, direction) = node.next(grid, direction);
+        if node.is_start() {
+            break;
+        }
+
+        main_loop.insert(node.coords);
+    }
+
+    main_loop
+}
+
+// scanning each row left to right, every loop pipe that connects up
+// flips whether we're inside or outside the loop
+// (only counting the up connections means running along a horizontal
+// section only flips if the bends at either end go opposite ways)
+fn find_enclosed(
+    grid: &[Vec<Pipe>],
+    main_loop: &HashSet<(usize, usize)>,
+) -> HashSet<(usize, usize)> {
+    let mut enclosed = HashSet::new();
+
+    for row in grid {
+        let mut inside = false;
+        for pipe in row {
+            if main_loop.contains(&pipe.coords) {
+                if pipe.resolve_type(grid).connects(Direction::Up) {
+                    inside = !inside;
+                }
+            } else if inside {
+                enclosed.insert(pipe.coords);
+            }
+        }
+    }
+
+    enclosed
+}
+
+#[cfg(feature = "debugvis")]
+fn render(
+    grid: &[Vec<Pipe>],
+    main_loop: &HashSet<(usize, usize)>,
+    enclosed: &HashSet<(usize, usize)>,
+) {
+    for row in grid {
+        for pipe in row {
+            let ch = if main_loop.contains(&pipe.coords) {
+                match pipe.resolve_type(grid) {
+                    PipeType::Vertical => '│',
+                    PipeType::Horizontal => '─',
+                    PipeType::NEBend => '└',
+                    PipeType::NWBend => '┘',
+                    PipeType::SWBend => '┐',
+                    PipeType::SEBend => '┌',
+                    _ => unreachable!(),
+                }
+            } else if enclosed.contains(&pipe.coords) {
+                'I'
+            } else {
+                'O'
+            };
+            print!("{}", ch);
+        }
+        println!();
+    }
+}
+
+pub fn part2(grid: &[Vec<Pipe>]) -> usize {
+    let main_loop = trace_loop(grid);
+    let enclosed = find_enclosed(grid, &main_loop);
+
+    #[cfg(feature = "debugvis")]
+    render(grid, &main_loop, &enclosed);
+
+    let total = enclosed.len();
+
+    println!("Enclosed: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Pipe>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.char_indices()
+                .enumerate()
+                .map(|(x, (idx, c))| {
+                    Pipe::try_from((x, y, c)).map_err(|_| {
+                        ParseError::new(
+                            input,
+                            &line[idx..idx + c.len_utf8()],
+                            "expected ., S, |, -, L, J, 7 or F",
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let grid = parse(include_str!("../examples/example.txt")).unwrap();
+        assert_eq!(part1(&grid), 4);
+
+        let grid = parse(include_str!("../examples/example2.txt")).unwrap();
+        assert_eq!(part1(&grid), 8);
+    }
+
+    // part 2 has its own set of examples
+    #[test]
+    fn enclosed() {
+        let grid = parse(include_str!("../examples/example3.txt")).unwrap();
+        assert_eq!(part2(&grid), 4);
+
+        let grid = parse(include_str!("../examples/example4.txt")).unwrap();
+        assert_eq!(part2(&grid), 8);
+
+        let grid = parse(include_str!("../examples/example5.txt")).unwrap();
+        assert_eq!(part2(&grid), 10);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse(".....\n.S-7.\n.|.|.\n.L-X.\n.....\n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 4));
+        assert_eq!(err.text, "X");
+    }
+}
@@ -0,0 +1,158 @@
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, strum::EnumString)]
+enum Condition {
+    #[strum(serialize = ".")]
+    Operational,
+
+    #[strum(serialize = "#")]
+    Damaged,
+
+    #[strum(serialize = "?")]
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    conditions: Vec<Condition>,
+    groups: Vec<usize>,
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"(?<conditions>(\?|\.|#)+) (?<groups>.+)").unwrap();
+        let caps = re.captures(v).ok_or_else(|| {
+            ParseError::new(
+                v,
+                v,
+                "expected springs (., # or ?) and then the group sizes",
+            )
+        })?;
+
+        // the regex already made sure these are all valid
+        let conditions = caps["conditions"]
+            .chars()
+            .map(|ch| Condition::from_str(ch.to_string().as_str()).unwrap())
+            .collect::<Vec<_>>();
+        let groups = caps["groups"]
+            .split(',')
+            .map(|g| parse_at(v, g))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { conditions, groups })
+    }
+}
+
+impl Record {
+    fn is_valid(&self) -> bool {
+        let mut conditions_idx = 0;
+        let mut group_idx = 0;
+        loop {
+            if conditions_idx >= self.conditions.len() {
+                break;
+            }
+
+            if group_idx >= self.groups.len() {
+                //println!("overran groups");
+                return false;
+            }
+
+            /*println!(
+                "checking condition {}: {:?}",
+                conditions_idx, self.conditions[conditions_idx]
+            );*/
+            match self.conditions[conditions_idx] {
+                Condition::Operational => {
+                    conditions_idx += 1;
+                    continue;
+                }
+                Condition::Unknown => return false,
+                Condition::Damaged => {
+                    //println!("checking group {} ({})", group_idx, self.groups[group_idx]);
+                    for _ in 1..self.groups[group_idx] {
+                        conditions_idx += 1;
+                        if conditions_idx >= self.conditions.len() {
+                            //println!("overran conditions");
+                            return false;
+                        }
+
+                        /*println!(
+                            "checking group {} condition {}: {:?}",
+                            group_idx, conditions_idx, self.conditions[conditions_idx]
+                        );*/
+                        if self.conditions[conditions_idx] != Condition::Damaged {
+                            return false;
+                        }
+                    }
+                    conditions_idx += 1;
+                    group_idx += 1;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+fn check_arrangements(record: &mut Record) -> usize {
+    if let Some(pos) = record
+        .conditions
+        .iter()
+        .position(|x| *x == Condition::Unknown)
+    {
+        //println!("twiddle unknown at {}", pos);
+
+        record.conditions[pos] = Condition::Operational;
+        let mut arrangements = check_arrangements(record);
+
+        record.conditions[pos] = Condition::Damaged;
+        arrangements += check_arrangements(record);
+
+        //println!("got {} arrangements after twiddle", arrangements);
+
+        record.conditions[pos] = Condition::Unknown;
+        arrangements
+    } else if record.is_valid() {
+        1
+    } else {
+        0
+    }
+}
+
+pub fn part1(records: &mut [Record]) -> usize {
+    let mut total = 0;
+
+    for record in records {
+        let arrangements = check_arrangements(record);
+        println!("got {} arrangements of {:?}", arrangements, record);
+        total += arrangements;
+    }
+
+    // TODO: getting 32433 which is too high
+    // even though the example comes out right
+    // (so probably the validity check is still missing a case)
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    aoc_parse::lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut records = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&mut records), 21);
+    }
+}
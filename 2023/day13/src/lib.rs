@@ -0,0 +1,203 @@
+use std::cell::RefCell;
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+
+#[derive(Debug)]
+pub struct Grid {
+    grid: Vec<Vec<char>>,
+
+    reflection_row: RefCell<Option<usize>>,
+    reflection_col: RefCell<Option<usize>>,
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let mut grid: Vec<Vec<char>> = vec![];
+        for line in v.lines() {
+            if let Some((idx, ch)) = line.char_indices().find(|(_, ch)| !matches!(ch, '.' | '#')) {
+                return Err(ParseError::new(
+                    v,
+                    &line[idx..idx + ch.len_utf8()],
+                    "expected . or #",
+                ));
+            }
+
+            let row = line.chars().collect::<Vec<_>>();
+            if grid.first().is_some_and(|first| first.len() != row.len()) {
+                return Err(ParseError::new(
+                    v,
+                    line,
+                    "rows have to all be the same width",
+                ));
+            }
+            grid.push(row);
+        }
+
+        Ok(Self {
+            grid,
+            reflection_row: RefCell::new(None),
+            reflection_col: RefCell::new(None),
+        })
+    }
+}
+
+fn is_horizontal_reflection(grid: &[Vec<char>], row: usize) -> bool {
+    for v in 0..=row {
+        let a = row - v;
+        let b = row + v + 1;
+        if b >= grid.len() {
+            break;
+        }
+        //println!("checking row {} against row {}", a, b);
+
+        if !grid[a].iter().zip(grid[b].iter()).all(|(a, b)| a == b) {
+            return false;
+        }
+    }
+    true
+}
+
+fn is_vertical_reflection(grid: &[Vec<char>], col: usize) -> bool {
+    for v in 0..=col {
+        let a = col - v;
+        let b = col + v + 1;
+        if b >= grid[0].len() {
+            break;
+        }
+        //println!("checking col {} against col {}", a, b);
+
+        let mut reflected = true;
+        for row in grid {
+            if row[a] != row[b] {
+                reflected = false;
+                break;
+            }
+        }
+
+        if !reflected {
+            return false;
+        }
+    }
+    true
+}
+
+fn score_grid(grid: &Grid) -> usize {
+    let mut score = 0;
+
+    for y in 0..(grid.grid.len() - 1) {
+        if *grid.reflection_row.borrow() == Some(y) {
+            continue;
+        }
+
+        //println!("checking row {}", y);
+        if is_horizontal_reflection(&grid.grid, y) {
+            let value = 100 * (y + 1);
+            /*println!(
+                "found horizontal reflection at row {}, value += {}",
+                y, value
+            );*/
+
+            grid.reflection_row.replace(Some(y));
+            score += value;
+            break;
+        }
+    }
+
+    for x in 0..(grid.grid[0].len() - 1) {
+        if *grid.reflection_col.borrow() == Some(x) {
+            continue;
+        }
+
+        //println!("checking col {}", x);
+        if is_vertical_reflection(&grid.grid, x) {
+            let value = x + 1;
+            //println!("found vertical reflection at col {}, value += {}", x, value);
+
+            grid.reflection_col.replace(Some(x));
+            score += value;
+            break;
+        }
+    }
+
+    score
+}
+
+pub fn part1(grids: &[Grid]) -> usize {
+    let mut total = 0;
+
+    for grid in grids {
+        let score = score_grid(grid);
+        //println!("scored: {}", score);
+        total += score;
+        //println!("");
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(mut grids: Vec<Grid>) -> usize {
+    let mut total = 0;
+
+    for grid in &mut grids {
+        'outer: for y in 0..grid.grid.len() {
+            if *grid.reflection_row.borrow() == Some(y) {
+                continue;
+            }
+
+            for x in 0..grid.grid[0].len() {
+                if *grid.reflection_col.borrow() == Some(x) {
+                    continue;
+                }
+
+                let prev = grid.grid[y][x];
+                match prev {
+                    '#' => grid.grid[y][x] = '.',
+                    '.' => grid.grid[y][x] = '#',
+                    _ => unreachable!(),
+                }
+
+                let score = score_grid(grid);
+                grid.grid[y][x] = prev;
+
+                if score != 0 {
+                    //println!("scored grid after changing ({}, {}): {}", x, y, score);
+                    total += score;
+                    break 'outer;
+                }
+            }
+        }
+        //println!("");
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Grid>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|grid| {
+            grid.parse()
+                .map_err(|err: ParseError| err.within(input, grid))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let grids = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&grids), 405);
+        assert_eq!(part2(grids), 400);
+    }
+}
@@ -0,0 +1,252 @@
+use aoc_cycle::find_cycle;
+use aoc_parse::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Rock {
+    Round(usize, usize),
+    Solid(usize, usize),
+}
+
+// the error is the character that isn't a rock
+impl TryFrom<(char, usize, usize)> for Rock {
+    type Error = char;
+
+    fn try_from(v: (char, usize, usize)) -> Result<Self, Self::Error> {
+        match v.0 {
+            'O' => Ok(Self::Round(v.1, v.2)),
+            '#' => Ok(Self::Solid(v.1, v.2)),
+            _ => Err(v.0),
+        }
+    }
+}
+
+impl Rock {
+    fn get_x(&self) -> usize {
+        match self {
+            Self::Round(x, _) => *x,
+            Self::Solid(x, _) => *x,
+        }
+    }
+
+    fn set_x(&mut self, new_x: usize) {
+        match self {
+            Self::Round(x, _) => *x = new_x,
+            Self::Solid(x, _) => *x = new_x,
+        }
+    }
+
+    fn get_y(&self) -> usize {
+        match self {
+            Self::Round(_, y) => *y,
+            Self::Solid(_, y) => *y,
+        }
+    }
+
+    fn set_y(&mut self, new_y: usize) {
+        match self {
+            Self::Round(_, y) => *y = new_y,
+            Self::Solid(_, y) => *y = new_y,
+        }
+    }
+}
+
+fn init_cols(rocks: &mut [Rock], width: usize) -> Vec<Vec<&mut Rock>> {
+    let mut cols = Vec::with_capacity(width);
+    for _ in 0..width {
+        cols.push(vec![]);
+    }
+
+    for rock in rocks {
+        cols[rock.get_x()].push(rock);
+    }
+
+    cols
+}
+
+#[allow(dead_code)]
+fn init_rows(rocks: &mut [Rock], height: usize) -> Vec<Vec<&mut Rock>> {
+    let mut rows = Vec::with_capacity(height);
+    for _ in 0..height {
+        rows.push(vec![]);
+    }
+
+    for rock in rocks {
+        rows[rock.get_y()].push(rock);
+    }
+
+    rows
+}
+
+// tilting only ever happens north, the other directions
+// are handled by rotating the rocks so that side is north
+fn tilt_north(rocks: &mut [Rock], width: usize) {
+    // columns need to be in y order for the tilt to stack correctly
+    rocks.sort_unstable_by_key(|rock| (rock.get_y(), rock.get_x()));
+
+    let mut cols = init_cols(rocks, width);
+
+    //println!("rock cols before: {:?}", rock_cols);
+
+    for col in &mut cols {
+        let mut prev_y = 0;
+        for (idx, rock) in col.iter_mut().enumerate() {
+            if !matches!(rock, Rock::Round(_, _)) {
+                prev_y = rock.get_y();
+                continue;
+            }
+
+            if idx == 0 {
+                rock.set_y(0);
+                prev_y = 0;
+                continue;
+            }
+
+            rock.set_y(prev_y + 1);
+            prev_y += 1;
+        }
+    }
+
+    //println!("rock cols after: {:?}", rock_cols);
+}
+
+// rotates 90 degrees clockwise, so west becomes north
+// the width and height swap after this
+fn rotate_rocks(rocks: &mut [Rock], height: usize) {
+    for rock in rocks {
+        let (x, y) = (rock.get_x(), rock.get_y());
+        rock.set_x(height - 1 - y);
+        rock.set_y(x);
+    }
+}
+
+// north, west, south, east
+fn spin_cycle(rocks: &mut [Rock], width: usize, height: usize) {
+    let (mut width, mut height) = (width, height);
+    for _ in 0..4 {
+        tilt_north(rocks, width);
+        rotate_rocks(rocks, height);
+        std::mem::swap(&mut width, &mut height);
+    }
+
+    // keep the layout in a consistent order so it can be compared
+    rocks.sort_unstable_by_key(|rock| (rock.get_y(), rock.get_x()));
+}
+
+fn get_load(rocks: &[Rock], height: usize) -> usize {
+    let mut load = 0;
+
+    for rock in rocks {
+        if !matches!(rock, Rock::Round(_, _)) {
+            continue;
+        }
+
+        //println!("consider {} vs {:?}", height, rock);
+
+        let value = height - rock.get_y();
+        //println!("value: {}", value);
+        load += value;
+    }
+
+    load
+}
+
+pub fn part1(mut rocks: Vec<Rock>, width: usize, height: usize) -> usize {
+    tilt_north(&mut rocks, width);
+
+    let load = get_load(&rocks, height);
+
+    println!("Load: {}", load);
+
+    load
+}
+
+pub fn part2(mut rocks: Vec<Rock>, width: usize, height: usize) -> usize {
+    const CYCLES: usize = 1000000000;
+
+    // too many cycles to brute force, but the layout eventually repeats
+    // so once it does we can skip ahead to where the last cycle lands
+    rocks.sort_unstable_by_key(|rock| (rock.get_y(), rock.get_x()));
+
+    let cycle = find_cycle(
+        rocks,
+        |rocks| {
+            let mut rocks = rocks.clone();
+            spin_cycle(&mut rocks, width, height);
+            rocks
+        },
+        |rocks| rocks.clone(),
+    );
+    //println!("cycle starts at {} (period {})", cycle.start, cycle.period);
+
+    let load = cycle.extrapolate(CYCLES, |rocks| get_load(rocks, height));
+
+    println!("Load: {}", load);
+
+    load
+}
+
+// returns the rocks and the size of the platform
+// (the edge rows / columns can be empty, so the size comes from the lines not the rocks)
+pub fn parse(input: &str) -> Result<(Vec<Rock>, usize, usize), ParseError> {
+    let width = input
+        .lines()
+        .next()
+        .map(|line| line.chars().count())
+        .unwrap_or(0);
+    let height = input.lines().count();
+
+    let mut rocks = vec![];
+    for (y, line) in input.lines().enumerate() {
+        if line.chars().count() != width {
+            return Err(ParseError::new(
+                input,
+                line,
+                format!("expected every row to be {} wide", width),
+            ));
+        }
+
+        for (x, (idx, ch)) in line.char_indices().enumerate() {
+            if ch == '.' {
+                continue;
+            }
+
+            let rock = Rock::try_from((ch, x, y)).map_err(|_| {
+                ParseError::new(input, &line[idx..idx + ch.len_utf8()], "expected ., O or #")
+            })?;
+            rocks.push(rock);
+        }
+    }
+
+    Ok((rocks, width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (rocks, width, height) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(rocks.clone(), width, height), 136);
+        assert_eq!(part2(rocks, width, height), 64);
+    }
+
+    #[test]
+    fn empty_edges() {
+        // nothing in the last row or column, but they still count
+        let (rocks, width, height) = parse("O...\n.#..\n....\n").unwrap();
+        assert_eq!((width, height), (4, 3));
+
+        assert_eq!(part1(rocks.clone(), width, height), 3);
+        // ends up against the south east corner after every cycle
+        assert_eq!(part2(rocks, width, height), 1);
+    }
+
+    #[test]
+    fn ragged() {
+        let err = parse("O...\n.#.\n....\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected every row to be 4 wide");
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Operation {
+    Remove,
+    Insert(usize),
+}
+
+#[derive(Debug)]
+pub struct Step<'a> {
+    text: &'a str,
+    label: &'a str,
+    operation: Operation,
+}
+
+impl<'a> TryFrom<&'a str> for Step<'a> {
+    type Error = ParseError;
+
+    fn try_from(text: &'a str) -> Result<Self, Self::Error> {
+        if let Some(label) = text.strip_suffix('-') {
+            return Ok(Self {
+                text,
+                label,
+                operation: Operation::Remove,
+            });
+        }
+
+        let (label, focal_len) = text
+            .split_once('=')
+            .ok_or_else(|| ParseError::new(text, text, "expected label- or label=N"))?;
+
+        Ok(Self {
+            text,
+            label,
+            operation: Operation::Insert(parse_at(text, focal_len)?),
+        })
+    }
+}
+
+fn hash(v: &str) -> usize {
+    let mut h = 0;
+    for ch in v.chars() {
+        h += ch as usize;
+        h *= 17;
+        h %= 256;
+    }
+    h
+}
+
+pub fn part1(sequence: &[Step]) -> usize {
+    let mut total = 0;
+
+    for step in sequence {
+        let h = hash(step.text);
+        //println!("{}", h);
+        total += h;
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(sequence: &[Step]) -> usize {
+    let mut boxes = Vec::with_capacity(256);
+    for _ in 0..boxes.capacity() {
+        boxes.push(vec![]);
+    }
+
+    for step in sequence {
+        let label = step.label;
+        match step.operation {
+            Operation::Remove => {
+                let r#box = hash(label);
+
+                //println!("{} is in box {}", label, r#box);
+
+                let r#box = &mut boxes[r#box];
+                if let Some(idx) = r#box.iter().position(|(x, _)| *x == label) {
+                    // can't remove_swap since we need to maintain ordering
+                    r#box.remove(idx);
+                }
+            }
+            Operation::Insert(focal_len) => {
+                let r#box = hash(label);
+
+                //println!("{} ({}) is in box {}", label, focal_len, r#box);
+
+                let r#box = &mut boxes[r#box];
+                if let Some(idx) = r#box.iter().position(|(x, _)| *x == label) {
+                    r#box.get_mut(idx).unwrap().1 = focal_len;
+                } else {
+                    r#box.push((label, focal_len));
+                }
+            }
+        }
+    }
+
+    let mut total = 0;
+
+    for (r#box, lenses) in boxes.iter().enumerate() {
+        //println!("{} = {:?}", r#box, lenses);
+
+        for (idx, (_label, focal_len)) in lenses.iter().enumerate() {
+            let power = (1 + r#box) * (idx + 1) * focal_len;
+            /*println!(
+                "{} ({}) is in box {} with power {}",
+                _label, focal_len, r#box, power
+            );*/
+            total += power;
+        }
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|x| Step::try_from(x).map_err(|err| err.within(input, x)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let sequence = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&sequence), 1320);
+        assert_eq!(part2(&sequence), 145);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("rn=1,cm-,qp=x").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 13));
+        assert_eq!(err.text, "x");
+
+        let err = parse("rn=1,cm,qp=3").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.message, "expected label- or label=N");
+    }
+}
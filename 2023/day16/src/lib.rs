@@ -0,0 +1,235 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+
+    #[default]
+    Right,
+}
+
+#[derive(Debug, Clone, strum::EnumString, strum::Display)]
+pub enum Cell {
+    #[strum(serialize = ".")]
+    Empty,
+
+    #[strum(serialize = "/")]
+    RightMirror,
+
+    #[strum(serialize = "\\")]
+    LeftMirror,
+
+    #[strum(serialize = "|")]
+    VertSplitter,
+
+    #[strum(serialize = "-")]
+    HorizSplitter,
+}
+
+impl Cell {
+    fn encounter(&self, mut beam: Beam) -> Vec<Beam> {
+        //println!("{:?} encountering {:?}", self, beam);
+
+        match self {
+            Cell::Empty => vec![beam],
+            Cell::RightMirror => {
+                match beam.get_direction() {
+                    Direction::Up => beam.set_direction(Direction::Right),
+                    Direction::Down => beam.set_direction(Direction::Left),
+                    Direction::Left => beam.set_direction(Direction::Down),
+                    Direction::Right => beam.set_direction(Direction::Up),
+                }
+
+                vec![beam]
+            }
+            Cell::LeftMirror => {
+                match beam.get_direction() {
+                    Direction::Up => beam.set_direction(Direction::Left),
+                    Direction::Down => beam.set_direction(Direction::Right),
+                    Direction::Left => beam.set_direction(Direction::Up),
+                    Direction::Right => beam.set_direction(Direction::Down),
+                }
+
+                vec![beam]
+            }
+            Cell::VertSplitter => match beam.get_direction() {
+                Direction::Left | Direction::Right => {
+                    vec![
+                        Beam {
+                            x: beam.x,
+                            y: beam.y,
+                            direction: Direction::Up,
+                        },
+                        Beam {
+                            x: beam.x,
+                            y: beam.y,
+                            direction: Direction::Down,
+                        },
+                    ]
+                }
+                Direction::Up | Direction::Down => vec![beam],
+            },
+            Cell::HorizSplitter => match beam.get_direction() {
+                Direction::Up | Direction::Down => {
+                    vec![
+                        Beam {
+                            x: beam.x,
+                            y: beam.y,
+                            direction: Direction::Left,
+                        },
+                        Beam {
+                            x: beam.x,
+                            y: beam.y,
+                            direction: Direction::Right,
+                        },
+                    ]
+                }
+                Direction::Left | Direction::Right => vec![beam],
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Beam {
+    x: usize,
+    y: usize,
+    direction: Direction,
+}
+
+impl Beam {
+    fn get_direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+
+    fn r#move(&mut self, width: i64, height: i64) -> bool {
+        let cur_x = self.x as i64;
+        let cur_y = self.y as i64;
+
+        let (next_x, next_y) = match self.direction {
+            Direction::Up => (cur_x, cur_y - 1),
+            Direction::Down => (cur_x, cur_y + 1),
+            Direction::Left => (cur_x - 1, cur_y),
+            Direction::Right => (cur_x + 1, cur_y),
+        };
+
+        /*println!(
+            "move from ({},{}) to ({},{}) ({}, {})",
+            cur_x, cur_y, next_x, next_y, width, height
+        );*/
+
+        if next_x < 0 || next_y < 0 || next_x >= width || next_y >= height {
+            //println!("fail at {}, {}", next_x, next_y);
+            return false;
+        }
+
+        self.x = next_x as usize;
+        self.y = next_y as usize;
+
+        true
+    }
+}
+
+// the grid never changes, each run tracks its own beam state
+// a beam that's already passed through a tile going the same way will just repeat itself
+fn run(grid: &[Vec<Cell>], start_x: usize, start_y: usize, start_direction: Direction) -> usize {
+    let height = grid.len() as i64;
+    let width = grid[0].len() as i64;
+
+    let mut visited = HashSet::new();
+
+    let mut beams = VecDeque::new();
+    beams.push_back(Beam {
+        x: start_x,
+        y: start_y,
+        direction: start_direction,
+    });
+
+    while let Some(beam) = beams.pop_front() {
+        if !visited.insert((beam.x, beam.y, beam.get_direction())) {
+            continue;
+        }
+
+        let cell = &grid[beam.y][beam.x];
+        let result = cell.encounter(beam);
+
+        for mut beam in result {
+            if beam.r#move(width, height) {
+                beams.push_back(beam);
+            }
+        }
+    }
+
+    // energized tiles are any that had a beam pass through in any direction
+    visited
+        .iter()
+        .map(|(x, y, _)| (x, y))
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub fn part1(grid: &[Vec<Cell>]) -> usize {
+    let total = run(grid, 0, 0, Direction::Right);
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(grid: &[Vec<Cell>]) -> usize {
+    let height = grid.len();
+    let width = grid[0].len();
+
+    // every edge tile pointing into the grid
+    let starts = (0..height)
+        .flat_map(|y| [(0, y, Direction::Right), (width - 1, y, Direction::Left)])
+        .chain((0..width).flat_map(|x| [(x, 0, Direction::Down), (x, height - 1, Direction::Up)]));
+
+    let max = starts
+        .map(|(x, y, direction)| run(grid, x, y, direction))
+        .max()
+        .unwrap();
+
+    println!("Max: {}", max);
+
+    max
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Cell>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(idx, c)| parse_at(input, &line[idx..idx + c.len_utf8()]))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let grid = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&grid), 46);
+        assert_eq!(part2(&grid), 51);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse(".|...\\....\n|.-.\\.....\n.....|-+..\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 8));
+        assert_eq!(err.text, "+");
+    }
+}
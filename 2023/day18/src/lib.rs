@@ -0,0 +1,279 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Copy, Clone, strum::EnumString)]
+enum Direction {
+    #[strum(serialize = "U")]
+    Up,
+
+    #[strum(serialize = "D")]
+    Down,
+
+    #[strum(serialize = "L")]
+    Left,
+
+    #[strum(serialize = "R")]
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    direction: Direction,
+    meters: usize,
+
+    #[allow(dead_code)]
+    color: String,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"(?<direction>.+) (?<meters>\d+) \((?<color>#.*)\)").unwrap();
+        let caps = re
+            .captures(v)
+            .ok_or_else(|| ParseError::new(v, v, "expected D N (#rrggbb)"))?;
+
+        let direction = Direction::from_str(&caps["direction"])
+            .map_err(|_| ParseError::new(v, &caps["direction"], "expected U, D, L or R"))?;
+        let meters = parse_at::<usize>(v, &caps["meters"])?;
+
+        // part 2 decodes the color into 5 hex digits of meters and a direction from 0 to 3
+        let color = &caps["color"];
+        let valid = color.len() == 7
+            && color[1..].chars().all(|ch| ch.is_ascii_hexdigit())
+            && matches!(color.chars().last(), Some('0'..='3'));
+        if !valid {
+            return Err(ParseError::new(v, color, "expected a color like #70c710"));
+        }
+
+        Ok(Self {
+            direction,
+            meters,
+            color: color.to_owned(),
+        })
+    }
+}
+
+impl Instruction {
+    fn decode(&self) -> (Direction, usize) {
+        let meters = usize::from_str_radix(&self.color[1..=5], 16).unwrap();
+        let direction = match self.color.chars().last().unwrap() {
+            '0' => Direction::Right,
+            '1' => Direction::Down,
+            '2' => Direction::Left,
+            '3' => Direction::Up,
+            _ => unreachable!(),
+        };
+
+        (direction, meters)
+    }
+}
+
+// shoelace formula gives the area of the polygon traced through the trench centers
+// pick's theorem (A = i + b/2 - 1) then gives the interior points from that
+// and the lagoon is the interior plus the trench itself (i + b)
+fn lagoon_volume(steps: impl IntoIterator<Item = (Direction, usize)>) -> usize {
+    let mut x = 0_i64;
+    let mut y = 0_i64;
+    let mut area2 = 0;
+    let mut boundary = 0;
+    for (direction, meters) in steps {
+        let meters = meters as i64;
+        let (next_x, next_y) = match direction {
+            Direction::Up => (x, y - meters),
+            Direction::Down => (x, y + meters),
+            Direction::Left => (x - meters, y),
+            Direction::Right => (x + meters, y),
+        };
+
+        area2 += x * next_y - next_x * y;
+        boundary += meters;
+
+        x = next_x;
+        y = next_y;
+    }
+
+    let area2 = area2.abs();
+    let interior = (area2 - boundary) / 2 + 1;
+
+    (interior + boundary) as usize
+}
+
+fn edges_to_grid(edges: Vec<(i32, i32)>) -> Vec<Vec<char>> {
+    let mut min_x = i32::MAX;
+    let mut max_x = i32::MIN;
+    let mut min_y = i32::MAX;
+    let mut max_y = i32::MIN;
+
+    for edge in &edges {
+        if edge.0 < min_x {
+            min_x = edge.0;
+        } else if edge.0 > max_x {
+            max_x = edge.0;
+        }
+
+        if edge.1 < min_y {
+            min_y = edge.1;
+        } else if edge.1 > max_y {
+            max_y = edge.1;
+        }
+    }
+
+    //println!("{} {} {} {}", min_x, max_x, min_y, max_y);
+
+    // add an outer border to make flood fill easier to implement
+    min_y -= 1;
+    max_y += 1;
+    min_x -= 1;
+    max_x += 1;
+
+    let mut grid = vec![vec!['.'; (max_x - min_x + 1) as usize]; (max_y - min_y + 1) as usize];
+    for edge in edges {
+        grid[(edge.1 - min_y) as usize][(edge.0 - min_x) as usize] = '#';
+    }
+
+    grid
+}
+
+fn find_start(grid: &[Vec<char>]) -> (usize, usize) {
+    for (y, row) in grid.iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            if *cell == '.' {
+                return (x, y);
+            }
+        }
+    }
+
+    unreachable!();
+}
+
+fn fill(grid: &mut [Vec<char>], v: char) {
+    let height = grid.len();
+    let width = grid[0].len();
+
+    let mut queue = VecDeque::new();
+    queue.push_back(find_start(grid));
+
+    while let Some(pos) = queue.pop_front() {
+        if grid[pos.1][pos.0] != '.' {
+            continue;
+        }
+
+        grid[pos.1][pos.0] = v;
+
+        if pos.1 > 0 {
+            // N
+            queue.push_back((pos.0, pos.1 - 1));
+
+            // NW
+            if pos.0 > 0 {
+                queue.push_back((pos.0 - 1, pos.1 - 1));
+            }
+
+            // NE
+            if pos.0 < width - 1 {
+                queue.push_back((pos.0 + 1, pos.1 - 1));
+            }
+        }
+
+        if pos.1 < height - 1 {
+            // S
+            queue.push_back((pos.0, pos.1 + 1));
+
+            // SW
+            if pos.0 > 0 {
+                queue.push_back((pos.0 - 1, pos.1 + 1));
+            }
+
+            // SE
+            if pos.0 < width - 1 {
+                queue.push_back((pos.0 + 1, pos.1 + 1));
+            }
+        }
+
+        if pos.0 > 0 {
+            // E
+            queue.push_back((pos.0 - 1, pos.1));
+        }
+
+        if pos.0 < width - 1 {
+            // W
+            queue.push_back((pos.0 + 1, pos.1));
+        }
+    }
+}
+
+pub fn part1(plan: &[Instruction]) -> usize {
+    let mut x = 0;
+    let mut y = 0;
+    let mut edges = vec![(x, y)];
+    for instruction in plan {
+        for _ in 0..instruction.meters {
+            match instruction.direction {
+                Direction::Up => y -= 1,
+                Direction::Down => y += 1,
+                Direction::Left => x -= 1,
+                Direction::Right => x += 1,
+            }
+            edges.push((x, y));
+        }
+    }
+    edges.truncate(edges.len() - 1);
+
+    let mut grid = edges_to_grid(edges);
+
+    // as described, there should be an outer area and a singular inner area
+    fill(&mut grid, 'o');
+    fill(&mut grid, '#');
+
+    let total = grid.iter().flatten().filter(|c| **c == '#').count();
+
+    /*for row in &grid {
+        for cell in row {
+            print!("{}", cell);
+        }
+        println!();
+    }*/
+
+    // make sure the flood fill and the polygon math agree
+    let volume = lagoon_volume(
+        plan.iter()
+            .map(|instruction| (instruction.direction, instruction.meters)),
+    );
+    assert!(volume == total);
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(plan: &[Instruction]) -> usize {
+    // the decoded trench is way too big to flood fill,
+    // so this only works from the polygon vertices
+    let total = lagoon_volume(plan.iter().map(Instruction::decode));
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    aoc_parse::lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let plan = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&plan), 62);
+        assert_eq!(part2(&plan), 952408144115);
+    }
+}
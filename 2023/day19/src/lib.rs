@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Clone, strum::EnumString)]
+enum Destination {
+    #[strum(serialize = "A")]
+    Accept,
+
+    #[strum(serialize = "R")]
+    Reject,
+
+    #[strum(default)]
+    Workflow(String),
+}
+
+#[derive(Debug, strum::EnumString)]
+enum Operand {
+    #[strum(serialize = "<")]
+    LessThan,
+
+    #[strum(serialize = ">")]
+    GreaterThan,
+}
+
+#[derive(Debug)]
+struct Condition {
+    rating: char,
+    operand: Operand,
+    value: usize,
+}
+
+impl FromStr for Condition {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^(?<rating>x|m|a|s)(?<operand><|>)(?<value>\d+)$").unwrap();
+        let caps = re
+            .captures(v)
+            .ok_or_else(|| ParseError::new(v, v, "expected a condition like a<2006"))?;
+
+        let rating = caps["rating"].chars().next().unwrap();
+        let operand = Operand::from_str(&caps["operand"]).unwrap();
+        let value = parse_at::<usize>(v, &caps["value"])?;
+
+        Ok(Self {
+            rating,
+            operand,
+            value,
+        })
+    }
+}
+
+impl Condition {
+    fn process_part(&self, part: &Part) -> bool {
+        let v = match self.rating {
+            'x' => part.x,
+            'm' => part.m,
+            'a' => part.a,
+            's' => part.s,
+            _ => unreachable!(),
+        };
+
+        match self.operand {
+            Operand::LessThan => v < self.value,
+            Operand::GreaterThan => v > self.value,
+        }
+    }
+
+    // splits the range into the (passing, failing) parts
+    fn split_range(&self, range: &PartRange) -> (Option<PartRange>, Option<PartRange>) {
+        let r = range.get_rating(self.rating);
+
+        // nothing is < 0 (or > usize::MAX) so there's no passing part at all then
+        let (pass, fail) = match self.operand {
+            Operand::LessThan => (
+                self.value
+                    .checked_sub(1)
+                    .map(|max| *r.start()..=(*r.end()).min(max)),
+                (*r.start()).max(self.value)..=*r.end(),
+            ),
+            Operand::GreaterThan => (
+                self.value
+                    .checked_add(1)
+                    .map(|min| (*r.start()).max(min)..=*r.end()),
+                *r.start()..=(*r.end()).min(self.value),
+            ),
+        };
+
+        (
+            pass.and_then(|pass| range.with_rating(self.rating, pass)),
+            range.with_rating(self.rating, fail),
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    condition: Option<Condition>,
+    destination: Destination,
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"((?<condition>.*):)?(?<destination>.+)").unwrap();
+        let caps = re
+            .captures(v)
+            .ok_or_else(|| ParseError::truncated(v, "expected a rule"))?;
+
+        let condition = caps
+            .name("condition")
+            .map(|condition| {
+                let condition = condition.as_str();
+                condition
+                    .parse::<Condition>()
+                    .map_err(|err| err.within(v, condition))
+            })
+            .transpose()?;
+        // anything that isn't A or R is a workflow
+        let destination = Destination::from_str(&caps["destination"]).unwrap();
+
+        Ok(Self {
+            condition,
+            destination,
+        })
+    }
+}
+
+impl Rule {
+    fn process_part(&self, part: &Part) -> Option<Destination> {
+        if let Some(condition) = &self.condition {
+            if condition.process_part(part) {
+                Some(self.destination.clone())
+            } else {
+                None
+            }
+        } else {
+            Some(self.destination.clone())
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"(?<name>.+)\{(?<rules>.+)\}").unwrap();
+        let caps = re
+            .captures(v)
+            .ok_or_else(|| ParseError::new(v, v, "expected a workflow like px{a<2006:qkq,rfg}"))?;
+
+        let name = caps["name"].to_owned();
+        let rules = caps["rules"]
+            .split(',')
+            .map(|rule| rule.parse().map_err(|err: ParseError| err.within(v, rule)))
+            .collect::<Result<Vec<Rule>, _>>()?;
+
+        // every part has to end up somewhere
+        if rules.last().is_some_and(|rule| rule.condition.is_some()) {
+            let last = caps["rules"].rsplit(',').next().unwrap();
+            return Err(ParseError::new(
+                v,
+                last,
+                "the last rule can't have a condition",
+            ));
+        }
+
+        Ok(Self { name, rules })
+    }
+}
+
+impl Workflow {
+    fn process_part(&self, part: &Part) -> Destination {
+        for rule in &self.rules {
+            let destination = rule.process_part(part);
+            if let Some(destination) = destination {
+                return destination;
+            }
+        }
+
+        unreachable!()
+    }
+
+    // each rule peels off the part of the range that passes it
+    // and whatever is left over falls through to the next rule
+    fn count_accepted(&self, workflows: &HashMap<String, Workflow>, range: PartRange) -> usize {
+        let mut count = 0;
+
+        let mut remaining = Some(range);
+        for rule in &self.rules {
+            let Some(range) = remaining else {
+                break;
+            };
+
+            let (pass, fail) = match &rule.condition {
+                Some(condition) => condition.split_range(&range),
+                None => (Some(range), None),
+            };
+
+            if let Some(pass) = pass {
+                count += match &rule.destination {
+                    Destination::Accept => pass.combinations(),
+                    Destination::Reject => 0,
+                    Destination::Workflow(name) => {
+                        workflows.get(name).unwrap().count_accepted(workflows, pass)
+                    }
+                };
+            }
+
+            remaining = fail;
+        }
+
+        count
+    }
+}
+
+// a 4-dimensional hypercube of part ratings
+#[derive(Debug, Clone)]
+struct PartRange {
+    x: RangeInclusive<usize>,
+    m: RangeInclusive<usize>,
+    a: RangeInclusive<usize>,
+    s: RangeInclusive<usize>,
+}
+
+impl Default for PartRange {
+    fn default() -> Self {
+        Self {
+            x: 1..=4000,
+            m: 1..=4000,
+            a: 1..=4000,
+            s: 1..=4000,
+        }
+    }
+}
+
+impl PartRange {
+    fn get_rating(&self, rating: char) -> &RangeInclusive<usize> {
+        match rating {
+            'x' => &self.x,
+            'm' => &self.m,
+            'a' => &self.a,
+            's' => &self.s,
+            _ => unreachable!(),
+        }
+    }
+
+    fn with_rating(&self, rating: char, r: RangeInclusive<usize>) -> Option<Self> {
+        if r.is_empty() {
+            return None;
+        }
+
+        let mut range = self.clone();
+        match rating {
+            'x' => range.x = r,
+            'm' => range.m = r,
+            'a' => range.a = r,
+            's' => range.s = r,
+            _ => unreachable!(),
+        }
+        Some(range)
+    }
+
+    fn combinations(&self) -> usize {
+        [&self.x, &self.m, &self.a, &self.s]
+            .iter()
+            .map(|r| r.end() - r.start() + 1)
+            .product()
+    }
+}
+
+#[derive(Debug)]
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl FromStr for Part {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"\{x=(?<x>\d+),m=(?<m>\d+),a=(?<a>\d+),s=(?<s>\d+)\}").unwrap();
+        let caps = re.captures(v).ok_or_else(|| {
+            ParseError::new(v, v, "expected a part like {x=787,m=2655,a=1222,s=2876}")
+        })?;
+
+        let x = parse_at::<usize>(v, &caps["x"])?;
+        let m = parse_at::<usize>(v, &caps["m"])?;
+        let a = parse_at::<usize>(v, &caps["a"])?;
+        let s = parse_at::<usize>(v, &caps["s"])?;
+
+        Ok(Self { x, m, a, s })
+    }
+}
+
+impl Part {
+    fn get_value(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+pub fn part1(workflows: &HashMap<String, Workflow>, parts: &[Part]) -> usize {
+    let mut accepted = 0;
+
+    for part in parts {
+        //println!("checking part {:?}", part);
+        let mut workflow = workflows.get("in").unwrap();
+        loop {
+            let destination = workflow.process_part(part);
+            match destination {
+                Destination::Accept => {
+                    //println!("accepted part: {:?}", part);
+                    accepted += part.get_value();
+                    break;
+                }
+                Destination::Reject => {
+                    break;
+                }
+                Destination::Workflow(name) => {
+                    //println!("passing to workflow: {}", name);
+                    workflow = workflows.get(&name).unwrap();
+                }
+            }
+        }
+    }
+
+    println!("Accepted: {}", accepted);
+
+    accepted
+}
+
+pub fn part2(workflows: &HashMap<String, Workflow>) -> usize {
+    let accepted = workflows
+        .get("in")
+        .unwrap()
+        .count_accepted(workflows, PartRange::default());
+
+    println!("Accepted combinations: {}", accepted);
+
+    accepted
+}
+
+pub fn parse(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+    let (workflows, parts) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::truncated(
+            input,
+            "expected a blank line between the workflows and the parts",
+        )
+    })?;
+
+    let workflows = aoc_parse::lines::<Workflow>(workflows)
+        .map_err(|err| err.within(input, workflows))?
+        .into_iter()
+        .map(|workflow| (workflow.name.clone(), workflow))
+        .collect::<HashMap<_, _>>();
+
+    let parts = aoc_parse::lines(parts).map_err(|err| err.within(input, parts))?;
+
+    Ok((workflows, parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (workflows, parts) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&workflows, &parts), 19114);
+        assert_eq!(part2(&workflows), 167409079868000);
+    }
+
+    #[test]
+    fn split_range_edges() {
+        let range = PartRange::default();
+        let all = range.combinations();
+
+        for (condition, pass) in [
+            ("x<0", 0),
+            ("x<1", 0),
+            ("x<2", all / 4000),
+            ("x>3999", all / 4000),
+            ("x>4000", 0),
+            (&format!("x>{}", usize::MAX), 0),
+        ] {
+            let (passed, failed) = condition.parse::<Condition>().unwrap().split_range(&range);
+            let passed = passed.map(|r| r.combinations()).unwrap_or(0);
+            let failed = failed.map(|r| r.combinations()).unwrap_or(0);
+            assert_eq!((passed, failed), (pass, all - pass), "{}", condition);
+        }
+    }
+}
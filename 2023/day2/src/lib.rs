@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, strum::EnumString)]
+enum Color {
+    #[strum(ascii_case_insensitive)]
+    Red,
+
+    #[strum(ascii_case_insensitive)]
+    Green,
+
+    #[strum(ascii_case_insensitive)]
+    Blue,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: usize,
+    results: Vec<HashMap<Color, usize>>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"Game (?P<id>\d+): (?<pulls>.*)+").unwrap();
+        let caps = re
+            .captures(v)
+            .ok_or_else(|| ParseError::new(v, v, "expected Game N: ..."))?;
+
+        let id = parse_at(v, &caps["id"])?;
+
+        // this is probably all doable in the regex :shrug:
+        let mut results = vec![];
+        let pulls = caps["pulls"].split(';');
+        for pull in pulls {
+            let mut cubes = HashMap::new();
+            let counts = pull.split(',');
+            for count in counts {
+                let count = count.trim();
+                let parts = count
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::new(v, count, "expected a count and a color"))?;
+
+                let color = Color::from_str(parts.1)
+                    .map_err(|_| ParseError::new(v, parts.1, "expected red, green or blue"))?;
+                let count = parse_at(v, parts.0)?;
+                let old = cubes.insert(color, count);
+                if old.is_some() {
+                    return Err(ParseError::new(v, parts.1, "color pulled twice"));
+                }
+            }
+            results.push(cubes);
+        }
+
+        Ok(Self { id, results })
+    }
+}
+
+impl Game {
+    fn is_possible(&self, max_red: usize, max_green: usize, max_blue: usize) -> bool {
+        for result in &self.results {
+            if let Some(count) = result.get(&Color::Red) {
+                if *count > max_red {
+                    return false;
+                }
+            }
+
+            if let Some(count) = result.get(&Color::Green) {
+                if *count > max_green {
+                    return false;
+                }
+            }
+
+            if let Some(count) = result.get(&Color::Blue) {
+                if *count > max_blue {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn get_min_needed(&self) -> (usize, usize, usize) {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        for result in &self.results {
+            if let Some(count) = result.get(&Color::Red) {
+                red = red.max(*count);
+            }
+
+            if let Some(count) = result.get(&Color::Green) {
+                green = green.max(*count);
+            }
+
+            if let Some(count) = result.get(&Color::Blue) {
+                blue = blue.max(*count);
+            }
+        }
+
+        (red, green, blue)
+    }
+
+    fn get_min_power(&self) -> usize {
+        let (red, green, blue) = self.get_min_needed();
+        red * green * blue
+    }
+}
+
+pub fn part1(games: impl AsRef<[Game]>) -> usize {
+    let mut sum = 0;
+
+    for game in games.as_ref() {
+        if game.is_possible(12, 13, 14) {
+            sum += game.id;
+        }
+    }
+
+    println!("Sum: {}", sum);
+
+    sum
+}
+
+pub fn part2(games: impl AsRef<[Game]>) -> usize {
+    let mut sum = 0;
+
+    for game in games.as_ref() {
+        sum += game.get_min_power();
+    }
+
+    println!("Sum: {}", sum);
+
+    sum
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    aoc_parse::lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 8);
+        assert_eq!(part2(values), 2286);
+    }
+}
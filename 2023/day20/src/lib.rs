@@ -0,0 +1,224 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub enum Module {
+    FlipFlop {
+        name: String,
+        value: RefCell<bool>,
+        destinations: Vec<String>,
+    },
+    Conjunction {
+        name: String,
+        values: RefCell<HashMap<String, bool>>,
+        destinations: Vec<String>,
+    },
+    Broadcaster {
+        name: String,
+        destinations: Vec<String>,
+    },
+}
+
+impl FromStr for Module {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re =
+            Regex::new(r"(?<type>%|&|broadcaster)(?<name>.+)? -> (?<destinations>.+)").unwrap();
+        let caps = re.captures(v).ok_or_else(|| {
+            ParseError::new(
+                v,
+                v,
+                "expected a module like %a -> b, c or broadcaster -> a",
+            )
+        })?;
+
+        let r#type = &caps["type"];
+        let name = || {
+            caps.name("name")
+                .map(|v| v.as_str().to_owned())
+                .ok_or_else(|| ParseError::new(v, &caps["type"], "expected a module name"))
+        };
+        let destinations = caps["destinations"]
+            .split(", ")
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+
+        Ok(match r#type {
+            "%" => Self::FlipFlop {
+                name: name()?,
+                value: RefCell::new(false),
+                destinations,
+            },
+            "&" => Self::Conjunction {
+                name: name()?,
+                values: RefCell::new(HashMap::new()),
+                destinations,
+            },
+            // the regex only matches the three types
+            _ => Self::Broadcaster {
+                name: "broadcaster".to_string(),
+                destinations,
+            },
+        })
+    }
+}
+
+impl Module {
+    fn get_name(&self) -> &String {
+        match self {
+            Self::FlipFlop { name, .. } => name,
+            Self::Conjunction { name, .. } => name,
+            Self::Broadcaster { name, .. } => name,
+        }
+    }
+
+    fn get_destinations(&self) -> &Vec<String> {
+        match self {
+            Self::FlipFlop { destinations, .. } => destinations,
+            Self::Conjunction { destinations, .. } => destinations,
+            Self::Broadcaster { destinations, .. } => destinations,
+        }
+    }
+
+    fn broadcast(
+        input: &String,
+        pulse: bool,
+        destinations: &Vec<String>,
+        modules: &HashMap<String, Module>,
+    ) -> (usize, usize) {
+        let mut counts = (0, 0);
+
+        // TODO: this should be breadth-first, not depth-first
+        for destination in destinations {
+            println!(
+                "{} -{}-> {}",
+                input,
+                if pulse { "high" } else { "low" },
+                destination
+            );
+            let (highs, lows) = if let Some(module) = modules.get(destination) {
+                module.handle(input, pulse, modules)
+            } else {
+                (0, 0)
+            };
+
+            counts.0 += highs;
+            counts.1 += lows;
+        }
+
+        counts
+    }
+
+    fn handle(
+        &self,
+        input: &String,
+        pulse: bool,
+        modules: &HashMap<String, Module>,
+    ) -> (usize, usize) {
+        match self {
+            Self::FlipFlop {
+                name,
+                value,
+                destinations,
+                ..
+            } => {
+                if pulse {
+                    (0, 0)
+                } else {
+                    let prev = *value.borrow();
+                    *value.borrow_mut() = !prev;
+
+                    let value = *value.borrow();
+                    let (highs, lows) = Self::broadcast(name, value, destinations, modules);
+
+                    if value {
+                        (destinations.len() + highs, lows)
+                    } else {
+                        (highs, destinations.len() + lows)
+                    }
+                }
+            }
+            Self::Conjunction {
+                name,
+                values,
+                destinations,
+                ..
+            } => {
+                *values.borrow_mut().get_mut(input).unwrap() = pulse;
+
+                if values.borrow().values().all(|v| *v) {
+                    let (highs, lows) = Self::broadcast(name, false, destinations, modules);
+                    (highs, destinations.len() + lows)
+                } else {
+                    let (highs, lows) = Self::broadcast(name, true, destinations, modules);
+                    (destinations.len() + highs, lows)
+                }
+            }
+            Self::Broadcaster {
+                name, destinations, ..
+            } => {
+                let (highs, lows) = Self::broadcast(name, pulse, destinations, modules);
+                if pulse {
+                    (destinations.len() + highs, lows)
+                } else {
+                    (highs, destinations.len() + lows)
+                }
+            }
+        }
+    }
+}
+
+pub fn part1(modules: HashMap<String, Module>) -> usize {
+    let broadcaster = modules.get("broadcaster").unwrap();
+
+    let mut totals = (0, 0);
+    //for _ in 0..1000 {
+    println!("button -low-> broadcaster");
+    let (highs, lows) = broadcaster.handle(broadcaster.get_name(), false, &modules);
+    totals.0 += highs;
+    totals.1 += lows + 1;
+    //}
+
+    let total = totals.0 * (totals.1);
+    println!("Total: {} (highs: {}, lows: {})", total, totals.0, totals.1);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<HashMap<String, Module>, ParseError> {
+    let modules = aoc_parse::lines::<Module>(input)?
+        .into_iter()
+        .map(|module| (module.get_name().to_owned(), module))
+        .collect::<HashMap<_, _>>();
+
+    for module in modules.values() {
+        if let Module::Conjunction { name, values, .. } = module {
+            for input in modules
+                .values()
+                .filter(|m| m.get_destinations().contains(name))
+            {
+                values.borrow_mut().insert(input.get_name().clone(), false);
+            }
+        }
+    }
+
+    Ok(modules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "part 1 only pushes the button once so far"]
+    fn example() {
+        let modules = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(modules), 11687500);
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Card {
+    winning_numbers: Vec<usize>,
+    numbers: Vec<usize>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re =
+            Regex::new(r"Card\s+(?<id>\d+):\s+(?<winning_numbers>.+)\|(?<numbers>.+)").unwrap();
+        let caps = re
+            .captures(v)
+            .ok_or_else(|| ParseError::new(v, v, "expected Card N: ... | ..."))?;
+
+        // this is probably all doable in the regex :shrug:
+        let winning_numbers = caps["winning_numbers"]
+            .split_ascii_whitespace()
+            .map(|n| parse_at(v, n))
+            .collect::<Result<Vec<_>, _>>()?;
+        let numbers = caps["numbers"]
+            .split_ascii_whitespace()
+            .map(|n| parse_at(v, n))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            winning_numbers,
+            numbers,
+        })
+    }
+}
+
+impl Card {
+    fn get_success_count(&self) -> usize {
+        let mut count = 0;
+        for n in &self.numbers {
+            if self.winning_numbers.contains(n) {
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+pub fn part1(cards: &Vec<Card>) -> usize {
+    let mut sum = 0;
+
+    for card in cards {
+        let success_count = card.get_success_count();
+        let value = match success_count {
+            0 => 0,
+            _ => 2_usize.pow(success_count as u32 - 1),
+        };
+        //println!("value: {}", value);
+        sum += value;
+    }
+
+    println!("Sum: {}", sum);
+
+    sum
+}
+
+pub fn part2(cards: &[Card]) -> usize {
+    let mut collection = cards
+        .iter()
+        .map(|card| (card.clone(), 1))
+        .collect::<HashMap<_, _>>();
+
+    for (idx, card) in cards.iter().enumerate() {
+        let count = *collection.get(card).unwrap();
+        let successes = card.get_success_count();
+
+        let start = idx + 1;
+        let end = (start + successes).min(cards.len());
+        for n in cards.iter().take(end).skip(start) {
+            *collection.get_mut(n).unwrap() += count;
+        }
+    }
+
+    let sum: usize = collection.values().sum();
+
+    println!("Sum: {}", sum);
+
+    sum
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    aoc_parse::lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let cards = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&cards), 13);
+        assert_eq!(part2(&cards), 30);
+    }
+}
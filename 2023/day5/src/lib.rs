@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::str::FromStr;
+
+use aoc_parse::{parse_at, ParseError};
+use rayon::prelude::*;
+use regex::Regex;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, strum::EnumString)]
+enum MapType {
+    #[strum(serialize = "seed-to-soil")]
+    SeedToSoil,
+
+    #[strum(serialize = "soil-to-fertilizer")]
+    SoilToFertilizer,
+
+    #[strum(serialize = "fertilizer-to-water")]
+    FertilizerToWater,
+
+    #[strum(serialize = "water-to-light")]
+    WaterToLight,
+
+    #[strum(serialize = "light-to-temperature")]
+    LightToTemperature,
+
+    #[strum(serialize = "temperature-to-humidity")]
+    TemperatureToHumidity,
+
+    #[strum(serialize = "humidity-to-location")]
+    HumidityToLocation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct MapRange {
+    src_range: Range<usize>,
+    dst_range: Range<usize>,
+}
+
+impl FromStr for MapRange {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let parts = v.split_ascii_whitespace().collect::<Vec<_>>();
+        if parts.len() != 3 {
+            return Err(ParseError::new(v, v.trim(), "expected dst src len"));
+        }
+        let len = parse_at::<usize>(v, parts[2])?;
+
+        let dst_start = parse_at::<usize>(v, parts[0])?;
+        let dst_range = dst_start..(dst_start + len);
+
+        let src_start = parse_at::<usize>(v, parts[1])?;
+        let src_range = src_start..(src_start + len);
+
+        Ok(Self {
+            src_range,
+            dst_range,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    r#type: MapType,
+    ranges: Vec<MapRange>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"(?<type>.*) map:\n(?<ranges>(.|\n)*)").unwrap();
+        let caps = re
+            .captures(v)
+            .ok_or_else(|| ParseError::new(v, v.trim(), "expected a map like seed-to-soil map:"))?;
+
+        let r#type = MapType::from_str(&caps["type"])
+            .map_err(|_| ParseError::new(v, &caps["type"], "unknown map"))?;
+        let ranges = caps["ranges"]
+            .trim()
+            .split('\n')
+            .map(|range| {
+                range
+                    .parse()
+                    .map_err(|err: ParseError| err.within(v, range))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { r#type, ranges })
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Almanac {
+    maps: HashMap<MapType, Map>,
+}
+
+impl Almanac {
+    fn new(maps: HashMap<MapType, Map>) -> Self {
+        Self { maps }
+    }
+}
+
+fn get_seed_location(seed: usize, almanac: &Almanac) -> usize {
+    //println!("seed: {}", seed);
+
+    let map = &almanac.maps[&MapType::SeedToSoil];
+    let soil = match map.ranges.iter().find(|r| r.src_range.contains(&seed)) {
+        Some(r) => r.dst_range.start + (seed - r.src_range.start),
+        None => seed,
+    };
+
+    //println!("soil: {}", soil);
+
+    let map = &almanac.maps[&MapType::SoilToFertilizer];
+    let fertilizer = match map.ranges.iter().find(|r| r.src_range.contains(&soil)) {
+        Some(r) => r.dst_range.start + (soil - r.src_range.start),
+        None => soil,
+    };
+
+    //println!("fertilizer: {}", fertilizer);
+
+    let map = &almanac.maps[&MapType::FertilizerToWater];
+    let water = match map
+        .ranges
+        .iter()
+        .find(|r| r.src_range.contains(&fertilizer))
+    {
+        Some(r) => r.dst_range.start + (fertilizer - r.src_range.start),
+        None => fertilizer,
+    };
+
+    //println!("water: {}", water);
+
+    let map = &almanac.maps[&MapType::WaterToLight];
+    let light = match map.ranges.iter().find(|r| r.src_range.contains(&water)) {
+        Some(r) => r.dst_range.start + (water - r.src_range.start),
+        None => water,
+    };
+
+    //println!("light: {}", light);
+
+    let map = &almanac.maps[&MapType::LightToTemperature];
+    let temp = match map.ranges.iter().find(|r| r.src_range.contains(&light)) {
+        Some(r) => r.dst_range.start + (light - r.src_range.start),
+        None => light,
+    };
+
+    //println!("temp: {}", temp);
+
+    let map = &almanac.maps[&MapType::TemperatureToHumidity];
+    let humidity = match map.ranges.iter().find(|r| r.src_range.contains(&temp)) {
+        Some(r) => r.dst_range.start + (temp - r.src_range.start),
+        None => temp,
+    };
+
+    //println!("humidity: {}", humidity);
+
+    let map = &almanac.maps[&MapType::HumidityToLocation];
+    let location = match map.ranges.iter().find(|r| r.src_range.contains(&humidity)) {
+        Some(r) => r.dst_range.start + (humidity - r.src_range.start),
+        None => humidity,
+    };
+
+    //println!("location: {}", location);
+
+    location
+}
+
+pub fn part1(seeds: &[usize], almanac: &Almanac) -> usize {
+    let locations = seeds
+        .iter()
+        .map(|s| get_seed_location(*s, almanac))
+        .collect::<Vec<_>>();
+
+    let min_location = *locations.iter().min().unwrap();
+
+    println!("Min Location: {}", min_location);
+
+    min_location
+}
+
+pub fn part2(seeds: &[usize], almanac: &Almanac) -> usize {
+    let seed_ranges = seeds
+        .chunks(2)
+        .map(|r| r[0]..(r[0] + r[1]))
+        .collect::<Vec<_>>();
+
+    // this prints 2445858670
+    /*println!(
+        "part 2 checking {} seeds",
+        seed_ranges.iter().map(|x| x.len()).sum::<usize>()
+    );*/
+
+    // lol
+    let locations = seed_ranges
+        .par_iter()
+        .flat_map(|r| {
+            let r = r.clone();
+            r.into_par_iter()
+                .map(|s| get_seed_location(s, almanac))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let min_location = *locations.iter().min().unwrap();
+
+    println!("Min Location: {}", min_location);
+
+    min_location
+}
+
+pub fn parse(input: &str) -> Result<(Vec<usize>, Almanac), ParseError> {
+    let re = Regex::new(r"seeds: (?<seeds>.*)\n\n(?<maps>(.|\n)*)").unwrap();
+    let caps = re
+        .captures(input)
+        .ok_or_else(|| ParseError::new(input, input, "expected seeds: ... and then the maps"))?;
+
+    let seeds = caps["seeds"]
+        .split_ascii_whitespace()
+        .map(|x| parse_at::<usize>(input, x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let maps = caps["maps"]
+        .split("\n\n")
+        .map(|m| m.parse::<Map>().map_err(|err| err.within(input, m)))
+        .map(|m| m.map(|m| (m.r#type, m)))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    // every step from seed to location needs its map
+    if maps.len() != 7 {
+        return Err(ParseError::truncated(input, "expected all 7 maps"));
+    }
+
+    Ok((seeds, Almanac::new(maps)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (seeds, almanac) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&seeds, &almanac), 35);
+        assert_eq!(part2(&seeds, &almanac), 46);
+    }
+
+    #[test]
+    fn malformed() {
+        let example = include_str!("../examples/example.txt");
+
+        let err = parse(&example.replace("52 50 48", "52 50 x")).unwrap_err();
+        assert_eq!((err.line, err.column), (5, 7));
+        assert_eq!(err.text, "x");
+
+        // cut off part way through the maps
+        let err = parse(&example[..example.find("\n\nlight").unwrap()]).unwrap_err();
+        assert_eq!(err.message, "expected all 7 maps");
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_parse::{parse_at, ParseError};
+
+// every race, and the one long race you get by ignoring the spaces
+#[derive(Debug)]
+pub struct Races {
+    times: Vec<usize>,
+    distances: Vec<usize>,
+
+    time: usize,
+    distance: usize,
+}
+
+fn calculate_wins(time: usize, distance: usize) -> usize {
+    let mut wins = 0;
+    for hold in 0..=time {
+        let v = hold;
+        let d = v * (time - hold);
+        if d > distance {
+            wins += 1;
+        }
+    }
+    wins
+}
+
+pub fn part1(races: &Races) -> usize {
+    let mut total_wins = vec![];
+    for (time, distance) in races.times.iter().zip(races.distances.iter()) {
+        let wins = calculate_wins(*time, *distance);
+        total_wins.push(wins);
+    }
+
+    let total = total_wins.iter().product::<usize>();
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(races: &Races) -> usize {
+    let wins = calculate_wins(races.time, races.distance);
+    println!("Total: {}", wins);
+
+    wins
+}
+
+// the numbers after the label, and all of them run together (ignoring the spaces)
+fn numbers<'a>(
+    input: &'a str,
+    line: Option<&'a str>,
+    label: &str,
+) -> Result<(Vec<usize>, usize), ParseError> {
+    let expected = format!("expected {} and then numbers", label);
+    let line = line.ok_or_else(|| ParseError::truncated(input, expected.as_str()))?;
+    let values = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::new(input, line, expected.as_str()))?;
+
+    let numbers = values
+        .split_ascii_whitespace()
+        .map(|x| parse_at(input, x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let all = values.split_ascii_whitespace().collect::<String>();
+    let all = all.parse().map_err(|err| {
+        ParseError::new(
+            input,
+            values.trim(),
+            format!("expected one number ({})", err),
+        )
+    })?;
+
+    Ok((numbers, all))
+}
+
+pub fn parse(input: &str) -> Result<Races, ParseError> {
+    let mut lines = input.lines();
+
+    let (times, time) = numbers(input, lines.next(), "Time:")?;
+    let (distances, distance) = numbers(input, lines.next(), "Distance:")?;
+    if times.len() != distances.len() {
+        return Err(ParseError::truncated(
+            input,
+            "expected a distance for every time",
+        ));
+    }
+
+    Ok(Races {
+        times,
+        distances,
+        time,
+        distance,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let races = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&races), 288);
+        assert_eq!(part2(&races), 71503);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("Time:      7  15   30\nDistance:  9  4O  200\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 15));
+        assert_eq!(err.text, "4O");
+
+        let err = parse("Time:      7  15   30\n").unwrap_err();
+        assert_eq!(err.message, "expected Distance: and then numbers");
+
+        let err = parse("Time:      7  15   30\nDistance:  9  40\n").unwrap_err();
+        assert_eq!(err.message, "expected a distance for every time");
+    }
+}
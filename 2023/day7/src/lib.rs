@@ -0,0 +1,218 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+struct Card(usize);
+
+// the error is the character that isn't a card
+impl TryFrom<(char, bool)> for Card {
+    type Error = char;
+
+    fn try_from(v: (char, bool)) -> Result<Self, Self::Error> {
+        if ('2'..='9').contains(&v.0) {
+            return Ok(Self(v.0.to_digit(10).unwrap() as usize));
+        }
+
+        Ok(Self(match v.0 {
+            'A' => 14,
+            'K' => 13,
+            'Q' => 12,
+            'J' => {
+                if v.1 {
+                    1
+                } else {
+                    11
+                }
+            }
+            'T' => 10,
+            _ => return Err(v.0),
+        }))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, Clone, Eq)]
+pub struct Hand {
+    r#type: HandType,
+    cards: Vec<Card>,
+    bid: usize,
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.r#type != other.r#type {
+            return self.r#type.cmp(&other.r#type);
+        }
+
+        for (idx, card) in self.cards.iter().enumerate() {
+            match card.cmp(&other.cards[idx]) {
+                Ordering::Greater => return Ordering::Greater,
+                Ordering::Less => return Ordering::Less,
+                Ordering::Equal => (),
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.r#type == other.r#type && self.cards == other.cards
+    }
+}
+
+impl Hand {
+    fn get_hand_type(cards: &[Card]) -> HandType {
+        let card_counts = cards.iter().fold(HashMap::new(), |mut acc, card| {
+            acc.entry(card.0).and_modify(|v| *v += 1).or_insert(1);
+            acc
+        });
+
+        match card_counts.len() {
+            1 => HandType::FiveOfAKind,
+            2 => {
+                if card_counts.values().any(|v| *v == 4) {
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                if card_counts.values().any(|v| *v == 3) {
+                    HandType::ThreeOfAKind
+                } else {
+                    HandType::TwoPair
+                }
+            }
+            4 => HandType::OnePair,
+            5 => HandType::HighCard,
+            _ => unreachable!(),
+        }
+    }
+
+    fn get_hand_type_replace(cards: &[Card], idx: usize, card: Card) -> HandType {
+        let mut cards = cards.to_owned();
+        cards[idx] = card;
+
+        if let Some(idx) = cards.iter().position(|card| card.0 == 1) {
+            let mut max = HandType::HighCard;
+            for v in 2..=14 {
+                if v == 11 {
+                    continue;
+                }
+                max = Self::get_hand_type_replace(&cards, idx, Card(v)).max(max)
+            }
+            max
+        } else {
+            Self::get_hand_type(&cards)
+        }
+    }
+
+    fn new(v: &str, joker: bool) -> Result<Self, ParseError> {
+        let parts = v
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(v, v, "expected a hand and a bid"))?;
+        let cards = parts
+            .0
+            .char_indices()
+            .map(|(idx, ch)| {
+                Card::try_from((ch, joker)).map_err(|_| {
+                    ParseError::new(v, &parts.0[idx..idx + ch.len_utf8()], "expected a card")
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if cards.len() != 5 {
+            return Err(ParseError::new(v, parts.0, "expected 5 cards"));
+        }
+        let bid = parse_at::<usize>(v, parts.1)?;
+
+        let r#type = if joker {
+            // just brute force the dang thing
+            if let Some(idx) = cards.iter().position(|card| card.0 == 1) {
+                let mut max = HandType::HighCard;
+                for v in 2..=14 {
+                    if v == 11 {
+                        continue;
+                    }
+                    max = Self::get_hand_type_replace(&cards, idx, Card(v)).max(max)
+                }
+                max
+            } else {
+                Self::get_hand_type(&cards)
+            }
+        } else {
+            Self::get_hand_type(&cards)
+        };
+
+        Ok(Self { r#type, cards, bid })
+    }
+}
+
+pub fn part1(mut hands: Vec<Hand>) -> usize {
+    hands.sort();
+
+    let mut total = 0;
+    for (rank, hand) in hands.iter().enumerate() {
+        let value = hand.bid * (rank + 1);
+        //println!("value of {:?}, rank {} is {}", hand, rank + 1, value);
+        total += value;
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(mut hands: Vec<Hand>) -> usize {
+    hands.sort();
+
+    let mut total = 0;
+    for (rank, hand) in hands.iter().enumerate() {
+        let value = hand.bid * (rank + 1);
+        //println!("value of {:?}, rank {} is {}", hand, rank + 1, value);
+        total += value;
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str, joker: bool) -> Result<Vec<Hand>, ParseError> {
+    input
+        .lines()
+        .map(|line| Hand::new(line, joker).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = include_str!("../examples/example.txt");
+
+        assert_eq!(part1(parse(input, false).unwrap()), 6440);
+        assert_eq!(part2(parse(input, true).unwrap()), 5905);
+    }
+}
@@ -0,0 +1,255 @@
+use aoc_cycle::find_cycle;
+use aoc_graph::Graph;
+use aoc_math::crt;
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, strum::EnumString)]
+pub enum Direction {
+    #[strum(serialize = "L")]
+    Left,
+
+    #[strum(serialize = "R")]
+    Right,
+}
+
+// each node has a left and a right edge, labelled with the direction
+type Nodes<'a> = Graph<'a, Direction>;
+
+fn is_start(name: &str) -> bool {
+    name.ends_with('A')
+}
+
+fn is_end(name: &str, full: bool) -> bool {
+    if full {
+        name == "ZZZ"
+    } else {
+        name.ends_with('Z')
+    }
+}
+
+fn follow(node: usize, direction: Direction, nodes: &Nodes) -> usize {
+    nodes
+        .edges(node)
+        .iter()
+        .find(|(_, d)| *d == direction)
+        .map(|(next, _)| *next)
+        .unwrap()
+}
+
+fn run(start: &str, directions: &[Direction], nodes: &Nodes, full_end: bool) -> usize {
+    let mut steps = 0;
+
+    let mut idx = 0;
+    let mut node = nodes.index_of(start).unwrap();
+    loop {
+        steps += 1;
+
+        node = follow(node, directions[idx], nodes);
+
+        if is_end(nodes.name(node), full_end) {
+            break;
+        }
+
+        idx = (idx + 1) % directions.len();
+    }
+
+    steps
+}
+
+// when a ghost is on an end node, it can hit some before its path starts looping
+// and then the rest come back around every trip round the loop
+#[derive(Debug)]
+struct GhostEnds {
+    start: usize,
+    period: usize,
+
+    // steps before the loop starts
+    before: Vec<usize>,
+
+    // steps during the first trip round the loop (start..start + period)
+    looping: Vec<usize>,
+}
+
+impl GhostEnds {
+    fn at_end(&self, step: usize) -> bool {
+        if step < self.start {
+            self.before.contains(&step)
+        } else {
+            let step = self.start + (step - self.start) % self.period;
+            self.looping.contains(&step)
+        }
+    }
+}
+
+// a ghost repeats itself once it's on the same node
+// at the same point in the directions, so its path has to loop
+fn ghost_loop(start: &str, directions: &[Direction], nodes: &Nodes) -> GhostEnds {
+    let cycle = find_cycle(
+        (nodes.index_of(start).unwrap(), 0),
+        |(node, idx)| {
+            (
+                follow(*node, directions[*idx], nodes),
+                (idx + 1) % directions.len(),
+            )
+        },
+        |state| *state,
+    );
+
+    // the last state in the history is the repeat, it's already in there at the loop start
+    let (before, looping) = cycle.history[..cycle.start + cycle.period]
+        .iter()
+        .enumerate()
+        .filter(|(_, (node, _))| is_end(nodes.name(*node), false))
+        .map(|(step, _)| step)
+        .partition(|&step| step < cycle.start);
+
+    //println!("start {} period {} before {:?} looping {:?}", cycle.start, cycle.period, before, looping);
+    GhostEnds {
+        start: cycle.start,
+        period: cycle.period,
+        before,
+        looping,
+    }
+}
+
+// first step every ghost is on an end node at the same time
+fn all_at_end(ghosts: &[GhostEnds]) -> Option<usize> {
+    // until every ghost is looping just check each step
+    let settled = ghosts
+        .iter()
+        .map(|ghost| ghost.start)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    if let Some(step) = (1..settled).find(|&step| ghosts.iter().all(|ghost| ghost.at_end(step))) {
+        return Some(step);
+    }
+
+    // after that each ghost is at an end on a few steps mod its period,
+    // so solve every combination of those with the crt
+    // (in the real input every ghost has exactly one end, right as its loop comes back around,
+    // which makes this the lcm of the periods)
+    let mut solutions = vec![(0, 1)];
+    for ghost in ghosts {
+        solutions = solutions
+            .iter()
+            .flat_map(|&solution| {
+                ghost
+                    .looping
+                    .iter()
+                    .filter_map(move |&step| crt([solution, (step as i64, ghost.period as i64)]))
+            })
+            .collect();
+    }
+
+    solutions
+        .into_iter()
+        .map(|(x, m)| {
+            // first solution once everyone is looping
+            let (x, m) = (x as usize, m as usize);
+            if x >= settled {
+                x
+            } else {
+                x + (settled - x).div_ceil(m) * m
+            }
+        })
+        .min()
+}
+
+pub fn part1(directions: &[Direction], nodes: &Nodes) -> usize {
+    let steps = run("AAA", directions, nodes, true);
+
+    println!("Steps: {}", steps);
+
+    steps
+}
+
+pub fn part2(directions: &[Direction], nodes: &Nodes) -> usize {
+    let start_nodes = nodes
+        .names()
+        .iter()
+        .filter(|name| is_start(name))
+        .collect::<Vec<_>>();
+
+    let ghosts = start_nodes
+        .iter()
+        .map(|name| ghost_loop(name, directions, nodes))
+        .collect::<Vec<_>>();
+
+    //println!("ghosts: {:?}", ghosts);
+
+    let steps = all_at_end(&ghosts).unwrap();
+
+    println!("Steps: {}", steps);
+
+    steps
+}
+
+pub fn parse(input: &str) -> Result<(Vec<Direction>, Nodes<'_>), ParseError> {
+    let mut lines = input.lines();
+
+    let directions_line = lines.next().unwrap_or("").trim();
+    if directions_line.is_empty() {
+        return Err(ParseError::new(
+            input,
+            directions_line,
+            "expected L / R directions",
+        ));
+    }
+
+    let directions = directions_line
+        .char_indices()
+        .map(|(idx, ch)| parse_at(input, &directions_line[idx..idx + ch.len_utf8()]))
+        .collect::<Result<Vec<Direction>, _>>()?;
+
+    let re = Regex::new(r"^(?<name>.+) = \((?<left>.+), (?<right>.+)\)$").unwrap();
+
+    let mut nodes = Nodes::directed();
+    for v in lines {
+        if v.is_empty() {
+            continue;
+        }
+
+        let caps = re
+            .captures(v)
+            .ok_or_else(|| ParseError::new(input, v, "expected AAA = (BBB, CCC)"))?;
+        let name = caps.name("name").unwrap().as_str();
+        nodes.add_edge(name, caps.name("left").unwrap().as_str(), Direction::Left);
+        nodes.add_edge(name, caps.name("right").unwrap().as_str(), Direction::Right);
+    }
+
+    Ok((directions, nodes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (directions, nodes) = parse(include_str!("../examples/example.txt")).unwrap();
+        assert_eq!(part1(&directions, &nodes), 2);
+
+        let (directions, nodes) = parse(include_str!("../examples/example2.txt")).unwrap();
+        assert_eq!(part1(&directions, &nodes), 6);
+    }
+
+    // part 2 has its own example
+    #[test]
+    fn ghosts() {
+        let (directions, nodes) = parse(include_str!("../examples/example3.txt")).unwrap();
+
+        assert_eq!(part2(&directions, &nodes), 6);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("LLX\n\nAAA = (BBB, BBB)\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+
+        let err = parse("LLR\n\nAAA = (BBB, BBB)\nBBB = AAA, ZZZ\n").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.message, "expected AAA = (BBB, CCC)");
+    }
+}
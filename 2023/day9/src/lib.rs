@@ -0,0 +1,103 @@
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Reverse,
+}
+
+fn process_sequence(sequence: &[i64], direction: Direction) -> i64 {
+    //println!("sequence: {:?}", sequence);
+    let diffs = if direction == Direction::Forward {
+        sequence
+            .windows(2)
+            .map(|window| {
+                //println!("chunk: {:?}", window);
+                window[1] - window[0]
+            })
+            .collect::<Vec<_>>()
+    } else {
+        sequence
+            .windows(2)
+            .rev()
+            .map(|window| {
+                //println!("chunk: {:?}", window);
+                window[1] - window[0]
+            })
+            .rev()
+            .collect::<Vec<_>>()
+    };
+
+    if diffs.iter().all(|v| *v == 0) {
+        return 0;
+    }
+
+    //println!("diffs: {:?}", diffs);
+
+    let diff = process_sequence(&diffs, direction);
+    //println!("diff: {}", diff);
+    if direction == Direction::Forward {
+        diffs.iter().last().unwrap() + diff
+    } else {
+        diffs.first().unwrap() - diff
+    }
+}
+
+pub fn part1(sequences: &[Vec<i64>]) -> i64 {
+    let mut total = 0;
+    for sequence in sequences {
+        let next_value =
+            sequence.iter().last().unwrap() + process_sequence(sequence, Direction::Forward);
+        //println!("next value: {}", next_value);
+        total += next_value;
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(sequences: &[Vec<i64>]) -> i64 {
+    let mut total = 0;
+    for sequence in sequences {
+        let next_value =
+            sequence.iter().next().unwrap() - process_sequence(sequence, Direction::Reverse);
+        //println!("next value: {}", next_value);
+        total += next_value;
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .map(|v| parse_at(input, v))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let sequences = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&sequences), 114);
+        assert_eq!(part2(&sequences), 2);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("0 3 6 9 12 15\n1 3 6 1O 15 21\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.text, "1O");
+    }
+}
@@ -0,0 +1,268 @@
+//! Times parsing, part 1 and part 2 for every day
+//!
+//! `cargo bench -p bench` runs everything, `cargo bench -p bench -- day7/` just the one day.
+//! `-- --save-baseline before` keeps the numbers around and `-- --baseline before` compares against them.
+
+use std::hint::black_box;
+use std::io::Write;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, BatchSize, Bencher, BenchmarkGroup, Criterion};
+use gag::Gag;
+
+// the days print their answers (and sometimes whole grids) as they go,
+// that's just noise in a bench so stdout is gagged while a day is running
+fn quiet<T>(f: impl FnOnce() -> T) -> T {
+    // only one gag can be open at a time, if that fails it's just noisy
+    let gag = Gag::stdout().ok();
+    let v = f();
+    std::io::stdout().flush().unwrap();
+    drop(gag);
+    v
+}
+
+// criterion prints its results after the routine is done, so those still show up
+struct QuietGroup<'a>(BenchmarkGroup<'a, WallTime>);
+
+impl QuietGroup<'_> {
+    fn sample_size(&mut self, n: usize) -> &mut Self {
+        self.0.sample_size(n);
+        self
+    }
+
+    fn bench_function<F: FnMut(&mut Bencher)>(&mut self, id: &str, mut f: F) -> &mut Self {
+        self.0.bench_function(id, |b| quiet(|| f(b)));
+        self
+    }
+
+    fn finish(self) {
+        self.0.finish();
+    }
+}
+
+// every day is a library crate, this brings its parse and parts into a module to bench them
+macro_rules! day {
+    ($day:ident, |$group:ident, $input:ident| $body:block) => {
+        // not every day needs batching
+        #[allow(unused_imports)]
+        mod $day {
+            use super::{black_box, quiet, BatchSize, QuietGroup};
+            use ::$day::*;
+
+            pub fn bench(c: &mut criterion::Criterion) {
+                let $input = include_str!(concat!("../../", stringify!($day), "/input.txt"));
+
+                let mut $group = QuietGroup(c.benchmark_group(stringify!($day)));
+                $group.sample_size(10);
+                $body
+                $group.finish();
+            }
+        }
+    };
+}
+
+day!(day1, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (a, b) = parse(input).unwrap();
+    group.bench_function("part1", |bencher| {
+        bencher.iter_batched(
+            || (a.clone(), b.clone()),
+            |(a, b)| part1(a, b),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("part2", |bencher| bencher.iter(|| part2(black_box(&a), &b)));
+});
+
+day!(day2, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let reports = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&reports))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&reports))));
+});
+
+day!(day3, |group, input| {
+    // no parsing, the parts scan the memory directly
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(input))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(input))));
+});
+
+day!(day4, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let puzzle = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&puzzle))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&puzzle))));
+});
+
+day!(day5, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (rules, updates) = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&rules), &updates)));
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || updates.clone(),
+            |updates| part2(&rules, updates),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+// day6 doesn't do anything past reading the input yet
+
+day!(day7, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    // the parts only borrow the operands mutably to slice them up, they don't change them
+    let mut equations = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&mut equations))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&mut equations))));
+});
+
+day!(day8, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let map = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&map))));
+});
+
+day!(day9, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let disk = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(|| disk.blocks(), part1, BatchSize::SmallInput)
+    });
+
+    // the disk can't be cloned so just parse it again for every run
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| parse(input).unwrap(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day10, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (heightmap, trailheads) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(black_box(&heightmap), &trailheads))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| part2(black_box(&heightmap), &trailheads))
+    });
+});
+
+day!(day11, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let stones = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&stones))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&stones))));
+});
+
+day!(day12, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let regions = quiet(|| parse(input));
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&regions))));
+});
+
+day!(day13, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let puzzles = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&puzzles))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&puzzles))));
+});
+
+day!(day14, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (robots, width, height) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(black_box(&robots), width, height))
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(
+            || robots.clone(),
+            |robots| part2(robots, width, height),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day15, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (input_map, moves) = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(input_map), &moves)));
+
+    // part 2 isn't written yet
+});
+
+day!(day16, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (map, start, end) = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&map), start, end)));
+});
+
+day!(day17, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (computer, program) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || computer.clone(),
+            |computer| part1(computer, &program),
+            BatchSize::SmallInput,
+        )
+    });
+
+    // part 2 searches for the register value by brute force and doesn't finish
+});
+
+// day19 only builds the trie so far, there's nothing to time
+
+day!(day23, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let network = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&network))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&network))));
+});
+
+criterion_group!(
+    benches,
+    day1::bench,
+    day2::bench,
+    day3::bench,
+    day4::bench,
+    day5::bench,
+    day7::bench,
+    day8::bench,
+    day9::bench,
+    day10::bench,
+    day11::bench,
+    day12::bench,
+    day13::bench,
+    day14::bench,
+    day15::bench,
+    day16::bench,
+    day17::bench,
+    day23::bench,
+);
+
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    aoc_bench::print_summary(aoc_bench::criterion_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/.."
+    )));
+}
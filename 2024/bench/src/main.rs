@@ -0,0 +1,9 @@
+// `cargo bench -p bench` runs the benches and prints this at the end,
+// this just prints the table again from whatever criterion has saved
+
+fn main() {
+    aoc_bench::print_summary(aoc_bench::criterion_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/.."
+    )));
+}
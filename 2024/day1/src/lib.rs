@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use aoc_parse::{parse_at, ParseError};
+
+pub fn part1(mut a: Vec<isize>, mut b: Vec<isize>) -> isize {
+    assert!(a.len() == b.len());
+
+    a.sort();
+    b.sort();
+
+    let mut sum = 0;
+    for _ in 0..a.len() {
+        sum += (a.pop().unwrap() - b.pop().unwrap()).abs();
+    }
+
+    println!("sum: {}", sum);
+
+    sum
+}
+
+pub fn part2(a: &Vec<isize>, b: &Vec<isize>) -> isize {
+    let mut counts: HashMap<isize, isize> = HashMap::new();
+    for v in b {
+        *counts.entry(*v).or_default() += 1;
+    }
+
+    let mut score = 0;
+    for v in a {
+        score += *v * counts.get(v).copied().unwrap_or_default();
+    }
+
+    println!("score: {}", score);
+
+    score
+}
+
+pub fn parse(input: &str) -> Result<(Vec<isize>, Vec<isize>), ParseError> {
+    let values = input.lines().map(|line| {
+        let mut values = line.split_whitespace();
+        match (values.next(), values.next()) {
+            (Some(a), Some(b)) => Ok((parse_at(input, a)?, parse_at(input, b)?)),
+            _ => Err(ParseError::new(input, line, "expected two location ids")),
+        }
+    });
+
+    // TODO: probably a way to do this directly in the iterator step?
+    let mut a = vec![];
+    let mut b = vec![];
+    for v in values {
+        let v = v?;
+        a.push(v.0);
+        b.push(v.1);
+    }
+
+    Ok((a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (a, b) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(a.clone(), b.clone()), 11);
+        assert_eq!(part2(&a, &b), 31);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("3   4\n4   3\n2   S\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.text, "S");
+
+        let err = parse("3   4\n4\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
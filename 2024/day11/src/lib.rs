@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use aoc_math::{count_digits, split_digits};
+use aoc_parse::{parse_at, ParseError};
+
+// stones never interact and the order doesn't matter for counting,
+// so every stone with the same number can be blinked together
+fn blink(stones: &HashMap<u64, u64>) -> HashMap<u64, u64> {
+    let mut next = HashMap::with_capacity(stones.len());
+
+    for (&stone, &count) in stones {
+        if stone == 0 {
+            *next.entry(1).or_default() += count;
+            continue;
+        }
+
+        let digits = count_digits(stone);
+        if digits.is_multiple_of(2) {
+            let (left, right) = split_digits(stone, digits / 2);
+            *next.entry(left).or_default() += count;
+            *next.entry(right).or_default() += count;
+        } else {
+            *next.entry(stone * 2024).or_default() += count;
+        }
+    }
+
+    next
+}
+
+// how many stones of each number there are after the given number of blinks
+fn blink_stones(stones: &[u64], blinks: usize) -> HashMap<u64, u64> {
+    let mut distribution = HashMap::new();
+    for &stone in stones {
+        *distribution.entry(stone).or_default() += 1;
+    }
+
+    (0..blinks).fold(distribution, |distribution, _| blink(&distribution))
+}
+
+pub fn part1(stones: &[u64]) -> u64 {
+    let count = blink_stones(stones, 25).values().sum::<u64>();
+
+    println!("stones: {}", count);
+
+    count
+}
+
+pub fn part2(stones: &[u64]) -> u64 {
+    let count = blink_stones(stones, 75).values().sum::<u64>();
+
+    println!("stones: {}", count);
+
+    count
+}
+
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    input
+        .split_ascii_whitespace()
+        .map(|x| parse_at(input, x))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let stones = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&stones), 55312);
+        assert_eq!(part2(&stones), 65601038650482);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("125 17 -3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 8));
+        assert_eq!(err.text, "-3");
+    }
+}
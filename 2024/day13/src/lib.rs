@@ -0,0 +1,120 @@
+use aoc_parse::{parse_at, ParseError};
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct Puzzle {
+    a: (isize, isize),
+    b: (isize, isize),
+    prize: (isize, isize),
+}
+
+pub fn part2(puzzles: &[Puzzle]) -> isize {
+    let mut total = 0;
+
+    for puzzle in puzzles {
+        let prize = (
+            puzzle.prize.0 + 10000000000000,
+            puzzle.prize.1 + 10000000000000,
+        );
+
+        let x = (puzzle.b.0 * prize.1 - puzzle.b.1 * prize.0)
+            / (puzzle.b.0 * puzzle.a.1 - puzzle.b.1 * puzzle.a.0);
+        let y = (puzzle.a.1 * prize.0 - puzzle.a.0 * prize.1)
+            / (puzzle.a.1 * puzzle.b.0 - puzzle.a.0 * puzzle.b.1);
+
+        if x * puzzle.a.0 + y * puzzle.b.0 != prize.0 || x * puzzle.a.1 + y * puzzle.b.1 != prize.1
+        {
+            continue;
+        }
+
+        let cost = x * 3 + y;
+        total += cost;
+    }
+
+    println!("Total cost: {}", total);
+
+    total
+}
+
+pub fn part1(puzzles: &[Puzzle]) -> isize {
+    let mut total = 0;
+
+    for puzzle in puzzles {
+        let x = (puzzle.b.0 * puzzle.prize.1 - puzzle.b.1 * puzzle.prize.0)
+            / (puzzle.b.0 * puzzle.a.1 - puzzle.b.1 * puzzle.a.0);
+        let y = (puzzle.a.1 * puzzle.prize.0 - puzzle.a.0 * puzzle.prize.1)
+            / (puzzle.a.1 * puzzle.b.0 - puzzle.a.0 * puzzle.b.1);
+
+        if (x > 100 || y > 100)
+            || (x * puzzle.a.0 + y * puzzle.b.0 != puzzle.prize.0
+                || x * puzzle.a.1 + y * puzzle.b.1 != puzzle.prize.1)
+        {
+            continue;
+        }
+
+        let cost = x * 3 + y;
+        total += cost;
+    }
+
+    println!("Total cost: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Puzzle>, ParseError> {
+    let button_a_regex = Regex::new(r"Button A: X\+(?P<x>\d+), Y\+(?P<y>\d+)").unwrap();
+    let button_b_regex = Regex::new(r"Button B: X\+(?P<x>\d+), Y\+(?P<y>\d+)").unwrap();
+    let prize_regex = Regex::new(r"Prize: X=(?P<x>\d+), Y=(?P<y>\d+)").unwrap();
+
+    // the x, y from the next line of the puzzle
+    let xy = |puzzle, line: Option<&str>, regex: &Regex, what: &str| {
+        let Some(line) = line else {
+            return Err(
+                ParseError::truncated(puzzle, format!("expected {}", what)).within(input, puzzle)
+            );
+        };
+        let Some(caps) = regex.captures(line) else {
+            return Err(ParseError::new(input, line, format!("expected {}", what)));
+        };
+        Ok((
+            parse_at(input, caps.name("x").unwrap().as_str())?,
+            parse_at(input, caps.name("y").unwrap().as_str())?,
+        ))
+    };
+
+    input
+        .split("\n\n")
+        .map(|puzzle| {
+            let mut lines = puzzle.lines();
+
+            let a = xy(puzzle, lines.next(), &button_a_regex, "Button A: X+n, Y+n")?;
+            let b = xy(puzzle, lines.next(), &button_b_regex, "Button B: X+n, Y+n")?;
+            let prize = xy(puzzle, lines.next(), &prize_regex, "Prize: X=n, Y=n")?;
+
+            Ok(Puzzle { a, b, prize })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let puzzles = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&puzzles), 480);
+        assert_eq!(part2(&puzzles), 875318608908);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\nButton A: X+26, Y+66\nButton B: X-67, Y+21\nPrize: X=12748, Y=12176\n").unwrap_err();
+        assert_eq!((err.line, err.column), (6, 1));
+        assert_eq!(err.message, "expected Button B: X+n, Y+n");
+
+        let err = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err();
+        assert_eq!(err.message, "expected Prize: X=n, Y=n");
+    }
+}
@@ -0,0 +1,219 @@
+use std::ops::{Add, Mul};
+
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Vector {
+    x: isize,
+    y: isize,
+}
+
+impl Mul<isize> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Robot {
+    position: Vector,
+    velocity: Vector,
+}
+
+impl Robot {
+    fn simulate(&mut self, seconds: isize) {
+        self.position = self.position + self.velocity * seconds;
+    }
+
+    fn wrap(&mut self, width: isize, height: isize) {
+        // TODO: there's probably a more correct way to handle negatives
+
+        self.position.x %= width;
+        if self.position.x < 0 {
+            self.position.x += width;
+        }
+
+        self.position.y %= height;
+        if self.position.y < 0 {
+            self.position.y += height;
+        }
+    }
+}
+
+pub fn part1(robots: &[Robot], width: isize, height: isize) -> isize {
+    assert!(width % 2 == 1);
+    assert!(height % 2 == 1);
+
+    let mw = width / 2;
+    let mh = height / 2;
+
+    let quadrants = robots
+        .iter()
+        .map(|robot| {
+            // move the robot
+            let mut robot = robot.clone();
+            robot.simulate(100);
+            robot.wrap(width, height);
+
+            robot
+        })
+        .fold((0, 0, 0, 0), |mut acc, robot| {
+            // count the quadrants
+            if robot.position.x < mw && robot.position.y < mh {
+                acc.0 += 1;
+            } else if robot.position.x > mw && robot.position.y < mh {
+                acc.1 += 1;
+            } else if robot.position.x < mw && robot.position.y > mh {
+                acc.2 += 1;
+            } else if robot.position.x > mw && robot.position.y > mh {
+                acc.3 += 1;
+            }
+            acc
+        });
+
+    let score = quadrants.0 * quadrants.1 * quadrants.2 * quadrants.3;
+    println!("score: {}", score);
+
+    score
+}
+
+pub fn part2(mut robots: Vec<Robot>, width: isize, height: isize) -> isize {
+    let mut grid = vec![vec![0_usize; width as usize]; height as usize];
+    for robot in &robots {
+        grid[robot.position.y as usize][robot.position.x as usize] += 1;
+    }
+
+    let mut seconds = 0;
+    'outer: loop {
+        seconds += 1;
+
+        for robot in robots.iter_mut() {
+            grid[robot.position.y as usize][robot.position.x as usize] -= 1;
+            robot.simulate(1);
+            robot.wrap(width, height);
+            grid[robot.position.y as usize][robot.position.x as usize] += 1;
+        }
+
+        // I cheated and checked reddit for wtf we're looking for here
+        // there's an outline so just look for a line of that
+        for row in &grid {
+            let mut c = 0;
+            let mut m = 0;
+            for x in 1..row.len() - 1 {
+                if row[x - 1] > 0 && row[x] > 0 && row[x + 1] > 0 {
+                    c += 1;
+                } else {
+                    m = m.max(c);
+                    c = 0;
+                }
+            }
+
+            // rough estimate that seems to work
+            if m > 10 {
+                /*for row in &grid {
+                    for col in row {
+                        if *col != 0 {
+                            print!("*");
+                        } else {
+                            print!(" ");
+                        }
+                    }
+                    println!();
+                }
+                println!();*/
+
+                break 'outer;
+            }
+        }
+
+        // reddit says we cycle back to our
+        // initial position in this many seconds
+        if seconds >= width * height {
+            break;
+        }
+    }
+
+    println!("seconds: {}", seconds);
+
+    seconds
+}
+
+// returns the robots and the size of the space they're in
+pub fn parse(input: &str) -> Result<(Vec<Robot>, isize, isize), ParseError> {
+    let mut width = 0;
+    let mut height = 0;
+
+    // p=x,y or v=x,y
+    let vector = |text: &str, prefix| {
+        let Some((x, y)) = text.strip_prefix(prefix).and_then(|v| v.split_once(',')) else {
+            return Err(ParseError::new(
+                input,
+                text,
+                format!("expected {}x,y", prefix),
+            ));
+        };
+        Ok(Vector {
+            x: parse_at(input, x)?,
+            y: parse_at(input, y)?,
+        })
+    };
+
+    let robots = input
+        .lines()
+        .map(|line| {
+            let (position, velocity) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(input, line, "expected p=x,y v=x,y"))?;
+            let position = vector(position, "p=")?;
+
+            width = width.max(position.x + 1);
+            height = height.max(position.y + 1);
+
+            let velocity = vector(velocity, "v=")?;
+
+            Ok(Robot { position, velocity })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((robots, width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // part 2 doesn't have an example
+    #[test]
+    fn example() {
+        let (robots, width, height) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&robots, width, height), 12);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("p=0,4 v=3,-3\np=6,3 v=-1-3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.text, "v=-1-3");
+
+        let err = parse("p=0,4 v=3,-3\np=6,x v=-1,-3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.text, "x");
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use aoc_parse::ParseError;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    #[default]
+    Empty,
+    Wall,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Box {
+    position: Position,
+}
+
+impl Box {
+    fn new_at(position: Position) -> Self {
+        Self { position }
+    }
+
+    fn r#move(&mut self, r#move: Move, map: &Map, boxes: &mut HashMap<Position, Box>) -> bool {
+        let new_position = match r#move {
+            Move::Up => Position::new(self.position.x, self.position.y - 1),
+            Move::Down => Position::new(self.position.x, self.position.y + 1),
+            Move::Left => Position::new(self.position.x - 1, self.position.y),
+            Move::Right => Position::new(self.position.x + 1, self.position.y),
+        };
+
+        if boxes.contains_key(&new_position) {
+            let mut r#box = boxes.remove(&new_position).unwrap();
+            let moved = r#box.r#move(r#move, map, boxes);
+            boxes.insert(r#box.position, r#box);
+
+            if moved {
+                self.position = new_position;
+            }
+            return moved;
+        }
+
+        match map.tiles[new_position.y][new_position.x] {
+            Tile::Empty => self.position = new_position,
+            Tile::Wall => return false,
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Map {
+    tiles: Vec<Vec<Tile>>,
+    start: Position,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Move {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+// the error is the character that isn't a move
+impl TryFrom<char> for Move {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' => Ok(Self::Up),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            '>' => Ok(Self::Right),
+            _ => Err(value),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Robot {
+    position: Position,
+}
+
+impl Robot {
+    fn new_at(position: Position) -> Self {
+        Self { position }
+    }
+
+    fn r#move(&mut self, r#move: Move, map: &Map, boxes: &mut HashMap<Position, Box>) {
+        let new_position = match r#move {
+            Move::Up => Position::new(self.position.x, self.position.y - 1),
+            Move::Down => Position::new(self.position.x, self.position.y + 1),
+            Move::Left => Position::new(self.position.x - 1, self.position.y),
+            Move::Right => Position::new(self.position.x + 1, self.position.y),
+        };
+
+        if boxes.contains_key(&new_position) {
+            let mut r#box = boxes.remove(&new_position).unwrap();
+            let moved = r#box.r#move(r#move, map, boxes);
+            boxes.insert(r#box.position, r#box);
+
+            if moved {
+                self.position = new_position;
+            }
+            return;
+        }
+
+        match map.tiles[new_position.y][new_position.x] {
+            Tile::Empty => self.position = new_position,
+            Tile::Wall => (),
+        }
+    }
+}
+
+pub fn part1(input_map: &str, moves: &[Move]) -> usize {
+    let mut map = Map::default();
+    let mut boxes = HashMap::new();
+    for (y, line) in input_map.lines().enumerate() {
+        let mut row = vec![Tile::Empty; line.len()];
+        for (x, ch) in line.chars().enumerate() {
+            let position = Position::new(x, y);
+
+            match ch {
+                '.' => (),
+                '#' => row[x] = Tile::Wall,
+                '@' => map.start = position,
+                'O' => {
+                    boxes.insert(position, Box::new_at(position));
+                }
+                _ => unreachable!("{}", ch),
+            }
+        }
+        map.tiles.push(row);
+    }
+
+    let mut robot = Robot::new_at(map.start);
+
+    for r#move in moves {
+        robot.r#move(*r#move, &map, &mut boxes);
+    }
+
+    let mut total = 0;
+    for position in boxes.keys() {
+        total += position.y * 100 + position.x;
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(_input_map: &str, _moves: &[Move]) {}
+
+// returns the unparsed map and the moves
+// the map is only checked here, the parts read it themselves
+pub fn parse(input: &str) -> Result<(&str, Vec<Move>), ParseError> {
+    let (input_map, moves) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::truncated(input, "expected a blank line between the map and the moves")
+    })?;
+
+    let invalid = |text: &str, idx: usize, ch: char, message: &str| {
+        ParseError::new(input, &text[idx..idx + ch.len_utf8()], message)
+    };
+
+    if let Some((idx, ch)) = input_map
+        .char_indices()
+        .find(|(_, ch)| !matches!(ch, '.' | '#' | '@' | 'O' | '\n'))
+    {
+        return Err(invalid(input_map, idx, ch, "expected ., #, @ or O"));
+    }
+
+    let moves = moves
+        .char_indices()
+        .filter(|(_, ch)| !ch.is_whitespace())
+        .map(|(idx, ch)| {
+            Move::try_from(ch).map_err(|_| invalid(moves, idx, ch, "expected ^, v, < or >"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((input_map, moves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (input_map, moves) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(input_map, &moves), 2028);
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::HashSet;
+
+use aoc_parse::{parse_at, ParseError};
+use rayon::prelude::*;
+use regex::Regex;
+
+#[derive(Debug)]
+enum Instruction {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl From<isize> for Instruction {
+    #[inline]
+    fn from(opcode: isize) -> Self {
+        match opcode {
+            0 => Self::Adv,
+            1 => Self::Bxl,
+            2 => Self::Bst,
+            3 => Self::Jnz,
+            4 => Self::Bxc,
+            5 => Self::Out,
+            6 => Self::Bdv,
+            7 => Self::Cdv,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+struct Registers {
+    a: isize,
+    b: isize,
+    c: isize,
+}
+
+impl Registers {
+    #[inline]
+    fn reset(&mut self, a: isize) {
+        self.a = a;
+        self.b = 0;
+        self.c = 0;
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Computer {
+    registers: Registers,
+    ip: usize,
+
+    output: Vec<isize>,
+
+    halt_checker: HashSet<(Registers, usize)>,
+}
+
+impl Computer {
+    #[inline]
+    fn reset(&mut self, a: isize) {
+        if a % 10_000_000 == 0 {
+            println!("resetting {}", a);
+        }
+
+        self.registers.reset(a);
+        self.ip = 0;
+
+        self.output.clear();
+        self.halt_checker.clear();
+    }
+
+    #[inline]
+    fn combo(&self, operand: isize) -> isize {
+        match operand {
+            0..=3 => operand,
+            4 => self.registers.a,
+            5 => self.registers.b,
+            6 => self.registers.c,
+            _ => unreachable!(),
+        }
+    }
+
+    fn execute(&mut self, instruction: Instruction, operand: isize) {
+        match instruction {
+            Instruction::Adv => self.registers.a /= 2_isize.pow(self.combo(operand) as u32),
+            Instruction::Bxl => self.registers.b ^= operand,
+            Instruction::Bst => self.registers.b = self.combo(operand) % 8,
+            Instruction::Jnz => {
+                if self.registers.a != 0 {
+                    self.ip = operand as usize;
+                }
+            }
+            Instruction::Bxc => self.registers.b ^= self.registers.c,
+            Instruction::Out => self.output.push(self.combo(operand) % 8),
+            Instruction::Bdv => {
+                self.registers.b = self.registers.a / 2_isize.pow(self.combo(operand) as u32)
+            }
+            Instruction::Cdv => {
+                self.registers.c = self.registers.a / 2_isize.pow(self.combo(operand) as u32)
+            }
+        }
+    }
+
+    fn step(&mut self, program: &[isize]) -> anyhow::Result<bool> {
+        if !self.halt_checker.insert((self.registers.clone(), self.ip)) {
+            anyhow::bail!("will not halt");
+        } else {
+            //println!("checker: {:?}", self.halt_checker);
+        }
+
+        if self.ip >= program.len() {
+            return Ok(false);
+        }
+
+        let instruction = program[self.ip].into();
+        self.ip += 1;
+
+        let operand = program[self.ip];
+        self.ip += 1;
+
+        self.execute(instruction, operand);
+
+        Ok(true)
+    }
+}
+
+pub fn part1(mut computer: Computer, program: &[isize]) -> String {
+    while computer.step(program).unwrap() {}
+
+    let output = computer
+        .output
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    println!("{}", output);
+
+    output
+}
+
+pub fn part2(program: &[isize]) {
+    let start = 10_908_900_000;
+    let end = 100_908_900_000;
+
+    (start..end).into_par_iter().for_each(|initial| {
+        let mut computer = Computer::default();
+        computer.reset(initial);
+
+        let halted = match computer.step(program) {
+            Ok(result) => !result,
+            Err(err) => {
+                println!("{}", err);
+                return;
+            }
+        };
+
+        for i in 0..computer.output.len() {
+            //println!("checking {}", i);
+            if i >= program.len() || computer.output[i] != program[i] {
+                /*if i >= program.len() {
+                    println!("invalid output len: {} vs {}", i, program.len());
+                } else {
+                    println!("invalid output: {} vs {}", computer.output[i], program[i]);
+                }*/
+                return;
+            }
+        }
+        //println!("out");
+
+        if halted && computer.output.len() == program.len() {
+            panic!("found it: {}", computer.registers.a);
+        }
+    });
+
+    /*loop {
+        let halted = match computer.step(program) {
+            Ok(result) => !result,
+            Err(err) => {
+                println!("{}", err);
+
+                initial += 1;
+                computer.reset(initial);
+                continue;
+            }
+        };
+
+        for i in 0..computer.output.len() {
+            //println!("checking {}", i);
+            if i >= program.len() || computer.output[i] != program[i] {
+                /*if i >= program.len() {
+                    println!("invalid output len: {} vs {}", i, program.len());
+                } else {
+                    println!("invalid output: {} vs {}", computer.output[i], program[i]);
+                }*/
+                initial += 1;
+                computer.reset(initial);
+                break;
+            }
+        }
+        //println!("out");
+
+        if halted && computer.output.len() == program.len() {
+            break;
+        }
+    }
+
+    println!("found it: {}", computer.registers.a);*/
+}
+
+// returns the initial computer state and the program to run
+pub fn parse(input: &str) -> Result<(Computer, Vec<isize>), ParseError> {
+    let (registers_input, program) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::truncated(
+            input,
+            "expected a blank line between the registers and the program",
+        )
+    })?;
+
+    let mut computer = Computer::default();
+    let mut registers = registers_input.split('\n');
+
+    let mut register = |name: &str| -> Result<isize, ParseError> {
+        let expected = format!("expected Register {}: N", name);
+        let line = registers
+            .next()
+            .ok_or_else(|| ParseError::truncated(registers_input, expected.as_str()))?;
+
+        let regex = Regex::new(&format!(r"Register {}: (?P<v>\d+)", name)).unwrap();
+        let caps: regex::Captures<'_> = regex
+            .captures(line)
+            .ok_or_else(|| ParseError::new(input, line, expected.as_str()))?;
+        parse_at(input, &caps["v"])
+    };
+    computer.registers.a = register("A")?;
+    computer.registers.b = register("B")?;
+    computer.registers.c = register("C")?;
+
+    let program_line = program.trim();
+    let (_, program) = program_line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(input, program_line, "expected Program: ..."))?;
+    // everything in the program is a 3 bit number
+    let program = program
+        .split(',')
+        .map(|v| {
+            let n = parse_at::<isize>(input, v)?;
+            if !(0..=7).contains(&n) {
+                return Err(ParseError::new(input, v, "expected a 3 bit number"));
+            }
+            Ok(n)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((computer, program))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // part 2 is still a brute force search
+    #[test]
+    fn example() {
+        let (computer, program) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(computer, &program), "4,6,3,5,6,3,5,2,1,0");
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::BTreeMap;
+
+#[derive(Debug)]
+struct TrieNode {
+    children: BTreeMap<char, Option<TrieNode>>,
+    value: Option<String>,
+    terminal: bool,
+}
+
+impl Default for TrieNode {
+    fn default() -> Self {
+        Self {
+            children: BTreeMap::from([
+                ('w', None),
+                ('u', None),
+                ('b', None),
+                ('r', None),
+                ('g', None),
+            ]),
+            value: None,
+            terminal: false,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Trie {
+    root: TrieNode,
+}
+
+impl Trie {
+    fn insert(&mut self, value: String) {
+        let mut c = &mut self.root;
+        for i in value.chars() {
+            if c.children[&i].is_none() {
+                *c.children.get_mut(&i).unwrap() = Some(TrieNode::default());
+            }
+            c = c.children.get_mut(&i).unwrap().as_mut().unwrap();
+        }
+
+        c.value = Some(value);
+        c.terminal = true;
+    }
+
+    // TODO: this is the basic search
+    // but we probably want to length limit and
+    // allow wrapping back to the root?
+    /*fn search(&self, key: &str) -> bool {
+        let mut c = &self.root;
+        for i in key.chars() {
+            if c.children[&i].is_none() {
+                return false;
+            }
+            c = c.children.get(&i).unwrap().as_ref().unwrap();
+        }
+        c.terminal
+    }*/
+}
+
+pub fn part1(patterns: &Trie, designs: &[&str]) -> usize {
+    println!("{:?}", patterns);
+    println!("{:?}", designs);
+
+    let possible = 0;
+    for _design in designs {
+        // TODO: do we traverse the Trie up len(design)
+        // allowing it to wrap if we terminal but aren't at the length?
+
+        // part1 just wants to know if the patterns are *possible*
+        // so really we could terminal substrings until we're at the end here?
+    }
+
+    possible
+}
+
+pub fn parse(input: &str) -> (Trie, Vec<&str>) {
+    let (patterns, designs) = input.split_once("\n\n").unwrap();
+
+    let mut patterns_trie = Trie::default();
+    patterns
+        .split(", ")
+        .for_each(|pattern| patterns_trie.insert(pattern.to_owned()));
+
+    let designs = designs.lines().collect::<Vec<_>>();
+
+    (patterns_trie, designs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "the designs aren't matched against the trie yet"]
+    fn example() {
+        let (patterns, designs) = parse(include_str!("../examples/example.txt"));
+
+        assert_eq!(part1(&patterns, &designs), 6);
+    }
+}
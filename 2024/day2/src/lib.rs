@@ -0,0 +1,130 @@
+use std::cmp::Ordering;
+
+use aoc_parse::{parse_at, ParseError};
+
+fn retry_is_safe(levels: &[isize], remove: usize) -> bool {
+    let mut levels = levels.to_owned();
+    levels.remove(remove);
+    is_safe(&levels, false)
+}
+
+fn retry_is_safe_naive(levels: &[isize]) -> bool {
+    for idx in 0..levels.len() {
+        if retry_is_safe(levels, idx) {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_safe(levels: &[isize], dampen: bool) -> bool {
+    match levels[0].cmp(&levels[1]) {
+        Ordering::Less => {
+            for window in levels.windows(2).enumerate() {
+                let a = window.1[0];
+                let b = window.1[1];
+
+                if a >= b {
+                    if dampen {
+                        return retry_is_safe_naive(levels);
+                    }
+                    return false;
+                }
+
+                if (a - b).abs() > 3 {
+                    if dampen {
+                        return retry_is_safe_naive(levels);
+                    }
+                    return false;
+                }
+            }
+            true
+        }
+        Ordering::Greater => {
+            for window in levels.windows(2).enumerate() {
+                let a = window.1[0];
+                let b = window.1[1];
+
+                if a <= b {
+                    if dampen {
+                        return retry_is_safe_naive(levels);
+                    }
+                    return false;
+                }
+
+                if (a - b).abs() > 3 {
+                    if dampen {
+                        return retry_is_safe_naive(levels);
+                    }
+                    return false;
+                }
+            }
+            true
+        }
+        Ordering::Equal => {
+            if dampen {
+                return retry_is_safe_naive(levels);
+            }
+            false
+        }
+    }
+}
+
+pub fn part1(reports: &[Vec<isize>]) -> usize {
+    let mut safe = 0;
+
+    for report in reports {
+        if is_safe(report, false) {
+            safe += 1;
+        }
+    }
+
+    println!("Safe undampened reports: {}", safe);
+
+    safe
+}
+
+pub fn part2(reports: &[Vec<isize>]) -> usize {
+    let mut safe = 0;
+
+    for report in reports {
+        if is_safe(report, true) {
+            safe += 1;
+        }
+    }
+
+    println!("Safe dampened reports: {}", safe);
+
+    safe
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<isize>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|value| parse_at(input, value))
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<isize>>, _>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let reports = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&reports), 2);
+        assert_eq!(part2(&reports), 4);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 l\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 9));
+        assert_eq!(err.text, "l");
+    }
+}
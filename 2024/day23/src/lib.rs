@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use aoc_graph::Graph;
+use aoc_parse::ParseError;
+
+type Network<'a> = Graph<'a>;
+
+// only counts each triangle once by requiring a < b < c (by name)
+fn find_triangles(network: &Network) -> Vec<[usize; 3]> {
+    let mut triangles = vec![];
+    let name = |v: &usize| network.name(*v);
+
+    for a in 0..network.len() {
+        for b in network.neighbors(a).filter(|b| name(b) > name(&a)) {
+            for c in network.neighbors(a).filter(|c| name(c) > name(&b)) {
+                if network.has_edge(b, c) {
+                    triangles.push([a, b, c]);
+                }
+            }
+        }
+    }
+
+    triangles
+}
+
+// bron-kerbosch with pivoting
+// r is the clique being built, p are candidates that could extend it,
+// and x are computers that have already been tried (to avoid repeating cliques)
+fn bron_kerbosch(
+    connections: &[HashSet<usize>],
+    r: &mut Vec<usize>,
+    mut p: HashSet<usize>,
+    mut x: HashSet<usize>,
+    largest: &mut Vec<usize>,
+) {
+    if p.is_empty() {
+        if x.is_empty() && r.len() > largest.len() {
+            *largest = r.clone();
+        }
+        return;
+    }
+
+    // can't possibly beat what we've already found
+    if r.len() + p.len() <= largest.len() {
+        return;
+    }
+
+    // any maximal clique has to include the pivot or one of its non-neighbors
+    let pivot = p
+        .union(&x)
+        .max_by_key(|v| connections[**v].intersection(&p).count())
+        .copied()
+        .unwrap();
+    let pivot_connections = &connections[pivot];
+
+    let candidates = p
+        .iter()
+        .filter(|v| !pivot_connections.contains(*v))
+        .copied()
+        .collect::<Vec<_>>();
+    for v in candidates {
+        let v_connections = &connections[v];
+
+        r.push(v);
+        bron_kerbosch(
+            connections,
+            r,
+            p.intersection(v_connections).copied().collect(),
+            x.intersection(v_connections).copied().collect(),
+            largest,
+        );
+        r.pop();
+
+        p.remove(&v);
+        x.insert(v);
+    }
+}
+
+fn find_largest_clique(network: &Network) -> Vec<usize> {
+    // the set operations want hash sets rather than edge lists
+    let connections = (0..network.len())
+        .map(|v| network.neighbors(v).collect::<HashSet<_>>())
+        .collect::<Vec<_>>();
+
+    let mut largest = vec![];
+    bron_kerbosch(
+        &connections,
+        &mut vec![],
+        (0..network.len()).collect(),
+        HashSet::new(),
+        &mut largest,
+    );
+    largest
+}
+
+pub fn part1(network: &Network) -> usize {
+    // count triangles that include a valid computer
+    let total = find_triangles(network)
+        .iter()
+        .filter(|triangle| {
+            triangle
+                .iter()
+                .any(|computer| network.name(*computer).starts_with('t'))
+        })
+        .count();
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(network: &Network) -> String {
+    let mut largest = find_largest_clique(network)
+        .into_iter()
+        .map(|computer| network.name(computer))
+        .collect::<Vec<_>>();
+    largest.sort_unstable();
+
+    let password = largest.join(",");
+
+    println!("Password: {}", password);
+
+    password
+}
+
+pub fn parse(input: &str) -> Result<Network<'_>, ParseError> {
+    let mut network = Network::undirected();
+    for line in input.lines() {
+        let Some(computers) = line.split_once('-') else {
+            return Err(ParseError::new(
+                input,
+                line,
+                "expected a connection like kh-tc",
+            ));
+        };
+        network.add_edge(computers.0, computers.1, ());
+    }
+
+    Ok(network)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let network = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&network), 7);
+        assert_eq!(part2(&network), "co,de,ka,ta");
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("kh-tc\nqp-kh\nde,cg\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "de,cg");
+    }
+}
@@ -0,0 +1,198 @@
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Token {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+// scans the raw bytes for instructions, skipping over everything else
+// yields each instruction along with the byte offset it starts at
+struct Scanner<'a> {
+    input: &'a [u8],
+    cur: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            cur: 0,
+        }
+    }
+
+    fn tag(&self, at: usize, tag: &[u8]) -> Option<usize> {
+        self.input[at..].starts_with(tag).then_some(at + tag.len())
+    }
+
+    // operands are 1-3 digits
+    fn number(&self, at: usize) -> Option<(usize, usize)> {
+        let len = self.input[at..]
+            .iter()
+            .take(3)
+            .take_while(|ch| ch.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return None;
+        }
+
+        let n = self.input[at..at + len]
+            .iter()
+            .fold(0, |acc, ch| acc * 10 + (ch - b'0') as usize);
+        Some((n, at + len))
+    }
+
+    fn mul(&self, at: usize) -> Option<(Token, usize)> {
+        let at = self.tag(at, b"mul(")?;
+        let (a, at) = self.number(at)?;
+        let at = self.tag(at, b",")?;
+        let (b, at) = self.number(at)?;
+        let at = self.tag(at, b")")?;
+        Some((Token::Mul(a, b), at))
+    }
+
+    fn conditional(&self, at: usize) -> Option<(Token, usize)> {
+        if let Some(at) = self.tag(at, b"do()") {
+            return Some((Token::Do, at));
+        }
+
+        let at = self.tag(at, b"don't()")?;
+        Some((Token::Dont, at))
+    }
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = (usize, Token);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.cur < self.input.len() {
+            let start = self.cur;
+
+            let token = match self.input[start] {
+                b'm' => self.mul(start),
+                b'd' => self.conditional(start),
+                _ => None,
+            };
+
+            if let Some((token, end)) = token {
+                self.cur = end;
+                return Some((start, token));
+            }
+
+            self.cur += 1;
+        }
+
+        None
+    }
+}
+
+pub fn part1(input: impl AsRef<str>) -> usize {
+    let sum = Scanner::new(input.as_ref()).fold(0, |acc, (_, token)| match token {
+        Token::Mul(a, b) => acc + a * b,
+        _ => acc,
+    });
+
+    println!("Total: {}", sum);
+
+    sum
+}
+
+pub fn part2(input: impl AsRef<str>) -> usize {
+    let (sum, _) =
+        Scanner::new(input.as_ref()).fold((0, true), |(acc, enabled), (_, token)| match token {
+            Token::Mul(a, b) if enabled => (acc + a * b, enabled),
+            Token::Mul(_, _) => (acc, enabled),
+            Token::Do => (acc, true),
+            Token::Dont => (acc, false),
+        });
+
+    println!("Total: {}", sum);
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use regex::Regex;
+
+    fn instruction_regex() -> Regex {
+        Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap()
+    }
+
+    fn regex_tokens(re: &Regex, input: &str) -> Vec<(usize, Token)> {
+        re.captures_iter(input)
+            .map(|caps| {
+                let m = caps.get(0).unwrap();
+                let token = match m.as_str() {
+                    "do()" => Token::Do,
+                    "don't()" => Token::Dont,
+                    _ => Token::Mul(caps[1].parse().unwrap(), caps[2].parse().unwrap()),
+                };
+                (m.start(), token)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(part1(include_str!("../examples/example.txt")), 161);
+
+        // part 2 has its own example
+        assert_eq!(part2(include_str!("../examples/example2.txt")), 48);
+    }
+
+    #[test]
+    fn examples() {
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(
+            Scanner::new(input).map(|(_, t)| t).collect::<Vec<_>>(),
+            vec![
+                Token::Mul(2, 4),
+                Token::Mul(5, 5),
+                Token::Mul(11, 8),
+                Token::Mul(8, 5)
+            ]
+        );
+
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(
+            Scanner::new(input).collect::<Vec<_>>(),
+            regex_tokens(&instruction_regex(), input)
+        );
+    }
+
+    // builds random garbage out of pieces that look a lot like instructions
+    #[test]
+    fn fuzz_against_regex() {
+        const PIECES: &[&str] = &[
+            "mul(", "mul", "mu", "m", "do()", "don't()", "don't", "do", "d", "(", ")", ",", "1",
+            "22", "333", "4444", "'", " ", "x", "\n", "ü",
+        ];
+
+        let re = instruction_regex();
+
+        // xorshift, good enough to not need a dependency
+        let mut state = 0x2545f4914f6cdd1d_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10000 {
+            let len = next() % 64;
+            let input = (0..len)
+                .map(|_| PIECES[(next() % PIECES.len() as u64) as usize])
+                .collect::<String>();
+
+            assert_eq!(
+                Scanner::new(&input).collect::<Vec<_>>(),
+                regex_tokens(&re, &input),
+                "{:?}",
+                input
+            );
+        }
+    }
+}
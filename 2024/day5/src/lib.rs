@@ -0,0 +1,171 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug, Default)]
+struct UpdateRule {
+    before: HashSet<usize>,
+    after: HashSet<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct UpdateRules {
+    rules: HashMap<usize, UpdateRule>,
+}
+
+impl UpdateRules {
+    fn sort(&self, update: &mut [usize]) {
+        update.sort_by(|a, b| {
+            let rule = self.rules.get(a).unwrap();
+
+            if rule.before.contains(b) {
+                return std::cmp::Ordering::Greater;
+            }
+
+            if rule.after.contains(b) {
+                return std::cmp::Ordering::Less;
+            }
+
+            std::cmp::Ordering::Equal
+        });
+    }
+}
+
+pub fn part1(rules: &UpdateRules, updates: &[Vec<usize>]) -> usize {
+    let mut total = 0;
+    for update in updates {
+        // TODO: not sure why but a loop label break in here isn't working
+        let mut fail = false;
+
+        for (idx, page) in update.iter().enumerate() {
+            let rule = rules.rules.get(page).unwrap();
+            for v in update.iter().skip(idx) {
+                if rule.before.contains(v) {
+                    fail = true;
+                    break;
+                }
+            }
+
+            if fail {
+                break;
+            }
+
+            for i in (idx..0).rev() {
+                if rule.after.contains(&update[i]) {
+                    fail = true;
+                    break;
+                }
+            }
+
+            if fail {
+                break;
+            }
+        }
+
+        if !fail {
+            let mid = update[update.len() / 2];
+            total += mid;
+        }
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(rules: &UpdateRules, mut updates: Vec<Vec<usize>>) -> usize {
+    let mut total = 0;
+    for update in updates.iter_mut() {
+        // TODO: not sure why but a loop label break in here isn't working
+        let mut fail = false;
+
+        for idx in 0..update.len() {
+            let page = update[idx];
+
+            let rule = rules.rules.get(&page).unwrap();
+            for i in idx..update.len() {
+                if rule.before.contains(&update[i]) {
+                    rules.sort(update);
+                    fail = true;
+                    break;
+                }
+            }
+
+            if fail {
+                break;
+            }
+
+            for i in (idx..0).rev() {
+                if rule.after.contains(&update[i]) {
+                    rules.sort(update);
+                    fail = true;
+                    break;
+                }
+            }
+
+            if fail {
+                break;
+            }
+        }
+
+        if fail {
+            let mid = update[update.len() / 2];
+            total += mid;
+        }
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<(UpdateRules, Vec<Vec<usize>>), ParseError> {
+    let sections = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::truncated(input, "expected a blank line before the updates"))?;
+
+    let mut rules = UpdateRules::default();
+    for line in sections.0.lines() {
+        let Some(pages) = line.split_once('|') else {
+            return Err(ParseError::new(input, line, "expected a rule like 47|53"));
+        };
+        let (a, b) = (parse_at(input, pages.0)?, parse_at(input, pages.1)?);
+
+        rules.rules.entry(a).or_default().after.insert(b);
+        rules.rules.entry(b).or_default().before.insert(a);
+    }
+
+    let updates = sections
+        .1
+        .lines()
+        .map(|line| line.split(',').map(|v| parse_at(input, v)).collect())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((rules, updates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (rules, updates) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&rules, &updates), 143);
+        assert_eq!(part2(&rules, updates), 123);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("47|53\n97-13\n\n75,47,61\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "97-13");
+
+        let err = parse("47|53\n\n75,47,,61\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 7));
+
+        let err = parse("47|53\n97|13\n").unwrap_err();
+        assert_eq!(err.message, "expected a blank line before the updates");
+    }
+}
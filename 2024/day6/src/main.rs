@@ -1,3 +1,15 @@
 fn main() {
     let _input = include_str!("../input.txt");
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[ignore = "part 1 isn't started yet"]
+    fn example() {
+        let _input = include_str!("../examples/example.txt");
+
+        // the guard visits 41 distinct positions
+        todo!();
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_math::concat;
+use aoc_parse::{parse_at, ParseError};
+
+#[derive(Debug)]
+pub struct Equation {
+    result: usize,
+    operands: Vec<usize>,
+}
+
+fn check(operations: &mut [usize], cur: usize, result: usize, part2: bool) -> bool {
+    if operations.len() == 1 {
+        let v = cur + operations[0];
+        if v == result {
+            return true;
+        }
+
+        let v = cur * operations[0];
+        if v == result {
+            return true;
+        }
+
+        if part2 {
+            return concat(cur, operations[0]).is_some_and(|v| v == result);
+        }
+
+        return false;
+    }
+
+    let c = cur + operations[0];
+    if check(&mut operations[1..], c, result, part2) {
+        return true;
+    }
+
+    if part2 {
+        // anything that overflows is way past the result anyway
+        if concat(cur, operations[0]).is_some_and(|c| check(&mut operations[1..], c, result, part2))
+        {
+            return true;
+        }
+    }
+
+    let c = cur * operations[0];
+    if check(&mut operations[1..], c, result, part2) {
+        return true;
+    }
+
+    if part2 {
+        // anything that overflows is way past the result anyway
+        if concat(cur, operations[0]).is_some_and(|c| check(&mut operations[1..], c, result, part2))
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub fn part1(equations: &mut [Equation]) -> usize {
+    let mut total = 0;
+    for equation in equations {
+        if check(&mut equation.operands, 0, equation.result, false) {
+            total += equation.result;
+        }
+    }
+
+    println!("total: {}", total);
+
+    total
+}
+
+pub fn part2(equations: &mut [Equation]) -> usize {
+    let mut total = 0;
+    for equation in equations {
+        if check(&mut equation.operands, 0, equation.result, true) {
+            total += equation.result;
+        }
+    }
+
+    println!("total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let Some(parts) = line.split_once(':') else {
+                return Err(ParseError::new(input, line, "expected result: operands"));
+            };
+            let result = parse_at(input, parts.0)?;
+            let operands = parts
+                .1
+                .split_ascii_whitespace()
+                .map(|x| parse_at(input, x))
+                .collect::<Result<_, _>>()?;
+            Ok(Equation { result, operands })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut equations = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&mut equations), 3749);
+        assert_eq!(part2(&mut equations), 11387);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("190: 10 19\n3267 81 40 27\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = parse("190: 10 19\n3267: 81 4O 27\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 10));
+        assert_eq!(err.text, "4O");
+    }
+}
@@ -0,0 +1,215 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use aoc_parse::ParseError;
+
+// a contiguous run of blocks
+#[derive(Debug, Copy, Clone)]
+struct Span {
+    id: usize,
+    start: usize,
+    len: usize,
+}
+
+#[derive(Debug)]
+pub struct Disk {
+    files: Vec<Span>,
+
+    // free spans bucketed by length (the diskmap only goes up to 9)
+    // each bucket is a min-heap of start positions so the leftmost is on top
+    free: [BinaryHeap<Reverse<usize>>; 10],
+
+    size: usize,
+}
+
+impl From<&[usize]> for Disk {
+    fn from(diskmap: &[usize]) -> Self {
+        let mut files = Vec::with_capacity(diskmap.len() / 2 + 1);
+        let mut free: [BinaryHeap<Reverse<usize>>; 10] = Default::default();
+
+        let mut start = 0;
+        for (id, v) in diskmap.chunks(2).enumerate() {
+            files.push(Span {
+                id,
+                start,
+                len: v[0],
+            });
+            start += v[0];
+
+            if v.len() > 1 {
+                if v[1] > 0 {
+                    free[v[1]].push(Reverse(start));
+                }
+                start += v[1];
+            }
+        }
+
+        Self {
+            files,
+            free,
+            size: start,
+        }
+    }
+}
+
+impl Disk {
+    pub fn blocks(&self) -> Vec<Option<usize>> {
+        let mut disk = vec![None; self.size];
+        for file in &self.files {
+            disk[file.start..file.start + file.len].fill(Some(file.id));
+        }
+        disk
+    }
+
+    // moves each file (highest id first) into the leftmost free span that fits it
+    // checking the top of each bucket that's big enough makes each move O(log n)
+    fn compact_files(&mut self) {
+        for file in self.files.iter_mut().rev() {
+            let Some((len, Reverse(start))) = (file.len..self.free.len())
+                .filter_map(|len| self.free[len].peek().map(|&start| (len, start)))
+                .min_by_key(|&(_, start)| start)
+            else {
+                continue;
+            };
+
+            // can't move to the right
+            if start >= file.start {
+                continue;
+            }
+
+            self.free[len].pop();
+            file.start = start;
+
+            // whatever is left of the free span goes back in its new bucket
+            // (the space the file left behind can't ever be used,
+            // everything that hasn't moved yet is to the left of it)
+            let remaining = len - file.len;
+            if remaining > 0 {
+                self.free[remaining].push(Reverse(start + file.len));
+            }
+        }
+    }
+}
+
+fn checksum_disk(disk: &[Option<usize>]) -> usize {
+    let mut checksum = 0;
+    for (idx, v) in disk.iter().enumerate() {
+        match v {
+            Some(id) => {
+                //print!("{}", id);
+                checksum += id * idx;
+                //println!("{} * {}, {}", id, idx, checksum);
+            }
+            None => {
+                //print!(".");
+            }
+        }
+    }
+    //println!();
+
+    //println!("disk checksum: {}", checksum);
+    checksum
+}
+
+fn checksum_diskmap(files: &[Span]) -> usize {
+    // each file contributes id * (start + ... + start + len - 1)
+    let checksum = files
+        .iter()
+        .map(|file| file.id * (2 * file.start + file.len.saturating_sub(1)) * file.len / 2)
+        .sum();
+
+    //println!("diskmap checksum: {}", checksum);
+    checksum
+}
+
+pub fn part1(mut disk: Vec<Option<usize>>) -> usize {
+    // head starts at the first empty block
+    let mut head = 0;
+    while disk[head].is_some() {
+        head += 1;
+    }
+
+    // tail starts at the last non-empty block
+    let mut tail = disk.len() - 1;
+    while disk[tail].is_none() {
+        tail -= 1;
+    }
+
+    loop {
+        if head >= tail {
+            break;
+        }
+
+        // move the tail to the head
+        disk[head] = disk[tail];
+        disk[tail] = None;
+
+        // update the head / tail
+        while disk[head].is_some() {
+            head += 1;
+        }
+        while disk[tail].is_none() {
+            tail -= 1;
+        }
+    }
+
+    let total = checksum_disk(&disk);
+    println!("Part 1: {}", total);
+
+    total
+}
+
+pub fn part2(mut disk: Disk) -> usize {
+    disk.compact_files();
+
+    let total = checksum_diskmap(&disk.files);
+    assert!(total == checksum_disk(&disk.blocks()));
+
+    println!("Part 2: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Disk, ParseError> {
+    let diskmap = input
+        .trim_ascii()
+        .char_indices()
+        .map(|(idx, ch)| match ch.to_digit(10) {
+            Some(v) => Ok(v as usize),
+            None => Err(ParseError::new(
+                input,
+                &input.trim_ascii()[idx..idx + ch.len_utf8()],
+                "expected a digit",
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Disk::from(diskmap.as_slice()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let disk = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(disk.blocks()), 1928);
+        assert_eq!(part2(disk), 2858);
+    }
+
+    #[test]
+    fn checksums_agree() {
+        // the diskmap and block checksums should match before any compacting
+        let disk = parse(include_str!("../examples/example.txt")).unwrap();
+        assert_eq!(checksum_diskmap(&disk.files), checksum_disk(&disk.blocks()));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("2333133121414l31402\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 14));
+        assert_eq!(err.text, "l");
+    }
+}
@@ -0,0 +1,193 @@
+//! Times parsing, part 1 and part 2 for every day
+//!
+//! `cargo bench -p bench` runs everything, `cargo bench -p bench -- day7/` just the one day.
+//! `-- --save-baseline before` keeps the numbers around and `-- --baseline before` compares against them.
+
+use std::hint::black_box;
+use std::io::Write;
+
+use criterion::measurement::WallTime;
+use criterion::{BatchSize, Bencher, BenchmarkGroup, Criterion, criterion_group};
+use gag::Gag;
+
+// the days print their answers (and sometimes whole grids) as they go,
+// that's just noise in a bench so stdout is gagged while a day is running
+fn quiet<T>(f: impl FnOnce() -> T) -> T {
+    // only one gag can be open at a time, if that fails it's just noisy
+    let gag = Gag::stdout().ok();
+    let v = f();
+    std::io::stdout().flush().unwrap();
+    drop(gag);
+    v
+}
+
+// criterion prints its results after the routine is done, so those still show up
+struct QuietGroup<'a>(BenchmarkGroup<'a, WallTime>);
+
+impl QuietGroup<'_> {
+    fn sample_size(&mut self, n: usize) -> &mut Self {
+        self.0.sample_size(n);
+        self
+    }
+
+    fn bench_function<F: FnMut(&mut Bencher)>(&mut self, id: &str, mut f: F) -> &mut Self {
+        self.0.bench_function(id, |b| quiet(|| f(b)));
+        self
+    }
+
+    fn finish(self) {
+        self.0.finish();
+    }
+}
+
+// every day is a library crate, this brings its parse and parts into a module to bench them
+macro_rules! day {
+    ($day:ident, |$group:ident, $input:ident| $body:block) => {
+        // not every day needs batching
+        #[allow(unused_imports)]
+        mod $day {
+            use super::{BatchSize, QuietGroup, black_box, quiet};
+            use ::$day::*;
+
+            pub fn bench(c: &mut criterion::Criterion) {
+                let $input = include_str!(concat!("../../", stringify!($day), "/input.txt"));
+
+                let mut $group = QuietGroup(c.benchmark_group(stringify!($day)));
+                $group.sample_size(10);
+                $body
+                $group.finish();
+            }
+        }
+    };
+}
+
+day!(day1, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let values = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| optimized_part1(black_box(&values))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&values))));
+
+    // see if the accumulator actually buys anything
+    group.bench_function("unoptimized_part1", |b| {
+        b.iter(|| part1(black_box(&values)))
+    });
+});
+
+day!(day2, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let ranges = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&ranges))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&ranges))));
+});
+
+day!(day3, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let battery_banks = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&battery_banks))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&battery_banks))));
+});
+
+day!(day4, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let grid = parse(input);
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&grid))));
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| grid.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day5, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (fresh_id_ranges, available_ids) = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter(|| part1(black_box(&fresh_id_ranges), &available_ids))
+    });
+    group.bench_function("part2", |b| {
+        b.iter_batched(|| fresh_id_ranges.clone(), part2, BatchSize::SmallInput)
+    });
+});
+
+day!(day6, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let matrix = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&matrix))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&matrix))));
+});
+
+day!(day7, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let (manifold, start) = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&manifold), start)));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&manifold), start)));
+});
+
+day!(day8, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let boxes = parse(input).unwrap();
+    group.bench_function("part1", |b| {
+        b.iter_batched(
+            || boxes.clone(),
+            |boxes| part1(boxes, 1000),
+            BatchSize::SmallInput,
+        )
+    });
+});
+
+day!(day9, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let tiles = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&tiles))));
+});
+
+day!(day10, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    // part 2 goes through z3 unless the bench is built with --no-default-features
+    let machines = parse(input).unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&machines))));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&machines))));
+});
+
+day!(day11, |group, input| {
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+
+    let devices = parse(input).unwrap();
+    let order = devices.topological_sort().unwrap();
+    group.bench_function("part1", |b| b.iter(|| part1(black_box(&devices), &order)));
+    group.bench_function("part2", |b| b.iter(|| part2(black_box(&devices), &order)));
+});
+
+criterion_group!(
+    benches,
+    day1::bench,
+    day2::bench,
+    day3::bench,
+    day4::bench,
+    day5::bench,
+    day6::bench,
+    day7::bench,
+    day8::bench,
+    day9::bench,
+    day10::bench,
+    day11::bench,
+);
+
+fn main() {
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+
+    aoc_bench::print_summary(aoc_bench::criterion_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/.."
+    )));
+}
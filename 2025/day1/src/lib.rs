@@ -0,0 +1,124 @@
+use aoc_parse::{ParseError, parse_at};
+
+#[inline]
+fn wrap_mod(a: i32, b: i32) -> i32 {
+    //((a % b) + b) % b
+    a.rem_euclid(b)
+}
+
+#[allow(dead_code)]
+pub fn part1(rotations: impl AsRef<[i32]>) -> i32 {
+    let rotations = rotations.as_ref();
+
+    let mut zero_count = 0;
+    let mut value = 50;
+    for rotation in rotations {
+        value = wrap_mod(value + rotation, 100);
+        if value == 0 {
+            zero_count += 1;
+        }
+    }
+
+    println!("Zero stop count: {}", zero_count);
+
+    zero_count
+}
+
+// not sure how much more optimized this actually is
+// but the idea of using an accumulator is neat
+pub fn optimized_part1(rotations: impl AsRef<[i32]>) -> i32 {
+    let rotations = rotations.as_ref();
+
+    let mut zero_count = 0;
+    let mut accum = 50_i64;
+
+    for rotation in rotations {
+        accum += *rotation as i64;
+        if accum % 100 == 0 {
+            zero_count += 1;
+        }
+    }
+
+    println!("Optimized Zero stop count: {}", zero_count);
+
+    zero_count
+}
+
+// TODO: I don't like this and I wonder if there's a way to do it with an accumulator
+// the upside is, this is avoiding arithmetic simulation, so that's good
+pub fn part2(rotations: impl AsRef<[i32]>) -> i32 {
+    let rotations = rotations.as_ref();
+
+    let mut zero_count = 0;
+    let mut value = 50;
+
+    for rotation in rotations {
+        // we might wrap 0 a few times before landing on the final value
+        let passes = (rotation / 100).abs();
+        zero_count += passes;
+
+        let prev = value;
+
+        value = wrap_mod(value + rotation, 100);
+        if value == 0 {
+            zero_count += 1;
+        } else if *rotation < 0 {
+            // if we landed on the opposite side, we crossed zero
+            if prev != 0 && value > prev {
+                zero_count += 1;
+            }
+        } else if *rotation > 0 {
+            // if we landed on the opposite side, we crossed zero
+            if prev != 0 && value < prev {
+                zero_count += 1;
+            }
+        }
+    }
+
+    println!("Zero click count: {}", zero_count);
+
+    zero_count
+}
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let digits = |v: &str| v.starts_with(|ch: char| ch.is_ascii_digit());
+            match line.split_at_checked(1) {
+                Some(("R", v)) if digits(v) => parse_at(input, v),
+                Some(("L", v)) if digits(v) => parse_at(input, v).map(|v: i32| -v),
+                _ => Err(ParseError::new(
+                    input,
+                    line,
+                    "expected a rotation like L68 or R48",
+                )),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let values = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&values), 3);
+        assert_eq!(optimized_part1(&values), 3);
+        assert_eq!(part2(&values), 6);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("L68\nL30\nU48\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "U48");
+
+        let err = parse("L68\nR4x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, "4x");
+    }
+}
@@ -0,0 +1,391 @@
+use std::str::FromStr;
+
+use aoc_math::gcd_signed;
+use aoc_parse::{ParseError, parse_at};
+use itertools::Itertools;
+
+// lots of help on this from https://www.reddit.com/r/adventofcode/comments/1pity70/2025_day_10_solutions/
+
+#[derive(Debug)]
+pub struct MachineDesc {
+    // ignored for part 2
+    // machine starts when indicator lights match this
+    indicator_lights: usize,
+
+    // each button is an entry in the outter vec
+    // each button is indexes into the lights it toggles
+    button_wirings: Vec<Vec<usize>>,
+    button_values: Vec<usize>, // the buttons as an int
+
+    // ignored for part 1
+    // minimization constraint
+    joltage_reqs: Vec<usize>,
+}
+
+pub fn part1(machines: impl AsRef<[MachineDesc]>) -> usize {
+    let machines = machines.as_ref();
+
+    /*
+    the buttons can be pressed in any order and the result will be the same
+    pressing a button twice is the same as never pressing it
+    therefore only need to consider pressing each button at most once
+    if we have n buttons then that's 2 ^ n combos (we either press each button or we don't)
+    this is basically just the power set
+    */
+
+    let mut total = 0;
+
+    for machine in machines {
+        let count = machine
+            .button_values
+            .iter()
+            .powerset()
+            .filter_map(|pressed| {
+                let on = pressed.iter().fold(0, |acc, &n| acc ^ n);
+                if on == machine.indicator_lights {
+                    Some(pressed.len())
+                } else {
+                    None
+                }
+            })
+            .min()
+            .unwrap();
+
+        total += count;
+    }
+
+    println!("Total: {}", total);
+
+    total
+}
+
+// each button is pressed some non-negative number of times
+// every counter has to exactly hit its joltage requirement
+// so this is A * x = b where A[counter][button] is 1 if the button is wired to the counter
+// and we want the x >= 0 with the smallest sum (an integer linear program)
+// (none if there's no way to hit the requirements)
+#[cfg(feature = "z3")]
+fn min_joltage_presses(machine: &MachineDesc) -> Option<usize> {
+    use z3::ast::Int;
+    use z3::{Optimize, SatResult};
+
+    let opt = Optimize::new();
+
+    let presses = (0..machine.button_wirings.len())
+        .map(|i| Int::new_const(format!("press{i}")))
+        .collect::<Vec<_>>();
+    for press in &presses {
+        opt.assert(&press.ge(Int::from_u64(0)));
+    }
+
+    for (counter, &req) in machine.joltage_reqs.iter().enumerate() {
+        let wired = machine
+            .button_wirings
+            .iter()
+            .zip(presses.iter())
+            .filter(|(wiring, _)| wiring.contains(&counter))
+            .map(|(_, press)| press)
+            .collect::<Vec<_>>();
+        opt.assert(&Int::add(&wired).eq(Int::from_u64(req as u64)));
+    }
+
+    let total = Int::add(&presses.iter().collect::<Vec<_>>());
+    opt.minimize(&total);
+
+    if opt.check(&[]) != SatResult::Sat {
+        return None;
+    }
+    let model = opt.get_model()?;
+    model
+        .eval(&total, true)?
+        .as_u64()
+        .map(|total| total as usize)
+}
+
+#[cfg(not(feature = "z3"))]
+fn min_joltage_presses(machine: &MachineDesc) -> Option<usize> {
+    min_joltage_presses_elimination(machine)
+}
+
+// pure rust fallback for when z3 isn't available
+// gaussian elimination (fraction free so we can stay in integers) reduces the system
+// down to a handful of free buttons, then we search over every bounded value of those
+// and back substitute to get the pivot buttons
+#[cfg_attr(feature = "z3", allow(dead_code))]
+fn min_joltage_presses_elimination(machine: &MachineDesc) -> Option<usize> {
+    let buttons = machine.button_wirings.len();
+    let counters = machine.joltage_reqs.len();
+
+    // augmented matrix, the last column is the joltage requirement
+    let mut matrix = (0..counters)
+        .map(|counter| {
+            let mut row = machine
+                .button_wirings
+                .iter()
+                .map(|wiring| wiring.contains(&counter) as i64)
+                .collect::<Vec<_>>();
+            row.push(machine.joltage_reqs[counter] as i64);
+            row
+        })
+        .collect::<Vec<_>>();
+
+    // reduced row echelon form, remembering which button each pivot row solves for
+    let mut pivots = vec![];
+    let mut row = 0;
+    for col in 0..buttons {
+        let Some(pivot) = (row..counters).find(|&r| matrix[r][col] != 0) else {
+            continue;
+        };
+        matrix.swap(row, pivot);
+
+        for r in 0..counters {
+            if r == row || matrix[r][col] == 0 {
+                continue;
+            }
+
+            let a = matrix[row][col];
+            let b = matrix[r][col];
+            let pivot_row = matrix[row].clone();
+            matrix[r]
+                .iter_mut()
+                .zip(pivot_row)
+                .for_each(|(v, p)| *v = *v * a - p * b);
+
+            // keep the numbers small
+            let g = matrix[r].iter().try_fold(0, |acc, &v| gcd_signed(acc, v));
+            if let Some(g) = g.filter(|g| *g > 1) {
+                matrix[r].iter_mut().for_each(|v| *v /= g);
+            }
+        }
+
+        pivots.push(col);
+        row += 1;
+    }
+
+    // any leftover 0 = b rows mean there's no solution at all
+    if matrix[row..].iter().any(|r| r[buttons] != 0) {
+        return None;
+    }
+
+    let free = (0..buttons)
+        .filter(|b| !pivots.contains(b))
+        .collect::<Vec<_>>();
+
+    // a button can never be pressed more than the smallest requirement it feeds
+    let bounds = free
+        .iter()
+        .map(|&b| {
+            machine.button_wirings[b]
+                .iter()
+                .map(|&counter| machine.joltage_reqs[counter])
+                .min()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let mut best = None;
+    let mut values = vec![0; free.len()];
+    search_free_presses(
+        &matrix[..row],
+        &pivots,
+        &free,
+        &bounds,
+        &mut values,
+        0,
+        &mut best,
+    );
+    best
+}
+
+fn search_free_presses(
+    matrix: &[Vec<i64>],
+    pivots: &[usize],
+    free: &[usize],
+    bounds: &[usize],
+    values: &mut Vec<usize>,
+    idx: usize,
+    best: &mut Option<usize>,
+) {
+    let free_total = values[..idx].iter().sum::<usize>();
+    if best.is_some_and(|best| free_total >= best) {
+        return;
+    }
+
+    if idx < free.len() {
+        for v in 0..=bounds[idx] {
+            values[idx] = v;
+            search_free_presses(matrix, pivots, free, bounds, values, idx + 1, best);
+        }
+        values[idx] = 0;
+        return;
+    }
+
+    // back substitute the pivot buttons
+    let mut total = free_total;
+    for (row, &col) in matrix.iter().zip(pivots.iter()) {
+        let buttons = row.len() - 1;
+        let rhs = row[buttons]
+            - free
+                .iter()
+                .zip(values.iter())
+                .map(|(&b, &v)| row[b] * v as i64)
+                .sum::<i64>();
+
+        // has to be a non-negative whole number of presses
+        let pivot = row[col];
+        if rhs % pivot != 0 || rhs / pivot < 0 {
+            return;
+        }
+        total += (rhs / pivot) as usize;
+    }
+
+    if best.is_none_or(|best| total < best) {
+        *best = Some(total);
+    }
+}
+
+pub fn part2(machines: impl AsRef<[MachineDesc]>) -> Result<usize, String> {
+    let machines = machines.as_ref();
+
+    let mut total = 0;
+    for (idx, machine) in machines.iter().enumerate() {
+        total += min_joltage_presses(machine)
+            .ok_or_else(|| format!("machine {} can't reach its joltage requirements", idx + 1))?;
+    }
+
+    println!("Total: {}", total);
+
+    Ok(total)
+}
+
+impl FromStr for MachineDesc {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split_ascii_whitespace();
+
+        let lights = parts
+            .next()
+            .ok_or_else(|| ParseError::truncated(value, "expected indicator lights"))?;
+        let indicator_lights = lights
+            .strip_prefix('[')
+            .and_then(|lights| lights.strip_suffix(']'))
+            .ok_or_else(|| ParseError::new(value, lights, "expected indicator lights like [.##.]"))?
+            .chars()
+            .rev() // reverse the light order (make them little endian)
+            .try_fold(0, |acc, ch| match ch {
+                '#' => Ok((acc << 1) | 1),
+                '.' => Ok(acc << 1),
+                _ => Err(ParseError::new(
+                    value,
+                    lights,
+                    "indicator lights can only be . or #",
+                )),
+            })?;
+
+        let mut button_wirings = vec![];
+        let mut button_values = vec![];
+        let mut joltage_reqs = vec![];
+        for part in parts {
+            if let Some(wiring) = part.strip_prefix('(').and_then(|p| p.strip_suffix(')')) {
+                let mut button_value = 0;
+                let button_wiring = wiring
+                    .split(",")
+                    .map(|s| {
+                        let v = parse_at::<usize>(value, s)?;
+                        if v >= usize::BITS as usize {
+                            return Err(ParseError::new(value, s, "not that many lights"));
+                        }
+                        button_value |= 1 << v as u32;
+                        Ok(v)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                button_wirings.push(button_wiring);
+                button_values.push(button_value);
+            } else if let Some(reqs) = part.strip_prefix('{').and_then(|p| p.strip_suffix('}')) {
+                for s in reqs.split(",") {
+                    joltage_reqs.push(parse_at::<usize>(value, s)?);
+                }
+            } else {
+                return Err(ParseError::new(
+                    value,
+                    part,
+                    "expected a button like (1,3) or joltages like {3,5,4,7}",
+                ));
+            }
+        }
+
+        Ok(Self {
+            indicator_lights,
+            button_wirings,
+            button_values,
+            joltage_reqs,
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<MachineDesc>, ParseError> {
+    aoc_parse::lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn example() {
+        let machines = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&machines), 7);
+        assert_eq!(part2(&machines), Ok(33));
+    }
+
+    #[test]
+    fn elimination_example() {
+        let presses = parse(EXAMPLE)
+            .unwrap()
+            .iter()
+            .map(min_joltage_presses_elimination)
+            .collect::<Vec<_>>();
+        assert_eq!(presses, vec![Some(10), Some(12), Some(11)]);
+    }
+
+    #[test]
+    fn solver_matches_elimination() {
+        for machine in parse(EXAMPLE).unwrap() {
+            assert_eq!(
+                min_joltage_presses(&machine),
+                min_joltage_presses_elimination(&machine)
+            );
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("[.##.] (3) (1,3) {3,5,4,7}\n[...#.] (0,x) {7,5}").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 12));
+        assert_eq!(err.text, "x");
+
+        let err = parse("[.##.] (3) (1,3) 3,5,4,7").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 18));
+    }
+
+    #[test]
+    fn unsolvable() {
+        // nothing is wired to the second counter
+        let machines = parse("[.#] (0,1) {1,1}\n[.#] (0) {1,2}").unwrap();
+        assert_eq!(min_joltage_presses_elimination(&machines[1]), None);
+        assert_eq!(min_joltage_presses(&machines[1]), None);
+        assert_eq!(
+            part2(&machines),
+            Err("machine 2 can't reach its joltage requirements".to_owned())
+        );
+
+        // solvable, but only by pressing a button -1 times
+        let machine = "[.#] (0,1) (0) {1,2}".parse::<MachineDesc>().unwrap();
+        assert_eq!(min_joltage_presses_elimination(&machine), None);
+        assert_eq!(min_joltage_presses(&machine), None);
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::BTreeSet;
+
+use aoc_graph::Graph;
+use aoc_parse::ParseError;
+
+type Devices<'a> = Graph<'a>;
+
+// number of paths from node to the target
+// (only safe to call once the graph is known to be acyclic)
+fn count_paths(
+    devices: &Devices,
+    node: usize,
+    target: usize,
+    cache: &mut [Option<usize>],
+) -> usize {
+    if node == target {
+        return 1;
+    }
+
+    if let Some(paths) = cache[node] {
+        return paths;
+    }
+
+    let paths = devices
+        .neighbors(node)
+        .map(|connection| count_paths(devices, connection, target, cache))
+        .sum();
+
+    cache[node] = Some(paths);
+    paths
+}
+
+// in a dag the required devices can only ever be visited in topological order
+// so the paths through all of them is the product of the paths between each one
+fn count_paths_through(
+    devices: &Devices,
+    order: &[usize],
+    start: &str,
+    end: &str,
+    required: &[&str],
+) -> usize {
+    let mut position = vec![0; devices.len()];
+    for (idx, node) in order.iter().enumerate() {
+        position[*node] = idx;
+    }
+
+    let mut waypoints = required
+        .iter()
+        .map(|name| devices.index_of(name).unwrap())
+        .collect::<Vec<_>>();
+    waypoints.sort_unstable_by_key(|node| position[*node]);
+
+    let mut stops = vec![devices.index_of(start).unwrap()];
+    stops.extend(waypoints);
+    stops.push(devices.index_of(end).unwrap());
+
+    stops
+        .windows(2)
+        .map(|segment| {
+            count_paths(
+                devices,
+                segment[0],
+                segment[1],
+                &mut vec![None; devices.len()],
+            )
+        })
+        .product()
+}
+
+pub fn part1(devices: &Devices, order: &[usize]) -> usize {
+    let paths = count_paths_through(devices, order, "you", "out", &[]);
+
+    println!("Paths: {}", paths);
+
+    paths
+}
+
+pub fn part2(devices: &Devices, order: &[usize]) -> usize {
+    let paths = count_paths_through(devices, order, "svr", "out", &["dac", "fft"]);
+
+    println!("Paths: {}", paths);
+
+    paths
+}
+
+pub fn parse(input: &str) -> Result<Devices<'_>, ParseError> {
+    let mut devices = Devices::directed();
+    for line in input.lines() {
+        let Some((node, connections)) = line.split_once(":") else {
+            return Err(ParseError::new(input, line, "expected device: outputs"));
+        };
+        let node = node.trim();
+        devices.add_node(node);
+        // an output listed twice is still only the one connection
+        let connections = connections
+            .split_ascii_whitespace()
+            .collect::<BTreeSet<_>>();
+        for connection in connections {
+            devices.add_edge(node, connection, ());
+        }
+    }
+
+    Ok(devices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let devices = parse(include_str!("../examples/example.txt")).unwrap();
+        let order = devices.topological_sort().unwrap();
+
+        assert_eq!(part1(&devices, &order), 5);
+    }
+
+    // part 2 has its own example
+    #[test]
+    fn example2() {
+        let devices = parse(include_str!("../examples/example2.txt")).unwrap();
+        let order = devices.topological_sort().unwrap();
+
+        assert_eq!(part2(&devices, &order), 2);
+    }
+
+    #[test]
+    fn repeated_outputs() {
+        let devices = parse("you: out out\n").unwrap();
+        let order = devices.topological_sort().unwrap();
+
+        assert_eq!(part1(&devices, &order), 1);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("you: bbb ccc\nbbb ddd\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "bbb ddd");
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use aoc_math::count_digits;
+use aoc_parse::{ParseError, parse_at};
+
+// an id made up of a block of digits repeated n times is
+// the block multiplied by 1 + 10^k + 10^2k + ... (for a k digit block)
+// so rather than checking every id in a range we can generate
+// just the blocks that land inside of it
+fn repeated_blocks(
+    range: &RangeInclusive<usize>,
+    block_digits: u32,
+    repeats: u32,
+) -> impl Iterator<Item = usize> {
+    let multiplier = (0..repeats)
+        .map(|i| 10_usize.pow(block_digits * i))
+        .sum::<usize>();
+
+    let min_block = 10_usize
+        .pow(block_digits - 1)
+        .max(range.start().div_ceil(multiplier));
+    let max_block = (10_usize.pow(block_digits) - 1).min(range.end() / multiplier);
+
+    (min_block..=max_block).map(move |block| block * multiplier)
+}
+
+fn invalid_ids(ranges: &[RangeInclusive<usize>], only_twice: bool) -> HashSet<usize> {
+    // a set because some ids can be built more than one way (111111 is 1x6, 11x3 and 111x2)
+    let mut ids = HashSet::new();
+
+    for range in ranges {
+        for digits in count_digits(*range.start())..=count_digits(*range.end()) {
+            for repeats in 2..=digits {
+                if digits % repeats != 0 || (only_twice && repeats != 2) {
+                    continue;
+                }
+
+                ids.extend(repeated_blocks(range, digits / repeats, repeats));
+            }
+        }
+    }
+
+    ids
+}
+
+pub fn part1(ranges: impl AsRef<[RangeInclusive<usize>]>) -> usize {
+    let total: usize = invalid_ids(ranges.as_ref(), true).iter().sum();
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(ranges: impl AsRef<[RangeInclusive<usize>]>) -> usize {
+    let total: usize = invalid_ids(ranges.as_ref(), false).iter().sum();
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<RangeInclusive<usize>>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|range| {
+            let Some(v) = range.split_once('-') else {
+                return Err(ParseError::new(input, range, "expected a range like 11-22"));
+            };
+            let start = parse_at::<usize>(input, v.0)?;
+            let end = parse_at::<usize>(input, v.1)?;
+            Ok(start..=end)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let ranges = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&ranges), 1227775554);
+        assert_eq!(part2(&ranges), 4174379265);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("11-22,95-115,998-l012\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 18));
+        assert_eq!(err.text, "l012");
+
+        let err = parse("11-22,95\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_parse::ParseError;
+use aoc_parse::combinators::{grid, parse_all};
+
+fn turn_on_batteries(battery_banks: impl AsRef<[Vec<u32>]>, battery_count: usize) -> u64 {
+    battery_banks
+        .as_ref()
+        .iter()
+        .map(|battery_bank| {
+            let v = battery_bank.windows(battery_count).fold(
+                vec![0; battery_count],
+                |mut batteries, window| {
+                    let start = battery_count - window.len();
+                    for idx in start..window.len() {
+                        if window[idx] > batteries[idx] {
+                            batteries[idx..window.len()].copy_from_slice(&window[idx..]);
+                            break;
+                        }
+                    }
+
+                    batteries
+                },
+            );
+
+            let mut joltage = 0;
+            for (x, battery) in v.iter().enumerate().take(battery_count) {
+                joltage += *battery as u64 * 10_u64.pow((battery_count - x - 1) as u32);
+            }
+
+            joltage
+        })
+        .sum()
+}
+
+pub fn part1(battery_banks: impl AsRef<[Vec<u32>]>) -> u64 {
+    let total = turn_on_batteries(battery_banks, 2);
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn part2(battery_banks: impl AsRef<[Vec<u32>]>) -> u64 {
+    let total = turn_on_batteries(battery_banks, 12);
+
+    println!("Total: {}", total);
+
+    total
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse_all(input, grid(|ch| ch.to_digit(10)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let battery_banks = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&battery_banks), 357);
+        assert_eq!(part2(&battery_banks), 3121910778619);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("987654321111111\n81111111111111O\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 15));
+        assert_eq!(err.message, "expected a grid cell");
+    }
+}
@@ -0,0 +1,119 @@
+use std::ops::RangeInclusive;
+
+use aoc_parse::{ParseError, parse_at};
+
+pub fn part1(
+    fresh_id_ranges: impl AsRef<[RangeInclusive<usize>]>,
+    available_ids: impl AsRef<[usize]>,
+) -> usize {
+    let fresh_id_ranges = fresh_id_ranges.as_ref();
+    let available_ids = available_ids.as_ref();
+
+    let fresh_count = available_ids
+        .iter()
+        .filter(|available_id| {
+            fresh_id_ranges
+                .iter()
+                .any(|range| range.contains(available_id))
+        })
+        .count();
+
+    println!("Fresh count: {}", fresh_count);
+
+    fresh_count
+}
+
+fn combine(fresh_id_ranges: &mut Vec<RangeInclusive<usize>>) -> bool {
+    for i in 0..fresh_id_ranges.len() - 1 {
+        for j in i + 1..fresh_id_ranges.len() {
+            let b = fresh_id_ranges[j].clone();
+            let a = fresh_id_ranges.get_mut(i).unwrap();
+
+            // ranges don't overlap
+            if a.start() > b.end() || b.start() > a.end() {
+                continue;
+            }
+
+            // ranges do overlap
+            let start = a.start().min(b.start());
+            let end = a.end().max(b.end());
+
+            *a = *start..=*end;
+            fresh_id_ranges.swap_remove(j);
+            return true;
+        }
+    }
+
+    false
+}
+
+pub fn part2(mut fresh_id_ranges: Vec<RangeInclusive<usize>>) -> usize {
+    while combine(&mut fresh_id_ranges) {}
+
+    let total: usize = fresh_id_ranges
+        .iter()
+        .map(|range| range.end() - range.start() + 1)
+        .sum();
+
+    println!("Total: {}", total);
+
+    total
+}
+
+// returns the fresh id ranges and the available ids
+type Inventory = (Vec<RangeInclusive<usize>>, Vec<usize>);
+
+pub fn parse(input: &str) -> Result<Inventory, ParseError> {
+    let (fresh_id_ranges, available_ids) = input.trim().split_once("\n\n").ok_or_else(|| {
+        ParseError::truncated(input, "expected a blank line before the available ids")
+    })?;
+
+    let fresh_id_ranges = fresh_id_ranges
+        .lines()
+        .map(|line| {
+            let Some((start, end)) = line.split_once('-') else {
+                return Err(ParseError::new(input, line, "expected a range like 3-5"));
+            };
+            let start = parse_at::<usize>(input, start)?;
+            let end = parse_at::<usize>(input, end)?;
+            Ok(start..=end)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let available_ids = available_ids
+        .lines()
+        .map(|line| parse_at::<usize>(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((fresh_id_ranges, available_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (fresh_id_ranges, available_ids) =
+            parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&fresh_id_ranges, &available_ids), 3);
+        assert_eq!(part2(fresh_id_ranges), 14);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("3-5\n10 14\n\n1\n5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "10 14");
+
+        let err = parse("3-5\n10-14\n\n1\nfive\n").unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+
+        let err = parse("3-5\n10-14\n").unwrap_err();
+        assert_eq!(
+            err.message,
+            "expected a blank line before the available ids"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use aoc_parse::ParseError;
+
+#[derive(Debug, Default, Clone)]
+struct Problem {
+    operands: Vec<usize>,
+    operator: char,
+}
+
+impl Problem {
+    fn new(size: usize, operator: char) -> Self {
+        Self {
+            operands: Vec::with_capacity(size),
+            operator,
+        }
+    }
+
+    fn add_operand(&mut self, operand: usize) {
+        self.operands.push(operand);
+    }
+
+    fn solve(&self) -> usize {
+        match self.operator {
+            '+' => self.operands.iter().sum::<usize>(),
+            '*' => self.operands.iter().product::<usize>(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn part1(lines: impl AsRef<[&'static str]>) -> usize {
+    let matrix = lines
+        .as_ref()
+        .iter()
+        .map(|line| line.split_ascii_whitespace().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut problems = Vec::with_capacity(matrix[0].len());
+
+    let size = matrix.len() - 1;
+    for y in 0..matrix[0].len() {
+        let operand = matrix[size][y].chars().next().unwrap();
+
+        let mut problem = Problem::new(size, operand);
+        for v in matrix.iter().take(size) {
+            problem.add_operand(v[y].parse::<usize>().unwrap());
+        }
+        problems.push(problem);
+    }
+
+    let total: usize = problems
+        .iter()
+        .map(|problem| {
+            #[allow(clippy::let_and_return)]
+            let v = problem.solve();
+            //println!("v={v}");
+            v
+        })
+        .sum();
+
+    println!("Total: {}", total);
+
+    total
+}
+
+// the numbers are read top to bottom down each column
+// and the columns are read right to left within each problem
+// so unlike part 1 every space in the line matters
+fn parse_columns(lines: &[&str]) -> Vec<Problem> {
+    let (operators, rows) = lines.split_last().unwrap();
+    let rows = rows.iter().map(|row| row.as_bytes()).collect::<Vec<_>>();
+
+    problem_spans(lines)
+        .into_iter()
+        .map(|(start, end)| {
+            // the operator sits somewhere under its problem
+            let operator = operators[start..end.min(operators.len())]
+                .trim()
+                .chars()
+                .next()
+                .unwrap();
+
+            let mut problem = Problem::new(end - start, operator);
+            for x in (start..end).rev() {
+                let operand = rows
+                    .iter()
+                    .filter_map(|row| row.get(x).filter(|ch| ch.is_ascii_digit()))
+                    .fold(0, |acc, ch| acc * 10 + (ch - b'0') as usize);
+                problem.add_operand(operand);
+            }
+            problem
+        })
+        .collect()
+}
+
+pub fn part2(lines: impl AsRef<[&'static str]>) -> usize {
+    let problems = parse_columns(lines.as_ref());
+
+    let total: usize = problems.iter().map(Problem::solve).sum();
+
+    println!("Total: {}", total);
+
+    total
+}
+
+// problems are separated by any run of blank columns, returns the start and end column of each
+fn problem_spans(lines: &[&str]) -> Vec<(usize, usize)> {
+    // lines aren't guaranteed to all be the same length
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let blank = |x: usize| {
+        lines
+            .iter()
+            .all(|line| line.as_bytes().get(x).is_none_or(|ch| *ch == b' '))
+    };
+
+    let mut spans = vec![];
+    let mut x = 0;
+    while x < width {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !blank(x) {
+            x += 1;
+        }
+        spans.push((start, x));
+    }
+    spans
+}
+
+// the parts work straight off the lines, so just make sure they're going to make sense
+pub fn parse(input: &'static str) -> Result<Vec<&'static str>, ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let Some((operators, rows)) = lines.split_last().filter(|(_, rows)| !rows.is_empty()) else {
+        return Err(ParseError::truncated(
+            input,
+            "expected rows of numbers and then the operators",
+        ));
+    };
+
+    if let Some(idx) = operators.find(|ch: char| !matches!(ch, '+' | '*' | ' ')) {
+        return Err(ParseError::new(
+            input,
+            &operators[idx..idx + 1],
+            "expected + or *",
+        ));
+    }
+
+    let count = operators.split_ascii_whitespace().count();
+    for row in rows {
+        if let Some(idx) = row.find(|ch: char| !ch.is_ascii_digit() && ch != ' ') {
+            return Err(ParseError::new(
+                input,
+                &row[idx..idx + 1],
+                "expected a digit",
+            ));
+        }
+        if row.split_ascii_whitespace().count() != count {
+            return Err(ParseError::new(
+                input,
+                row,
+                format!("expected {} numbers", count),
+            ));
+        }
+    }
+
+    // part 2 reads down the columns, so every column in a problem needs a digit in it
+    // and every problem needs exactly one operator under it
+    for (start, end) in problem_spans(&lines) {
+        for x in start..end {
+            if !rows
+                .iter()
+                .any(|row| row.as_bytes().get(x).is_some_and(u8::is_ascii_digit))
+            {
+                return Err(ParseError::new(
+                    input,
+                    &operators[x..x + 1],
+                    "expected a digit above the operator",
+                ));
+            }
+        }
+
+        let under = &operators[start.min(operators.len())..end.min(operators.len())];
+        if under.trim().len() != 1 {
+            // the span isn't blank, so some line has something in its first column
+            let line = lines
+                .iter()
+                .find(|line| line.as_bytes().get(start).is_some_and(|ch| *ch != b' '))
+                .unwrap();
+            return Err(ParseError::new(
+                input,
+                &line[start..start + 1],
+                "expected one operator under each problem",
+            ));
+        }
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let matrix = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&matrix), 4277556);
+        assert_eq!(part2(&matrix), 3263827);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("123 328\n 45 6x\n*   +  \n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.text, "x");
+
+        let err = parse("123 328\n 45\n*   +  \n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected 2 numbers");
+
+        let err = parse("123 328\n 45 64\n*   -  \n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+
+        let err = parse("123 328\n 45 64\n*  ++  \n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 4));
+        assert_eq!(err.message, "expected a digit above the operator");
+
+        let err = parse("123 328\n 45 64\n* +    \n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.message, "expected one operator under each problem");
+    }
+
+    #[test]
+    fn wide_gaps() {
+        // any number of blank columns can separate the problems
+        let matrix = parse("123   328\n 45   64 \n  6   98 \n*     +  \n").unwrap();
+
+        assert_eq!(part1(&matrix), 123 * 45 * 6 + 328 + 64 + 98);
+        assert_eq!(part2(&matrix), 24 * 356 + 369 + 248 + 8);
+    }
+}
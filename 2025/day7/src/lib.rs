@@ -0,0 +1,173 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_parse::ParseError;
+use aoc_parse::combinators::{grid, parse_all};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector2 {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Vector2 {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+pub fn part1(manifold: impl AsRef<[Vec<char>]>, start: Vector2) -> usize {
+    let manifold = manifold.as_ref();
+
+    let mut beams = BTreeSet::new();
+    beams.insert(start);
+
+    let mut processing = Vec::new();
+
+    let mut splits = 0;
+    loop {
+        while let Some(beam) = beams.pop_first() {
+            processing.push(beam);
+        }
+
+        while let Some(mut beam) = processing.pop() {
+            beam.y += 1;
+            if beam.y >= manifold.len() {
+                continue;
+            }
+
+            if manifold[beam.y][beam.x] == '^' {
+                if beam.x > 0 {
+                    let left = Vector2::new(beam.x - 1, beam.y);
+                    beams.insert(left);
+                }
+
+                if beam.x < manifold[beam.y].len() - 1 {
+                    let right = Vector2::new(beam.x + 1, beam.y);
+                    beams.insert(right);
+                }
+
+                splits += 1;
+            } else {
+                beams.insert(beam);
+            }
+        }
+
+        if beams.is_empty() {
+            break;
+        }
+    }
+
+    println!("Splits: {}", splits);
+
+    splits
+}
+
+pub fn part2(manifold: impl AsRef<[Vec<char>]>, start: Vector2) -> usize {
+    let manifold = manifold.as_ref();
+
+    let mut beams = BTreeMap::new();
+    beams.insert(start, 1_usize);
+
+    let mut processing = Vec::new();
+
+    let mut completed = 0;
+    loop {
+        while let Some(beam) = beams.pop_first() {
+            processing.push(beam);
+        }
+
+        while let Some(mut beam) = processing.pop() {
+            beam.0.y += 1;
+            if beam.0.y >= manifold.len() {
+                completed += beam.1;
+                continue;
+            }
+
+            if manifold[beam.0.y][beam.0.x] == '^' {
+                //println!("split {} at ({:?})", beam.1, beam.0);
+                if beam.0.x > 0 {
+                    let mut left = beam;
+                    left.0.x -= 1;
+                    beams
+                        .entry(left.0)
+                        .and_modify(|c| {
+                            *c += left.1;
+                            //println!("overlap left: {}", *c);
+                        })
+                        .or_insert(left.1);
+                }
+
+                if beam.0.x < manifold[beam.0.y].len() - 1 {
+                    let mut right = beam;
+                    right.0.x += 1;
+                    beams
+                        .entry(right.0)
+                        .and_modify(|c| {
+                            *c += right.1;
+                            //println!("overlap right: {}", *c);
+                        })
+                        .or_insert(right.1);
+                }
+            } else {
+                beams
+                    .entry(beam.0)
+                    .and_modify(|c| {
+                        *c += beam.1;
+                        //println!("overlap straight: {}", *c);
+                    })
+                    .or_insert(beam.1);
+            }
+        }
+
+        if beams.is_empty() {
+            break;
+        }
+    }
+
+    println!("Completed: {}", completed);
+
+    completed
+}
+
+// returns the manifold and where the beam starts
+pub fn parse(input: &str) -> Result<(Vec<Vec<char>>, Vector2), ParseError> {
+    let manifold = parse_all(
+        input,
+        grid(|ch| matches!(ch, '.' | '^' | 'S').then_some(ch)),
+    )?;
+
+    let Some(x) = manifold[0].iter().position(|ch| *ch == 'S') else {
+        let first = input.lines().next().unwrap_or(input);
+        return Err(ParseError::new(
+            input,
+            first,
+            "expected the start S on the first line",
+        ));
+    };
+
+    Ok((manifold, Vector2::new(x, 0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (manifold, start) = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&manifold, start), 21);
+        assert_eq!(part2(&manifold, start), 40);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse(".......S.......\n.......#.......\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(err.message, "expected a grid cell");
+
+        let err = parse("...............\n.......S.......\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.message, "expected the start S on the first line");
+    }
+}
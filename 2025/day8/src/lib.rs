@@ -0,0 +1,121 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use aoc_parse::{ParseError, parse_at};
+
+/*
+reddit says both parts of this can be solved using Union-Find aka Disjoint Set data structure
+    https://www.geeksforgeeks.org/dsa/introduction-to-disjoint-set-data-structure-or-union-find-algorithm/
+    https://en.wikipedia.org/wiki/Disjoint-set_data_structure
+
+also: https://www.reddit.com/r/adventofcode/comments/1pifkrg/comment/nt5plcx/
+
+this is a graph problem and it's finding if nodes in the graph are connected
+*/
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector3 {
+    x: isize,
+    y: isize,
+    z: isize,
+}
+
+impl FromStr for Vector3 {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split(",");
+        let mut next = || {
+            let part = parts
+                .next()
+                .ok_or_else(|| ParseError::truncated(value, "expected x,y,z"))?;
+            parse_at(value, part)
+        };
+        let x = next()?;
+        let y = next()?;
+        let z = next()?;
+        Ok(Self { x, y, z })
+    }
+}
+
+impl Vector3 {
+    fn distance(&self, other: Vector3) -> isize {
+        ((self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)).isqrt()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derivative::Derivative)]
+#[derivative(PartialOrd, Ord)]
+struct Pair {
+    #[derivative(PartialOrd = "ignore", Ord = "ignore")]
+    a: Vector3,
+    #[derivative(PartialOrd = "ignore", Ord = "ignore")]
+    b: Vector3,
+
+    distance: isize,
+}
+
+impl Pair {
+    fn new(a: Vector3, b: Vector3) -> Self {
+        Self {
+            a,
+            b,
+            distance: a.distance(b),
+        }
+    }
+}
+
+// connects the closest pairs of boxes, the example only makes 10 connections
+pub fn part1(boxes: Vec<Vector3>, max_connections: usize) -> usize {
+    let mut pairs = Vec::with_capacity(boxes.len() * (boxes.len() - 1));
+    for i in 0..boxes.len() {
+        for j in i + 1..boxes.len() {
+            let pair = Pair::new(boxes[i], boxes[j]);
+            pairs.push(pair);
+        }
+    }
+    pairs.sort();
+
+    let mut connections = 0;
+    let mut _circuits: HashMap<Vector3, HashSet<Vector3>> = HashMap::new();
+    for _pair in pairs {
+        // TODO: how to store the circuits is the question here
+        /*let aentry = circuits.entry(pair.a).or_default();
+        aentry.insert(pair.b);
+
+        let bentry = circuits.entry(pair.b).or_default();
+        bentry.insert(pair.a);*/
+
+        connections += 1;
+        if connections >= max_connections {
+            break;
+        }
+    }
+
+    // TODO: multiply together the sizes of the three largest circuits
+    0
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vector3>, ParseError> {
+    aoc_parse::lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "the circuits aren't built up from the connections yet"]
+    fn example() {
+        let boxes = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(boxes, 10), 40);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("162,817,812\n57,618\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.message, "expected x,y,z");
+    }
+}
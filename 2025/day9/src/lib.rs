@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use aoc_parse::{ParseError, parse_at};
+
+/*
+
+some related reddit threads to figure this out:
+
+https://www.reddit.com/r/adventofcode/comments/1pig1lc/year_2025_day_9_part_2_whats_wrong_all_tests_pass/
+https://www.reddit.com/r/adventofcode/comments/1pi5drz/2025_day_9_today_i_hit_a_wall/
+
+*/
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (x, y) = value
+            .split_once(",")
+            .ok_or_else(|| ParseError::new(value, value, "expected x,y"))?;
+        Ok(Self {
+            x: parse_at(value, x)?,
+            y: parse_at(value, y)?,
+        })
+    }
+}
+
+pub fn part1(tiles: impl AsRef<[Point]>) -> isize {
+    let tiles = tiles.as_ref();
+
+    // naive algorithm but I'm actually not sure you can do better?
+    let mut max_area = 0;
+    for i in 0..tiles.len() - 1 {
+        for j in i + 1..tiles.len() {
+            let a = tiles[i];
+            let b = tiles[j];
+
+            // + 1 because the points are 0-based
+            let w = (b.x - a.x).abs() + 1;
+            let h = (b.y - a.y).abs() + 1;
+            let area = w * h;
+
+            if area > max_area {
+                max_area = area;
+            }
+        }
+    }
+
+    println!("Area: {}", max_area);
+
+    max_area
+}
+
+pub fn part2(tiles: impl AsRef<[Point]>) {
+    let _tiles = tiles.as_ref();
+
+    //assert!(max_area == ???);
+    //println!("Area: {}", max_area);
+}
+
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    aoc_parse::lines(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // part 2 isn't done yet
+    #[test]
+    fn example() {
+        let tiles = parse(include_str!("../examples/example.txt")).unwrap();
+
+        assert_eq!(part1(&tiles), 50);
+    }
+}
@@ -0,0 +1,248 @@
+//! Summary of the criterion benches each year runs over its days
+//!
+//! Every year has a `bench` crate that times parsing, part 1 and part 2 for each day,
+//! one criterion group per day (`day1/parse`, `day1/part1`, `day1/part2`).
+//! Criterion leaves its estimates lying around in the target dir,
+//! this reads them back and prints one table with the slowest days at the top.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::Value;
+
+/// Benchmarks every day is expected to have, in the order they show up in the table
+pub const PARTS: [&str; 3] = ["parse", "part1", "part2"];
+
+/// How long one benchmark took
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    /// Mean time per iteration
+    pub mean: Duration,
+    /// Relative change in the mean against the previous run (or the baseline being compared to)
+    pub change: Option<f64>,
+}
+
+/// Estimates for every part of one day, missing parts weren't benched
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayTimings {
+    pub day: String,
+    pub parts: [Option<Estimate>; 3],
+}
+
+impl DayTimings {
+    /// Everything it takes to solve the day, the thing the table gets sorted by
+    pub fn total(&self) -> Duration {
+        self.parts
+            .iter()
+            .flatten()
+            .map(|estimate| estimate.mean)
+            .sum()
+    }
+}
+
+/// Where criterion writes its reports for a workspace
+///
+/// Criterion checks the same environment variables before falling back to the target dir.
+pub fn criterion_dir(workspace: impl AsRef<Path>) -> PathBuf {
+    if let Some(home) = std::env::var_os("CRITERION_HOME") {
+        return home.into();
+    }
+
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace.as_ref().join("target"));
+    target.join("criterion")
+}
+
+fn read_point_estimate(path: &Path) -> Option<f64> {
+    let json = fs::read_to_string(path).ok()?;
+    let estimates: Value = serde_json::from_str(&json).ok()?;
+    estimates["mean"]["point_estimate"].as_f64()
+}
+
+fn read_estimate(dir: &Path) -> Option<Estimate> {
+    // estimates are in nanoseconds
+    let mean = read_point_estimate(&dir.join("new").join("estimates.json"))?;
+    let change = read_point_estimate(&dir.join("change").join("estimates.json"));
+
+    Some(Estimate {
+        mean: Duration::from_secs_f64(mean / 1e9),
+        change,
+    })
+}
+
+fn day_number(day: &str) -> usize {
+    day.trim_start_matches("day").parse().unwrap_or(usize::MAX)
+}
+
+/// Reads back every day criterion has estimates for, slowest first
+pub fn load(criterion_dir: impl AsRef<Path>) -> Vec<DayTimings> {
+    let Ok(entries) = fs::read_dir(criterion_dir.as_ref()) else {
+        return vec![];
+    };
+
+    let mut days = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let day = entry.file_name().into_string().ok()?;
+            if !day.starts_with("day") {
+                // criterion's own report dir
+                return None;
+            }
+
+            let path = entry.path();
+            let parts = PARTS.map(|part| read_estimate(&path.join(part)));
+            if parts.iter().all(Option::is_none) {
+                return None;
+            }
+
+            Some(DayTimings { day, parts })
+        })
+        .collect::<Vec<_>>();
+
+    days.sort_by(|a, b| {
+        b.total()
+            .cmp(&a.total())
+            .then_with(|| day_number(&a.day).cmp(&day_number(&b.day)))
+    });
+
+    days
+}
+
+/// Formats a duration the way criterion does, in whatever unit keeps it readable
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_secs_f64() * 1e9;
+    if ns < 1e3 {
+        format!("{:.2} ns", ns)
+    } else if ns < 1e6 {
+        format!("{:.2} µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.2} ms", ns / 1e6)
+    } else {
+        format!("{:.2} s", ns / 1e9)
+    }
+}
+
+fn format_estimate(estimate: &Option<Estimate>) -> String {
+    match estimate {
+        Some(Estimate {
+            mean,
+            change: Some(change),
+        }) => format!("{} ({:+.1}%)", format_duration(*mean), change * 100.0),
+        Some(Estimate { mean, change: None }) => format_duration(*mean),
+        None => "-".to_string(),
+    }
+}
+
+/// The summary table, one row per day in the order given
+pub fn summary_table(days: &[DayTimings]) -> String {
+    let mut rows = vec![];
+    let mut header = vec!["day".to_string()];
+    header.extend(PARTS.iter().map(|part| part.to_string()));
+    header.push("total".to_string());
+    rows.push(header);
+
+    for day in days {
+        let mut row = vec![day.day.clone()];
+        row.extend(day.parts.iter().map(format_estimate));
+        row.push(format_duration(day.total()));
+        rows.push(row);
+    }
+
+    let widths = (0..rows[0].len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+
+    table
+}
+
+/// Prints the table for everything criterion has estimates for
+pub fn print_summary(criterion_dir: impl AsRef<Path>) {
+    let days = load(criterion_dir);
+    if days.is_empty() {
+        return;
+    }
+
+    println!("\nSlowest days first:\n");
+    print!("{}", summary_table(&days));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_estimate(dir: &Path, kind: &str, point_estimate: f64) {
+        let dir = dir.join(kind);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("estimates.json"),
+            format!(r#"{{"mean":{{"point_estimate":{}}}}}"#, point_estimate),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512.00 ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.50 µs");
+        assert_eq!(format_duration(Duration::from_micros(2_250)), "2.25 ms");
+        assert_eq!(format_duration(Duration::from_millis(3_000)), "3.00 s");
+    }
+
+    #[test]
+    fn loads_slowest_first() {
+        let dir = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        write_estimate(&dir.join("day1/parse"), "new", 1_000.0);
+        write_estimate(&dir.join("day1/part1"), "new", 2_000.0);
+        write_estimate(&dir.join("day1/part2"), "new", 3_000.0);
+        write_estimate(&dir.join("day1/part2"), "change", 0.05);
+
+        // only part 1 got benched
+        write_estimate(&dir.join("day2/part1"), "new", 10_000.0);
+
+        fs::create_dir_all(dir.join("report")).unwrap();
+
+        let days = load(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            days.iter().map(|d| d.day.as_str()).collect::<Vec<_>>(),
+            vec!["day2", "day1"]
+        );
+        assert_eq!(days[0].parts[0], None);
+        assert_eq!(days[0].total(), Duration::from_micros(10));
+        assert_eq!(days[1].total(), Duration::from_micros(6));
+        assert_eq!(days[1].parts[2].unwrap().change, Some(0.05));
+
+        let table = summary_table(&days);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert!(lines[0].starts_with("day "));
+        assert!(lines[1].starts_with("day2"));
+        assert!(lines[2].contains("3.00 µs (+5.0%)"));
+    }
+
+    #[test]
+    fn missing_dir_is_empty() {
+        assert!(load("/this/does/not/exist").is_empty());
+    }
+}
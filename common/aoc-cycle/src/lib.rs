@@ -0,0 +1,148 @@
+//! Cycle detection for simulations that get asked to run for an absurd number of steps
+//!
+//! Run the simulation until some state repeats, then everything past that point
+//! can be worked out from the states that have already been seen.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+/// Where a simulation starts repeating itself
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    /// First step that's part of the cycle
+    pub start: usize,
+    /// Number of steps before the cycle comes back around
+    pub period: usize,
+    /// Every state from the initial one (step 0) up to and including the first repeat
+    pub history: Vec<S>,
+}
+
+/// Steps the simulation until a key repeats
+///
+/// The key only needs to capture whatever decides the future of the simulation,
+/// so things that just keep growing (heights, step counters) can be left out of it
+/// and still be recovered from the state with [`Cycle::extrapolate`].
+///
+/// This never returns if the keys never repeat.
+pub fn find_cycle<S, K>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle<S>
+where
+    K: Hash + Eq,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![initial];
+
+    loop {
+        let current = history.len() - 1;
+        if let Some(start) = seen.insert(key(&history[current]), current) {
+            return Cycle {
+                start,
+                period: current - start,
+                history,
+            };
+        }
+
+        let next = step(&history[current]);
+        history.push(next);
+    }
+}
+
+impl<S> Cycle<S> {
+    /// The step in the history that step n is equivalent to
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.history.len() {
+            return n;
+        }
+
+        self.start + (n - self.start) % self.period
+    }
+
+    /// State after n steps (as far as the key is concerned)
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.history[self.equivalent_step(n)]
+    }
+
+    /// Value of a metric after n steps
+    ///
+    /// The metric can either repeat along with the cycle (a load or a count)
+    /// or grow by the same amount every time around it (a height),
+    /// unsigned metrics have to never shrink over a cycle.
+    pub fn extrapolate<M>(&self, n: usize, mut metric: impl FnMut(&S) -> M) -> M
+    where
+        M: Copy + Add<Output = M> + Sub<Output = M> + Mul<Output = M> + TryFrom<usize>,
+    {
+        if n < self.history.len() {
+            return metric(&self.history[n]);
+        }
+
+        let cycles = (n - self.start) / self.period;
+        let growth =
+            metric(&self.history[self.start + self.period]) - metric(&self.history[self.start]);
+
+        let cycles = M::try_from(cycles).unwrap_or_else(|_| panic!("{} cycles overflows", cycles));
+        metric(self.state_at(n)) + growth * cycles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_start_and_period() {
+        // 0 1 2 3 4 5 6 7 -> 3 ...
+        let cycle = find_cycle(0, |v| if *v == 7 { 3 } else { v + 1 }, |v| *v);
+        assert_eq!(cycle.start, 3);
+        assert_eq!(cycle.period, 5);
+        assert_eq!(cycle.history.len(), 9);
+
+        let mut v = 0;
+        for n in 0..100 {
+            assert_eq!(*cycle.state_at(n), v);
+            v = if v == 7 { 3 } else { v + 1 };
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let step = |v: &u64| (v * v + 1) % 1009;
+        let cycle = find_cycle(2_u64, step, |v| *v);
+
+        let mut v = 2;
+        for n in 0..5000 {
+            assert_eq!(*cycle.state_at(n), v);
+            v = step(&v);
+        }
+    }
+
+    #[test]
+    fn extrapolates_growth() {
+        // (position, height), only the position decides what happens next
+        // and the height goes up by the position every step
+        let step = |(pos, height): &(usize, i64)| ((pos + 1) % 4, height + *pos as i64);
+        let cycle = find_cycle((0_usize, 0_i64), step, |(pos, _)| *pos);
+
+        let mut state = (0, 0);
+        for n in 0..1000 {
+            assert_eq!(cycle.extrapolate(n, |(_, height)| *height), state.1);
+            state = step(&state);
+        }
+
+        // 6 every time around the cycle
+        assert_eq!(
+            cycle.extrapolate(4_000_000_000_000, |(_, height)| *height),
+            6_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn extrapolates_repeating() {
+        let cycle = find_cycle(1_usize, |v| v * 3 % 10, |v| *v);
+        assert_eq!(cycle.extrapolate(1_000_000_000, |v| *v), 1);
+        assert_eq!(cycle.extrapolate(1_000_000_001, |v| *v), 3);
+    }
+}
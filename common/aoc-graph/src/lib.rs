@@ -0,0 +1,634 @@
+//! Graphs keyed on node names, with the names interned to dense indices
+//!
+//! A lot of puzzles describe a graph as lines of `name -> names`,
+//! this takes care of the bookkeeping so the solution can work with plain indices.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::ops::Add;
+
+/// A graph that contains a cycle, where an acyclic one was needed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<'a> {
+    /// Every node that's part of a cycle (or only reachable through one), sorted by name
+    pub nodes: Vec<&'a str>,
+}
+
+impl fmt::Display for CycleError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "graph has a cycle through: {}", self.nodes.join(", "))
+    }
+}
+
+impl std::error::Error for CycleError<'_> {}
+
+/// Directed or undirected graph with optionally weighted edges
+///
+/// Unweighted graphs just use `()` for the weight.
+#[derive(Debug, Clone)]
+pub struct Graph<'a, W = ()> {
+    directed: bool,
+
+    names: Vec<&'a str>,
+    indices: HashMap<&'a str, usize>,
+
+    // outgoing (to, weight) for each node
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<'a, W> Graph<'a, W> {
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            names: vec![],
+            indices: HashMap::new(),
+            edges: vec![],
+        }
+    }
+
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Adds the node if it doesn't exist yet, returning its index either way
+    pub fn add_node(&mut self, name: &'a str) -> usize {
+        if let Some(&idx) = self.indices.get(name) {
+            return idx;
+        }
+
+        let idx = self.names.len();
+        self.names.push(name);
+        self.indices.insert(name, idx);
+        self.edges.push(vec![]);
+        idx
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn name(&self, idx: usize) -> &'a str {
+        self.names[idx]
+    }
+
+    pub fn names(&self) -> &[&'a str] {
+        &self.names
+    }
+
+    /// Outgoing (to, weight) edges of a node
+    pub fn edges(&self, idx: usize) -> &[(usize, W)] {
+        &self.edges[idx]
+    }
+
+    pub fn neighbors(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[idx].iter().map(|(to, _)| *to)
+    }
+
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.neighbors(from).any(|n| n == to)
+    }
+
+    /// Nodes that have no edges into them
+    pub fn sources(&self) -> impl Iterator<Item = usize> + '_ {
+        let degrees = self.in_degrees();
+        (0..self.len()).filter(move |&idx| degrees[idx] == 0)
+    }
+
+    fn in_degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.len()];
+        for to in self.edges.iter().flatten().map(|(to, _)| *to) {
+            degrees[to] += 1;
+        }
+        degrees
+    }
+
+    /// Every node reachable from the start (including the start)
+    pub fn reachable(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.len()];
+        visited[start] = true;
+
+        let mut reachable = vec![];
+        let mut queue = VecDeque::from([start]);
+        while let Some(idx) = queue.pop_front() {
+            reachable.push(idx);
+            for n in self.neighbors(idx) {
+                if !visited[n] {
+                    visited[n] = true;
+                    queue.push_back(n);
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Kahn's algorithm, fails with the nodes that could never be ordered
+    pub fn topological_sort(&self) -> Result<Vec<usize>, CycleError<'a>> {
+        let mut degrees = self.in_degrees();
+
+        let mut queue = (0..self.len())
+            .filter(|&idx| degrees[idx] == 0)
+            .collect::<VecDeque<_>>();
+
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(idx) = queue.pop_front() {
+            order.push(idx);
+
+            for n in self.neighbors(idx) {
+                degrees[n] -= 1;
+                if degrees[n] == 0 {
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        if order.len() != self.len() {
+            let mut nodes = (0..self.len())
+                .filter(|&idx| degrees[idx] > 0)
+                .map(|idx| self.names[idx])
+                .collect::<Vec<_>>();
+            nodes.sort_unstable();
+            return Err(CycleError { nodes });
+        }
+
+        Ok(order)
+    }
+
+    /// Tarjan's algorithm
+    ///
+    /// Components come out in reverse topological order
+    /// (a component only has edges into components before it).
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        struct State {
+            index: usize,
+            indices: Vec<Option<usize>>,
+            lowlinks: Vec<usize>,
+            stack: Vec<usize>,
+            on_stack: Vec<bool>,
+            components: Vec<Vec<usize>>,
+        }
+
+        fn connect<W>(graph: &Graph<W>, v: usize, state: &mut State) {
+            state.indices[v] = Some(state.index);
+            state.lowlinks[v] = state.index;
+            state.index += 1;
+            state.stack.push(v);
+            state.on_stack[v] = true;
+
+            for w in graph.neighbors(v) {
+                match state.indices[w] {
+                    None => {
+                        connect(graph, w, state);
+                        state.lowlinks[v] = state.lowlinks[v].min(state.lowlinks[w]);
+                    }
+                    Some(index) if state.on_stack[w] => {
+                        state.lowlinks[v] = state.lowlinks[v].min(index);
+                    }
+                    _ => (),
+                }
+            }
+
+            // v is the root of a component, everything above it on the stack is in it
+            if Some(state.lowlinks[v]) == state.indices[v] {
+                let mut component = vec![];
+                loop {
+                    let w = state.stack.pop().unwrap();
+                    state.on_stack[w] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                state.components.push(component);
+            }
+        }
+
+        let mut state = State {
+            index: 0,
+            indices: vec![None; self.len()],
+            lowlinks: vec![0; self.len()],
+            stack: vec![],
+            on_stack: vec![false; self.len()],
+            components: vec![],
+        };
+
+        for v in 0..self.len() {
+            if state.indices[v].is_none() {
+                connect(self, v, &mut state);
+            }
+        }
+
+        state.components
+    }
+
+    /// Groups of nodes connected to each other, ignoring edge direction
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        // union-find, since for directed graphs the edges only go one way
+        fn find(parents: &mut [usize], idx: usize) -> usize {
+            let mut root = idx;
+            while parents[root] != root {
+                root = parents[root];
+            }
+
+            // path compression
+            let mut idx = idx;
+            while parents[idx] != root {
+                let next = parents[idx];
+                parents[idx] = root;
+                idx = next;
+            }
+
+            root
+        }
+
+        let mut parents = (0..self.len()).collect::<Vec<_>>();
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, _) in edges {
+                let a = find(&mut parents, from);
+                let b = find(&mut parents, *to);
+                parents[a] = b;
+            }
+        }
+
+        let mut components: HashMap<usize, Vec<usize>> = HashMap::new();
+        for idx in 0..self.len() {
+            let root = find(&mut parents, idx);
+            components.entry(root).or_default().push(idx);
+        }
+
+        let mut components = components.into_values().collect::<Vec<_>>();
+        components.sort_unstable();
+        components
+    }
+
+    /// Graphviz output, edge weights are left off
+    pub fn to_dot(&self) -> String {
+        self.dot(|_| None)
+    }
+
+    // quoted ids and labels only need quotes and backslashes escaped
+    fn dot_escape(text: &str) -> String {
+        text.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    fn dot(&self, label: impl Fn(&W) -> Option<String>) -> String {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut dot = format!("{} {{\n", kind);
+        for (idx, name) in self.names.iter().enumerate() {
+            dot.push_str(&format!(
+                "    {} [label=\"{}\"];\n",
+                idx,
+                Self::dot_escape(name)
+            ));
+        }
+
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, weight) in edges {
+                // undirected edges are stored both ways, only write them once
+                if !self.directed && *to < from {
+                    continue;
+                }
+
+                match label(weight) {
+                    Some(label) => dot.push_str(&format!(
+                        "    {} {} {} [label=\"{}\"];\n",
+                        from,
+                        arrow,
+                        to,
+                        Self::dot_escape(&label)
+                    )),
+                    None => dot.push_str(&format!("    {} {} {};\n", from, arrow, to)),
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<'a, W: Clone> Graph<'a, W> {
+    /// Adds the edge (and any missing nodes)
+    ///
+    /// Undirected edges go both ways.
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str, weight: W) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+
+        if !self.directed && from != to {
+            self.edges[to].push((from, weight.clone()));
+        }
+        self.edges[from].push((to, weight));
+    }
+
+    /// The same graph with every edge flipped
+    pub fn reversed(&self) -> Self {
+        let mut edges = vec![vec![]; self.len()];
+        for (from, out) in self.edges.iter().enumerate() {
+            for (to, weight) in out {
+                edges[*to].push((from, weight.clone()));
+            }
+        }
+
+        Self {
+            directed: self.directed,
+            names: self.names.clone(),
+            indices: self.indices.clone(),
+            edges,
+        }
+    }
+}
+
+impl<W> Graph<'_, W>
+where
+    W: Copy + Default + Ord + Add<Output = W>,
+{
+    /// All-pairs shortest paths
+    ///
+    /// `distances[from][to]` is `None` if there's no path between them.
+    pub fn floyd_warshall(&self) -> Vec<Vec<Option<W>>> {
+        let len = self.len();
+
+        let mut distances = vec![vec![None; len]; len];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, weight) in edges {
+                distances[from][to] = Some(match distances[from][to] {
+                    Some(d) if d < weight => d,
+                    _ => weight,
+                });
+            }
+        }
+
+        for (idx, row) in distances.iter_mut().enumerate() {
+            row[idx] = Some(W::default());
+        }
+
+        for k in 0..len {
+            let through = distances[k].clone();
+            for row in distances.iter_mut() {
+                let Some(ik) = row[k] else {
+                    continue;
+                };
+
+                for (ij, kj) in row.iter_mut().zip(&through) {
+                    let Some(kj) = kj else {
+                        continue;
+                    };
+
+                    let d = ik + *kj;
+                    if ij.is_none_or(|ij| d < ij) {
+                        *ij = Some(d);
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+impl<W: fmt::Display> Graph<'_, W> {
+    /// Graphviz output with the edge weights as labels
+    pub fn to_weighted_dot(&self) -> String {
+        self.dot(|weight| Some(weight.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(&'static str, &'static str)]) -> Graph<'static> {
+        let mut graph = Graph::directed();
+        for (from, to) in edges {
+            graph.add_edge(from, to, ());
+        }
+        graph
+    }
+
+    fn names<'a>(graph: &Graph<'a>, nodes: &[usize]) -> Vec<&'a str> {
+        let mut names = nodes.iter().map(|idx| graph.name(*idx)).collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn topological_order() {
+        let graph = directed(&[
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("trousers", "shoes"),
+            ("trousers", "belt"),
+            ("belt", "jacket"),
+            ("shirt", "belt"),
+        ]);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), graph.len());
+
+        // every edge goes forwards
+        let mut position = vec![0; graph.len()];
+        for (pos, idx) in order.iter().enumerate() {
+            position[*idx] = pos;
+        }
+        for from in 0..graph.len() {
+            for to in graph.neighbors(from) {
+                assert!(position[from] < position[to]);
+            }
+        }
+
+        assert_eq!(
+            names(&graph, &graph.sources().collect::<Vec<_>>()),
+            ["shirt", "trousers"]
+        );
+    }
+
+    #[test]
+    fn topological_cycle() {
+        // b -> c -> d -> b, e hangs off the cycle and a is fine
+        let graph = directed(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "b"), ("d", "e")]);
+
+        let err = graph.topological_sort().unwrap_err();
+        assert_eq!(err.nodes, ["b", "c", "d", "e"]);
+        assert_eq!(err.to_string(), "graph has a cycle through: b, c, d, e");
+    }
+
+    #[test]
+    fn strongly_connected() {
+        // the example from wikipedia's page on Tarjan's algorithm
+        let graph = directed(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("d", "b"),
+            ("d", "c"),
+            ("d", "e"),
+            ("e", "d"),
+            ("e", "f"),
+            ("f", "c"),
+            ("f", "g"),
+            ("g", "f"),
+            ("h", "e"),
+            ("h", "g"),
+            ("h", "h"),
+        ]);
+
+        let components = graph.strongly_connected_components();
+        let mut sorted = components
+            .iter()
+            .map(|c| names(&graph, c))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+        assert_eq!(
+            sorted,
+            vec![
+                vec!["a", "b", "c"],
+                vec!["d", "e"],
+                vec!["f", "g"],
+                vec!["h"]
+            ]
+        );
+
+        // reverse topological order, edges only go back to earlier components
+        let mut component_of = vec![0; graph.len()];
+        for (idx, component) in components.iter().enumerate() {
+            for node in component {
+                component_of[*node] = idx;
+            }
+        }
+        for from in 0..graph.len() {
+            for to in graph.neighbors(from) {
+                assert!(component_of[to] <= component_of[from]);
+            }
+        }
+    }
+
+    #[test]
+    fn shortest_paths() {
+        // unit weights, so floyd warshall has to agree with a bfs from every node
+        let mut graph = Graph::directed();
+        for (from, to) in [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "d"),
+            ("a", "d"),
+            ("d", "e"),
+            ("e", "b"),
+            ("f", "a"),
+        ] {
+            graph.add_edge(from, to, 1);
+        }
+
+        let distances = graph.floyd_warshall();
+        for start in 0..graph.len() {
+            let mut bfs = vec![None; graph.len()];
+            bfs[start] = Some(0);
+            let mut queue = VecDeque::from([start]);
+            while let Some(idx) = queue.pop_front() {
+                for n in graph.neighbors(idx) {
+                    if bfs[n].is_none() {
+                        bfs[n] = Some(bfs[idx].unwrap() + 1);
+                        queue.push_back(n);
+                    }
+                }
+            }
+
+            assert_eq!(distances[start], bfs);
+        }
+
+        // the shorter of two parallel edges wins
+        let mut graph = Graph::undirected();
+        graph.add_edge("a", "b", 5);
+        graph.add_edge("a", "b", 2);
+        graph.add_edge("b", "c", 2);
+        graph.add_edge("a", "c", 7);
+        let distances = graph.floyd_warshall();
+        assert_eq!(distances[0], vec![Some(0), Some(2), Some(4)]);
+        assert_eq!(distances[2], vec![Some(4), Some(2), Some(0)]);
+    }
+
+    #[test]
+    fn connected() {
+        // direction doesn't matter, c and d only meet through e
+        let mut graph = directed(&[("a", "b"), ("c", "e"), ("d", "e")]);
+        graph.add_node("f");
+
+        let components = graph.connected_components();
+        let mut sorted = components
+            .iter()
+            .map(|c| names(&graph, c))
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![vec!["a", "b"], vec!["c", "d", "e"], vec!["f"]]);
+    }
+
+    #[test]
+    fn directed_dot() {
+        let mut graph = Graph::directed();
+        graph.add_edge("a", "b", ());
+        graph.add_edge("b", "a", ());
+
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -> 1;\n    1 -> 0;\n}\n"
+        );
+    }
+
+    #[test]
+    fn undirected_dot() {
+        let mut graph = Graph::undirected();
+        graph.add_edge("a", "b", ());
+        graph.add_edge("c", "b", ());
+
+        // each edge only once
+        assert_eq!(
+            graph.to_dot(),
+            "graph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    2 [label=\"c\"];\n    0 -- 1;\n    1 -- 2;\n}\n"
+        );
+    }
+
+    #[test]
+    fn weighted_dot() {
+        let mut graph = Graph::directed();
+        graph.add_edge("a", "b", 3);
+
+        assert_eq!(
+            graph.to_weighted_dot(),
+            "digraph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -> 1 [label=\"3\"];\n}\n"
+        );
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    0 [label=\"a\"];\n    1 [label=\"b\"];\n    0 -> 1;\n}\n"
+        );
+    }
+
+    #[test]
+    fn dot_escapes_quotes() {
+        let mut graph = Graph::directed();
+        graph.add_edge("say \"hi\"", "back\\slash", "\"w\"");
+
+        assert_eq!(
+            graph.to_weighted_dot(),
+            "digraph {\n    0 [label=\"say \\\"hi\\\"\"];\n    1 [label=\"back\\\\slash\"];\n    0 -> 1 [label=\"\\\"w\\\"\"];\n}\n"
+        );
+    }
+}
@@ -0,0 +1,399 @@
+//! Number theory and digit helpers that keep getting copy-pasted between days
+//!
+//! Anything that can overflow hands back an `Option` rather than wrapping,
+//! puzzle answers are usually big enough that silently wrapping is a real risk.
+
+use std::fmt::Debug;
+use std::ops::{Div, Rem};
+
+/// Unsigned integers the helpers work with
+pub trait Unsigned: Copy + Debug + Ord + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Unsigned for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TEN: Self = 10;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_pow(self, exp: u32) -> Option<Self> {
+                    <$t>::checked_pow(self, exp)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// Greatest common divisor, `gcd(0, 0)` is 0
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// Greatest common divisor of signed values, never negative
+///
+/// `None` if it doesn't fit, which only happens when it's `2^63`
+/// (`i64::MIN` with `0` or another `i64::MIN`).
+pub fn gcd_signed(a: i64, b: i64) -> Option<i64> {
+    i64::try_from(gcd(a.unsigned_abs(), b.unsigned_abs())).ok()
+}
+
+/// Lowest common multiple, `None` if it doesn't fit
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    // divide first so the intermediate value stays as small as possible
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Greatest common divisor of everything, 0 if there's nothing
+pub fn gcd_all<T: Unsigned>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// Lowest common multiple of everything, 1 if there's nothing
+pub fn lcm_all<T: Unsigned>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, where `g` is the (non-negative) gcd
+///
+/// The coefficients are bounded by `|b| / g` and `|a| / g` so they always fit,
+/// but `g` itself doesn't when it's `2^63` (the same cases as [`gcd_signed`]), that's `None`.
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (g, x, y) = extended_gcd_wide(a as i128, b as i128);
+    Some((i64::try_from(g).ok()?, x as i64, y as i64))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..m` such that `a * x ≡ 1 (mod m)`, if there is one
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+
+    mod_inverse_wide(a as i128, m as i128).map(|x| x as i64)
+}
+
+fn mod_inverse_wide(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd_wide(a.rem_euclid(m), m);
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(m))
+}
+
+/// Chinese remainder theorem over `(remainder, modulus)` pairs
+///
+/// Returns `(x, m)` where `x` in `0..m` satisfies every congruence and `m` is the
+/// lcm of the moduli (so every solution is `x + k * m`).
+/// The moduli don't have to be coprime, `None` if the congruences disagree,
+/// a modulus isn't positive or the combined modulus doesn't fit.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0_i128, 1_i128);
+
+    for (r, n) in congruences {
+        if n <= 0 {
+            return None;
+        }
+        let (r, n) = ((r as i128).rem_euclid(n as i128), n as i128);
+
+        // x + m * k ≡ r (mod n) only has a solution if the gcd divides the difference
+        let (g, _, _) = extended_gcd_wide(m, n);
+        let diff = r - x;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let step = n / g;
+        let k = (diff / g).rem_euclid(step) * mod_inverse_wide(m / g, step)? % step;
+
+        x += m * k;
+        m = m.checked_mul(step).filter(|m| *m <= i64::MAX as i128)?;
+        x = x.rem_euclid(m);
+    }
+
+    Some((x as i64, m as i64))
+}
+
+/// Wraps a value into `0..modulus` (like `%` but never negative)
+///
+/// # Panics
+///
+/// If `modulus` is 0.
+pub fn wrap(value: i64, modulus: i64) -> i64 {
+    value.rem_euclid(modulus)
+}
+
+/// Moves an index around a circular buffer of the given length
+///
+/// # Panics
+///
+/// If `len` is 0.
+pub fn wrap_index(idx: usize, offset: isize, len: usize) -> usize {
+    (idx as i128 + offset as i128).rem_euclid(len as i128) as usize
+}
+
+/// Number of decimal digits, 0 counts as a single digit
+pub fn count_digits<T: Unsigned>(mut n: T) -> u32 {
+    // floating point is slower than looping
+    //(n as f64).log(10.0).floor() as u32 + 1
+
+    if n == T::ZERO {
+        return 1;
+    }
+
+    let mut count = 0;
+    while n > T::ZERO {
+        n = n / T::TEN;
+        count += 1;
+    }
+    count
+}
+
+/// The i-th decimal digit, counting up from the least significant
+pub fn get_digit<T: Unsigned>(n: T, i: u32) -> T {
+    match T::TEN.checked_pow(i) {
+        Some(p) => (n / p) % T::TEN,
+        // anything past the largest power of ten is just a leading zero
+        None => T::ZERO,
+    }
+}
+
+/// Decimal digits, most significant first
+pub fn digits<T: Unsigned>(n: T) -> Vec<T> {
+    (0..count_digits(n))
+        .rev()
+        .map(|i| get_digit(n, i))
+        .collect()
+}
+
+/// Splits off the lowest `at` digits, `split_digits(123456, 2) == (1234, 56)`
+pub fn split_digits<T: Unsigned>(n: T, at: u32) -> (T, T) {
+    match T::TEN.checked_pow(at) {
+        Some(p) => (n / p, n % p),
+        None => (T::ZERO, n),
+    }
+}
+
+/// Appends the digits of `b` to `a`, `concat(12, 345) == Some(12345)`
+pub fn concat<T: Unsigned>(a: T, b: T) -> Option<T> {
+    T::TEN
+        .checked_pow(count_digits(b))?
+        .checked_mul(a)?
+        .checked_add(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn examples() {
+        assert_eq!(gcd(12_usize, 18), 6);
+        assert_eq!(gcd_signed(-12, 18), Some(6));
+        assert_eq!(gcd_signed(i64::MIN, 6), Some(2));
+        assert_eq!(gcd_signed(i64::MIN, 0), None);
+        assert_eq!(extended_gcd(i64::MIN, 0), None);
+        assert_eq!(extended_gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(extended_gcd(i64::MIN, i64::MAX), Some((1, -1, -1)));
+        assert_eq!(lcm(4_usize, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([2_usize, 3, 4, 5]), Some(60));
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(2, 4), None);
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(wrap(-1, 5), 4);
+        assert_eq!(wrap_index(0, -1, 5), 4);
+        assert_eq!(digits(120_u32), vec![1, 2, 0]);
+        assert_eq!(split_digits(123456_u64, 2), (1234, 56));
+        assert_eq!(concat(12_usize, 345), Some(12345));
+        assert_eq!(concat(u64::MAX, 1), None);
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_divides_both(a in 0..1_000_000_u64, b in 0..1_000_000_u64) {
+            let g = gcd(a, b);
+            if g == 0 {
+                prop_assert!(a == 0 && b == 0);
+            } else {
+                prop_assert_eq!(a % g, 0);
+                prop_assert_eq!(b % g, 0);
+                // nothing bigger divides both
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+        }
+
+        #[test]
+        fn gcd_times_lcm_is_product(a in 1..u32::MAX as u64, b in 1..u32::MAX as u64) {
+            let l = lcm(a, b).unwrap();
+            prop_assert_eq!(l as u128 * gcd(a, b) as u128, a as u128 * b as u128);
+            prop_assert_eq!(l % a, 0);
+            prop_assert_eq!(l % b, 0);
+        }
+
+        #[test]
+        fn lcm_is_checked(a in any::<u64>(), b in any::<u64>()) {
+            let exact = if a == 0 || b == 0 {
+                0
+            } else {
+                a as u128 / gcd(a, b) as u128 * b as u128
+            };
+            prop_assert_eq!(lcm(a, b), u64::try_from(exact).ok());
+        }
+
+        #[test]
+        fn over_iterators_match_folds(values in prop::collection::vec(1..1000_usize, 0..8)) {
+            prop_assert_eq!(gcd_all(values.iter().copied()), values.iter().fold(0, |acc, v| gcd(acc, *v)));
+
+            // enough values near 1000 can overflow, that has to come back as None too
+            let l = lcm_all(values.iter().copied());
+            prop_assert_eq!(l, values.iter().try_fold(1, |acc, v| lcm(acc, *v)));
+            if let Some(l) = l {
+                prop_assert!(values.iter().all(|v| l % v == 0));
+            }
+        }
+
+        #[test]
+        fn gcd_signed_matches_unsigned(a in any::<i64>(), b in any::<i64>()) {
+            let g = gcd(a.unsigned_abs(), b.unsigned_abs());
+            prop_assert_eq!(gcd_signed(a, b), i64::try_from(g).ok());
+        }
+
+        #[test]
+        fn extended_gcd_is_bezout(a in any::<i64>(), b in any::<i64>()) {
+            let g = gcd(a.unsigned_abs(), b.unsigned_abs());
+            match extended_gcd(a, b) {
+                Some((g2, x, y)) => {
+                    prop_assert_eq!(g2 as u64, g);
+                    prop_assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g2 as i128);
+                }
+                None => prop_assert_eq!(g, 1 << 63),
+            }
+        }
+
+        #[test]
+        fn mod_inverse_inverts(a in any::<i64>(), m in 1..i64::MAX) {
+            match mod_inverse(a, m) {
+                Some(x) => {
+                    prop_assert!((0..m).contains(&x));
+                    prop_assert_eq!((a as i128 * x as i128).rem_euclid(m as i128), 1 % m as i128);
+                }
+                None => prop_assert_ne!(gcd(a.rem_euclid(m) as u64, m as u64), 1),
+            }
+        }
+
+        #[test]
+        fn crt_matches_brute_force(congruences in prop::collection::vec((any::<i64>(), 1..20_i64), 1..4)) {
+            let m = lcm_all(congruences.iter().map(|(_, n)| *n as u64)).unwrap() as i64;
+            let satisfies = |x: i64| congruences.iter().all(|(r, n)| x.rem_euclid(*n) == r.rem_euclid(*n));
+
+            let expected = (0..m).find(|x| satisfies(*x)).map(|x| (x, m));
+            prop_assert_eq!(crt(congruences.iter().copied()), expected);
+        }
+
+        #[test]
+        fn crt_large_coprime(r1 in any::<i64>(), r2 in any::<i64>()) {
+            // two big primes, the combined modulus only just fits
+            let (n1, n2) = (2_147_483_647_i64, 4_294_967_291_i64);
+            let (x, m) = crt([(r1, n1), (r2, n2)]).unwrap();
+            prop_assert_eq!(m, n1 * n2);
+            prop_assert_eq!(x.rem_euclid(n1), r1.rem_euclid(n1));
+            prop_assert_eq!(x.rem_euclid(n2), r2.rem_euclid(n2));
+        }
+
+        #[test]
+        fn wrap_stays_in_range(value in any::<i64>(), modulus in 1..i64::MAX) {
+            let w = wrap(value, modulus);
+            prop_assert!((0..modulus).contains(&w));
+            prop_assert_eq!((value as i128 - w as i128) % modulus as i128, 0);
+        }
+
+        #[test]
+        fn wrap_index_moves_around(idx in 0..1000_usize, offset in -5000..5000_isize, len in 1..1000_usize) {
+            let idx = idx % len;
+            let mut expected = idx;
+            for _ in 0..offset.unsigned_abs() {
+                expected = if offset < 0 { (expected + len - 1) % len } else { (expected + 1) % len };
+            }
+            prop_assert_eq!(wrap_index(idx, offset, len), expected);
+        }
+
+        #[test]
+        fn digits_match_strings(n in any::<u64>()) {
+            let s = n.to_string();
+            prop_assert_eq!(count_digits(n) as usize, s.len());
+
+            let expected = s.bytes().map(|b| (b - b'0') as u64).collect::<Vec<_>>();
+            prop_assert_eq!(digits(n), expected);
+
+            for (i, b) in s.bytes().rev().enumerate() {
+                prop_assert_eq!(get_digit(n, i as u32), (b - b'0') as u64);
+            }
+            prop_assert_eq!(get_digit(n, 25), 0);
+        }
+
+        #[test]
+        fn split_digits_recombines(n in any::<u64>(), at in 0..25_u32) {
+            let (high, low) = split_digits(n, at);
+            prop_assert!(count_digits(low) <= at.max(1));
+            match 10_u128.checked_pow(at) {
+                Some(p) => prop_assert_eq!(high as u128 * p + low as u128, n as u128),
+                None => prop_assert_eq!((high, low), (0, n)),
+            }
+        }
+
+        #[test]
+        fn concat_matches_strings(a in any::<u64>(), b in any::<u64>()) {
+            let expected = format!("{}{}", a, b).parse::<u64>().ok();
+            prop_assert_eq!(concat(a, b), expected);
+        }
+    }
+}
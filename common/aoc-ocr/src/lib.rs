@@ -0,0 +1,227 @@
+//! Reads the block letters some puzzles draw instead of giving a number
+//!
+//! Most of them use a 4x6 font (six pixels high, letters a column apart),
+//! a few use a bigger 6x10 one. The font is picked from how tall the lit pixels are,
+//! then the letters get split apart on the blank columns between them.
+
+use std::fmt;
+
+const SMALL: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[rustfmt::skip]
+const LARGE: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Why the pixels couldn't be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// Nothing was lit
+    Empty,
+    /// The lit pixels are this many rows high, which isn't either font
+    Height(usize),
+    /// The glyph starting at this column isn't a letter in the font (drawn out with `#` and `.`)
+    Unknown { column: usize, glyph: String },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no pixels are lit"),
+            Self::Height(height) => {
+                write!(f, "letters are {} pixels high, expected 6 or 10", height)
+            }
+            Self::Unknown { column, glyph } => {
+                write!(f, "unknown letter at column {}:\n{}", column, glyph)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+// glyph as `#` / `.` rows, with the blank columns either side taken off
+fn draw(rows: &[Vec<bool>]) -> String {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+    let start = (0..width).find(|&x| lit(x)).unwrap_or(0);
+    let end = (0..width).rfind(|&x| lit(x)).map(|x| x + 1).unwrap_or(0);
+
+    rows.iter()
+        .map(|row| {
+            (start..end)
+                .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn font_glyph(rows: &[&str]) -> String {
+    let rows = rows
+        .iter()
+        .map(|row| row.chars().map(|ch| ch == '#').collect())
+        .collect::<Vec<_>>();
+    draw(&rows)
+}
+
+/// Reads the letters out of rows of pixels (`true` is lit)
+///
+/// Blank rows above and below the letters and blank columns around them are fine,
+/// but letters have to have at least one blank column between them.
+pub fn recognize<R: AsRef<[bool]>>(pixels: &[R]) -> Result<String, OcrError> {
+    let lit_rows = pixels
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| row.as_ref().contains(&true))
+        .map(|(y, _)| y)
+        .collect::<Vec<_>>();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(OcrError::Empty);
+    };
+    let rows = &pixels[top..=bottom];
+
+    let font = match rows.len() {
+        6 => SMALL
+            .iter()
+            .map(|(letter, glyph)| (*letter, font_glyph(glyph)))
+            .collect::<Vec<_>>(),
+        10 => LARGE
+            .iter()
+            .map(|(letter, glyph)| (*letter, font_glyph(glyph)))
+            .collect::<Vec<_>>(),
+        height => return Err(OcrError::Height(height)),
+    };
+
+    let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap();
+    let lit = |x: usize| rows.iter().any(|row| row.as_ref().get(x) == Some(&true));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if !lit(x) {
+            x += 1;
+            continue;
+        }
+
+        // a letter runs until the next blank column
+        let start = x;
+        while x < width && lit(x) {
+            x += 1;
+        }
+
+        let glyph = draw(
+            &rows
+                .iter()
+                .map(|row| {
+                    let row = row.as_ref();
+                    row[start.min(row.len())..x.min(row.len())].to_vec()
+                })
+                .collect::<Vec<_>>(),
+        );
+        match font.iter().find(|(_, g)| *g == glyph) {
+            Some((letter, _)) => letters.push(*letter),
+            None => {
+                return Err(OcrError::Unknown {
+                    column: start,
+                    glyph,
+                })
+            }
+        }
+    }
+
+    Ok(letters)
+}
+
+/// [`recognize`] for pixels drawn out as text, `#` is lit and anything else isn't
+pub fn recognize_str(screen: &str) -> Result<String, OcrError> {
+    let pixels = screen
+        .lines()
+        .map(|line| line.chars().map(|ch| ch == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    recognize(&pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_font() {
+        // 2021 day 13
+        let screen = "\
+###...##..###..#.....##..#..#.#..#.#....
+#..#.#..#.#..#.#....#..#.#.#..#..#.#....
+#..#.#....#..#.#....#..#.##...####.#....
+###..#....###..#....####.#.#..#..#.#....
+#.#..#..#.#....#....#..#.#.#..#..#.#....
+#..#..##..#....####.#..#.#..#.#..#.####.
+";
+        assert_eq!(recognize_str(screen), Ok("RCPLAKHL".to_string()));
+    }
+
+    #[test]
+    fn every_letter() {
+        for (letter, glyph) in SMALL {
+            assert_eq!(recognize_str(&glyph.join("\n")), Ok(letter.to_string()));
+        }
+        for (letter, glyph) in LARGE {
+            assert_eq!(recognize_str(&glyph.join("\n")), Ok(letter.to_string()));
+        }
+    }
+
+    #[test]
+    fn large_font_with_padding() {
+        let rows = (0..10)
+            .map(|y| format!("..{}..{}", LARGE[10].1[y], LARGE[13].1[y]))
+            .collect::<Vec<_>>();
+        let screen = format!("\n{}\n......\n", rows.join("\n"));
+        assert_eq!(recognize_str(&screen), Ok("NX".to_string()));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(recognize_str("....\n...."), Err(OcrError::Empty));
+        assert_eq!(recognize_str("#\n#\n#"), Err(OcrError::Height(3)));
+        assert_eq!(
+            recognize::<Vec<bool>>(&vec![vec![true, true]; 6]),
+            Err(OcrError::Unknown {
+                column: 0,
+                glyph: ["##"; 6].join("\n")
+            })
+        );
+    }
+}
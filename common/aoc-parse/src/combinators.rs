@@ -0,0 +1,335 @@
+//! nom combinators for the bits of input that keep coming up
+//!
+//! Everything here uses nom's [`VerboseError`] so that [`parse_all`] can turn a failure
+//! into a [`ParseError`] pointing at the right spot. Days write their own parsers as
+//! `fn(&str) -> IResult<'_, T>` and mix these in with the rest of nom. Wrapping a parser in
+//! [`context`] is what gives the error a readable message.
+
+use std::str::FromStr;
+
+use nom::bytes::complete::take_till1;
+use nom::character::complete::{char, digit1, line_ending, multispace0, one_of, space0, space1};
+use nom::combinator::{all_consuming, map_res, opt, recognize};
+use nom::error::{context, ErrorKind, VerboseError, VerboseErrorKind};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, preceded, separated_pair, terminated};
+use nom::{Finish, Parser};
+
+use crate::ParseError;
+
+/// nom's result, with the error type everything in here uses
+pub type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// Unsigned integer, `12`
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<'_, T> {
+    context("a number", map_res(digit1, str::parse))(input)
+}
+
+/// Signed integer, `-12`, `12` or `+12`
+pub fn signed<T: FromStr>(input: &str) -> IResult<'_, T> {
+    context(
+        "a number",
+        map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse),
+    )(input)
+}
+
+/// Run of anything but whitespace, `pppw`
+pub fn word(input: &str) -> IResult<'_, &str> {
+    context("a word", take_till1(char::is_whitespace))(input)
+}
+
+/// `1,2,3`, spaces after the commas are fine
+pub fn comma_list<'a, T>(
+    item: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// `1 2  3`, on one line
+pub fn whitespace_list<'a, T>(
+    item: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(space1, item)
+}
+
+/// `x,y` (or `x, y`)
+pub fn coord<T: FromStr>(input: &str) -> IResult<'_, (T, T)> {
+    context(
+        "x,y coordinates",
+        separated_pair(signed, pair(char(','), space0), signed),
+    )(input)
+}
+
+/// `key: value`, leading indentation is skipped
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, VerboseError<&'a str>>,
+    value: impl Parser<&'a str, V, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, (K, V)> {
+    preceded(space0, separated_pair(key, pair(char(':'), space0), value))
+}
+
+// error for when a hand rolled parser finds something other than `what` at `input`
+fn expected<'a>(input: &'a str, what: &'static str) -> nom::Err<VerboseError<&'a str>> {
+    nom::Err::Error(VerboseError {
+        errors: vec![(input, VerboseErrorKind::Context(what))],
+    })
+}
+
+/// Rectangular(ish) block of characters, one row per line
+///
+/// Stops at a blank line or the end of the input. Rows don't have to be the same length.
+pub fn grid<'a, T>(
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<Vec<T>>> {
+    move |input: &'a str| {
+        let mut rows = vec![];
+        let mut rest = input;
+        loop {
+            let line = rest.split(['\r', '\n']).next().unwrap();
+            if line.is_empty() {
+                break;
+            }
+
+            let mut row = vec![];
+            for (idx, ch) in line.char_indices() {
+                match cell(ch) {
+                    Some(value) => row.push(value),
+                    None => return Err(expected(&line[idx..], "a grid cell")),
+                }
+            }
+            rows.push(row);
+
+            rest = &rest[line.len()..];
+            match line_ending::<_, VerboseError<&str>>(rest) {
+                // only take the newline if there's another row after it
+                Ok((next, _)) if !next.starts_with(['\r', '\n']) && !next.is_empty() => rest = next,
+                _ => break,
+            }
+        }
+
+        if rows.is_empty() {
+            return Err(expected(input, "a grid"));
+        }
+        Ok((rest, rows))
+    }
+}
+
+/// A blank line, between blocks
+pub fn blank_line(input: &str) -> IResult<'_, &str> {
+    recognize(pair(line_ending, line_ending))(input)
+}
+
+/// Blocks separated by blank lines
+///
+/// A block that fails part way through just ends the list, [`parse_blocks`] reports those better.
+pub fn blocks<'a, T>(
+    block: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(blank_line, block)
+}
+
+// the bit of input to underline, a number / word or else just the next char
+fn token(rest: &str) -> &str {
+    let len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+        .unwrap_or(rest.len());
+    match len {
+        0 => &rest[..rest.chars().next().map(char::len_utf8).unwrap_or(0)],
+        len => &rest[..len],
+    }
+}
+
+fn to_parse_error(input: &str, err: VerboseError<&str>) -> ParseError {
+    // the innermost context says what was expected, otherwise make do with whatever nom tripped on
+    let (rest, kind) = err
+        .errors
+        .iter()
+        .find(|(_, kind)| matches!(kind, VerboseErrorKind::Context(_)))
+        .or(err.errors.first())
+        .cloned()
+        .unwrap_or((input, VerboseErrorKind::Nom(ErrorKind::Fail)));
+
+    let message = match kind {
+        VerboseErrorKind::Context(ctx) => format!("expected {}", ctx),
+        VerboseErrorKind::Char(ch) => format!("expected '{}'", ch),
+        VerboseErrorKind::Nom(ErrorKind::Eof) => "unexpected text at the end".to_string(),
+        VerboseErrorKind::Nom(ErrorKind::Tag) => "unexpected text".to_string(),
+        VerboseErrorKind::Nom(ErrorKind::Digit) => "expected a number".to_string(),
+        VerboseErrorKind::Nom(kind) => format!("couldn't parse ({})", kind.description()),
+    };
+
+    if rest.trim().is_empty() {
+        ParseError::truncated(input, message)
+    } else {
+        ParseError::new(input, token(rest), message)
+    }
+}
+
+/// Runs `parser` over the whole of `input` (bar trailing whitespace)
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> Result<T, ParseError> {
+    all_consuming(terminated(parser, multispace0))(input)
+        .finish()
+        .map(|(_, value)| value)
+        .map_err(|err| to_parse_error(input, err))
+}
+
+/// Runs `parser` over every line, errors point at the right line
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_all(line, |i| parser.parse(i)).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+// splits wherever a blank_line matches, so \r\n line endings work too
+fn split_blocks(input: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut start = 0;
+    let mut idx = 0;
+    while let Some(offset) = input[idx..].find(['\r', '\n']) {
+        idx += offset;
+        match blank_line(&input[idx..]) {
+            Ok((rest, _)) => {
+                blocks.push(&input[start..idx]);
+                idx = input.len() - rest.len();
+                start = idx;
+            }
+            Err(_) => idx += 1,
+        }
+    }
+    blocks.push(&input[start..]);
+    blocks
+}
+
+/// Runs `parser` over every blank line separated block, errors point at the right line
+pub fn parse_blocks<'a, T>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> Result<Vec<T>, ParseError> {
+    split_blocks(input)
+        .into_iter()
+        .filter(|block| !block.trim().is_empty())
+        .map(|block| parse_all(block, |i| parser.parse(i)).map_err(|err| err.within(input, block)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::bytes::complete::tag;
+
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_all("-12", signed::<i32>), Ok(-12));
+        assert_eq!(parse_all("+3", signed::<i32>), Ok(3));
+        assert_eq!(parse_all("42\n", unsigned::<u8>), Ok(42));
+
+        let err = parse_all("300", unsigned::<u8>).unwrap_err();
+        assert_eq!(err.message, "expected a number");
+        assert_eq!(err.text, "300");
+    }
+
+    #[test]
+    fn lists_and_coords() {
+        assert_eq!(
+            parse_all("1, 2,3", comma_list(unsigned::<usize>)),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parse_all("1  -2 3", whitespace_list(signed::<i64>)),
+            Ok(vec![1, -2, 3])
+        );
+        assert_eq!(
+            parse_all(
+                "498,4 -> 498,6",
+                separated_list1(tag(" -> "), coord::<usize>)
+            ),
+            Ok(vec![(498, 4), (498, 6)])
+        );
+    }
+
+    #[test]
+    fn key_values() {
+        let (items, ops) = parse_all(
+            "  Starting items: 79, 98\n  Operation: new = old * 19",
+            separated_pair(
+                key_value(tag("Starting items"), comma_list(unsigned::<usize>)),
+                line_ending,
+                key_value(
+                    tag("Operation"),
+                    preceded(tag("new = old * "), unsigned::<usize>),
+                ),
+            ),
+        )
+        .unwrap();
+        assert_eq!(items, ("Starting items", vec![79, 98]));
+        assert_eq!(ops, ("Operation", 19));
+    }
+
+    #[test]
+    fn grids_stop_at_blank_lines() {
+        let input = "#.\n.#\n\n10R5\n";
+        let (rows, moves) = parse_all(
+            input,
+            separated_pair(
+                grid(|c| (c == '#' || c == '.').then_some(c == '#')),
+                blank_line,
+                word,
+            ),
+        )
+        .unwrap();
+        assert_eq!(rows, vec![vec![true, false], vec![false, true]]);
+        assert_eq!(moves, "10R5");
+
+        let err = parse_all("#.\n.x", grid(|c| (c == '#' || c == '.').then_some(c))).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.message, "expected a grid cell");
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let err = parse_lines("1,2\n3,x\n", coord::<i32>).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "expected a number");
+
+        let err = parse_lines("1,2\n3,4 5", coord::<i32>).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.message, "unexpected text at the end");
+
+        let blocks = parse_blocks("1\n2\n\n3\n", separated_list1(line_ending, unsigned::<u32>));
+        assert_eq!(blocks, Ok(vec![vec![1, 2], vec![3]]));
+
+        let err = parse_blocks(
+            "1\n2\n\n3\ny\n",
+            separated_list1(line_ending, unsigned::<u32>),
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.source_line, "y");
+    }
+
+    #[test]
+    fn crlf_blocks() {
+        let blocks = parse_blocks(
+            "1\r\n2\r\n\r\n3\r\n",
+            separated_list1(line_ending, unsigned::<u32>),
+        );
+        assert_eq!(blocks, Ok(vec![vec![1, 2], vec![3]]));
+
+        let err = parse_blocks(
+            "1\r\n2\r\n\r\n3\r\ny\r\n",
+            separated_list1(line_ending, unsigned::<u32>),
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.source_line, "y");
+    }
+}
@@ -0,0 +1,276 @@
+//! Parse errors that point at the bit of the input that didn't parse
+//!
+//! Parsers build a [`ParseError`] against whatever they were handed (a line, a block,
+//! the whole input) with the offending text as a slice of it, so the line and column
+//! fall out of where that slice sits. Whoever split the input up moves the error
+//! back out with [`ParseError::within`], and main reports it with [`or_exit!`].
+//!
+//! Days that parse with nom get [`combinators`] for the usual numbers, lists, coordinates,
+//! blocks and grids, and [`combinators::parse_all`] to turn nom's error into a [`ParseError`].
+
+use std::fmt;
+use std::str::FromStr;
+
+pub mod combinators;
+
+/// Input that didn't parse, and where
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Year and day the input was for (`2025/day10`), filled in once it gets reported
+    pub day: Option<String>,
+    /// Line the offending text starts on, 1 based
+    pub line: usize,
+    /// Column (in chars) the offending text starts at, 1 based
+    pub column: usize,
+    /// The text that didn't parse, empty if the input ran out
+    pub text: String,
+    /// The whole line the offending text is on
+    pub source_line: String,
+    /// What was wrong with it
+    pub message: String,
+}
+
+// byte offset of a slice into the str it was taken from
+fn offset_in(source: &str, text: &str) -> Option<usize> {
+    let start = source.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize).checked_sub(start)?;
+    (offset + text.len() <= source.len()).then_some(offset)
+}
+
+// 1 based line and column of a byte offset, along with the line itself
+fn locate(source: &str, offset: usize) -> (usize, usize, &str) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    let column = source[line_start..offset].chars().count() + 1;
+    let source_line = source[line_start..].lines().next().unwrap_or("");
+    (line, column, source_line)
+}
+
+impl ParseError {
+    /// Error for `text`, which should be a slice of `source`
+    ///
+    /// If it isn't then the error points at the start of `source`.
+    pub fn new(source: &str, text: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(source, text).unwrap_or(0);
+        let (line, column, source_line) = locate(source, offset);
+
+        Self {
+            day: None,
+            line,
+            column,
+            text: text.to_string(),
+            source_line: source_line.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error for when `source` ran out before something that should have been there
+    pub fn truncated(source: &str, message: impl Into<String>) -> Self {
+        let text = &source[source.trim_end().len()..source.trim_end().len()];
+        Self::new(source, text, message)
+    }
+
+    /// Moves an error made against `source` (a slice of `input`) so it's relative to `input`
+    pub fn within(mut self, input: &str, source: &str) -> Self {
+        let Some(offset) = offset_in(input, source) else {
+            return self;
+        };
+
+        let (line, column, _) = locate(input, offset);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self.source_line = input.lines().nth(self.line - 1).unwrap_or("").to_string();
+        self
+    }
+
+    /// Tags the error with the year and day it came from
+    pub fn in_day(mut self, day: impl Into<String>) -> Self {
+        self.day = Some(day.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = &self.day {
+            write!(f, "{} ", day)?;
+        }
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        // underline the offending text (or just point past the end if there isn't any)
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map(|text| text.chars().count())
+            .unwrap_or(0)
+            .max(1);
+        writeln!(f, "  {}", self.source_line)?;
+        write!(f, "  {}{}", " ".repeat(self.column - 1), "^".repeat(width))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// `text.parse()`, with any error pointing at `text` in `source`
+pub fn parse_at<T>(source: &str, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse().map_err(|err| {
+        let name = std::any::type_name::<T>();
+        let name = name.rsplit("::").next().unwrap_or(name);
+        ParseError::new(source, text, format!("expected {} ({})", name, err))
+    })
+}
+
+/// Parses every line of the input, errors point at the right line
+pub fn lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|err: ParseError| err.within(input, line))
+        })
+        .collect()
+}
+
+/// `2025/day10` from the day's manifest dir, see [`day!`]
+pub fn day_from_manifest_dir(dir: &str) -> &str {
+    let mut parts = dir.rsplit(['/', '\\']).filter(|part| !part.is_empty());
+    let day = parts.next().unwrap_or(dir);
+    match parts.next() {
+        Some(year) => {
+            let start = offset_in(dir, year).unwrap();
+            let end = offset_in(dir, day).unwrap() + day.len();
+            &dir[start..end]
+        }
+        None => day,
+    }
+}
+
+/// Year and day of the crate this is used in, `2025/day10`
+#[macro_export]
+macro_rules! day {
+    () => {
+        $crate::day_from_manifest_dir(env!("CARGO_MANIFEST_DIR"))
+    };
+}
+
+/// Unwraps a parsed input, or prints where it went wrong and exits
+///
+/// ```ignore
+/// let machines = aoc_parse::or_exit!(parse(input));
+/// ```
+#[macro_export]
+macro_rules! or_exit {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => {
+                let err: $crate::ParseError = err;
+                eprintln!("{}", err.in_day($crate::day!()));
+                std::process::exit(1);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(usize, usize);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (a, b) = s
+                .split_once(',')
+                .ok_or_else(|| ParseError::new(s, s, "expected a,b"))?;
+            Ok(Pair(parse_at(s, a)?, parse_at(s, b)?))
+        }
+    }
+
+    #[test]
+    fn points_at_text() {
+        let err = ParseError::new("abc def ghi", &"abc def ghi"[4..7], "bad");
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.text, "def");
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: bad\n  abc def ghi\n      ^^^"
+        );
+    }
+
+    #[test]
+    fn lines_track_line_numbers() {
+        assert_eq!(
+            lines::<Pair>("1,2\n3,4").unwrap(),
+            vec![Pair(1, 2), Pair(3, 4)]
+        );
+
+        let err = lines::<Pair>("1,2\n3,4\n5,x6\n7,8").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.text, "x6");
+        assert_eq!(err.source_line, "5,x6");
+        assert_eq!(
+            err.message,
+            "expected usize (invalid digit found in string)"
+        );
+
+        let err = lines::<Pair>("1,2\n34").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected a,b");
+    }
+
+    #[test]
+    fn within_moves_columns_on_the_first_line() {
+        let input = "name: 1,x\nname: 2,3";
+        let (_, value) = input.split_once(": ").unwrap();
+        let value = value.lines().next().unwrap();
+
+        let err = value.parse::<Pair>().unwrap_err().within(input, value);
+        assert_eq!((err.line, err.column), (1, 9));
+        assert_eq!(err.source_line, "name: 1,x");
+    }
+
+    #[test]
+    fn truncated_points_past_the_end() {
+        let err = ParseError::truncated("1 2 3\n", "expected 4 numbers");
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.text, "");
+        assert!(err.to_string().ends_with("\n  1 2 3\n       ^"));
+    }
+
+    #[test]
+    fn reports_the_day() {
+        assert_eq!(
+            day_from_manifest_dir("/root/crate/2025/day10"),
+            "2025/day10"
+        );
+        assert_eq!(
+            day_from_manifest_dir("C:\\aoc\\2021\\day24\\"),
+            "2021\\day24"
+        );
+        assert_eq!(day!(), "common/aoc-parse");
+
+        let err = ParseError::new("x", "x", "bad").in_day("2021/day24");
+        assert!(err
+            .to_string()
+            .starts_with("2021/day24 line 1, column 1: bad"));
+    }
+}